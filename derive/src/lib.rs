@@ -0,0 +1,463 @@
+//! `#[derive(HidReport)]` for `usb-descriptors`.
+//!
+//! The struct is one Input, Output or Feature report. Every field becomes one Input, Output or
+//! Feature item in declaration order without gaps, so the report descriptor and `to_bytes` /
+//! `from_bytes` always agree.
+//!
+//! ```ignore
+//! #[derive(HidReport)]
+//! #[report(input, application = GenericDesktop::MOUSE, id = 1)]
+//! struct Mouse {
+//!     #[field(usage_minimum = Button::button(1), usage_maximum = Button::button(3))]
+//!     buttons: [bool; 3],
+//!     #[field(constant, bits = 5)]
+//!     padding: u8,
+//!     #[field(usage = GenericDesktop::X, logical_minimum = -127, logical_maximum = 127, relative)]
+//!     x: i8,
+//! }
+//! ```
+//!
+//! Struct attributes:
+//! - `input`, `output` or `feature`, the default is `input`
+//! - `application = <Usage>` wraps the report in an Application collection
+//! - `id = <u8>` sets the report ID, 1 to 255 since 0 means the report has no ID
+//!
+//! Field attributes:
+//! - `usage = <Usage>`, repeated once per element
+//! - `usage_minimum = <Usage>` and `usage_maximum = <Usage>`
+//! - `logical_minimum = <i32>` and `logical_maximum = <i32>`, the default is the range of the type
+//! - `bits = <n>` overrides the `Report Size` of the type
+//! - `constant`, `array` and `relative` set the data flags, the default is Data, Variable, Absolute.
+//!   Constant fields are Constant, Array, Absolute like the padding of the boot reports.
+//!
+//! Supported field types are `bool`, `u8`, `u16`, `u32`, `i8`, `i16`, `i32` and arrays of them.
+//!
+//! Report ID 0 is reserved, reports without an ID leave out `id`:
+//!
+//! ```compile_fail
+//! # use usb_descriptors::report::usage_tables::GenericDesktop;
+//! # use usb_descriptors_derive::HidReport;
+//! #[derive(HidReport)]
+//! #[report(input, id = 0)]
+//! struct Report {
+//!     #[field(usage = GenericDesktop::X)]
+//!     x: u8,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Fields, Ident,
+    Lit, LitInt, Type,
+};
+
+#[proc_macro_derive(HidReport, attributes(report, field))]
+pub fn derive_hid_report(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Attributes of the struct
+struct ReportAttributes {
+    kind: Ident,
+    application: Option<Expr>,
+    id: Option<Expr>,
+}
+
+/// Attributes of one field
+#[derive(Default)]
+struct FieldAttributes {
+    usages: Vec<Expr>,
+    usage_minimum: Option<Expr>,
+    usage_maximum: Option<Expr>,
+    logical_minimum: Option<Expr>,
+    logical_maximum: Option<Expr>,
+    bits: Option<u32>,
+    constant: bool,
+    array: bool,
+    relative: bool,
+}
+
+/// The element type of a field
+#[derive(Copy, Clone)]
+struct Element {
+    bits: u32,
+    signed: bool,
+    boolean: bool,
+}
+
+/// One field with its position in the report
+struct Field<'a> {
+    ident: &'a Ident,
+    element_type: &'a Type,
+    /// `None` for scalar fields
+    count: Option<u32>,
+    element: Element,
+    size: u32,
+    bit_offset: u32,
+    attributes: FieldAttributes,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "HidReport needs a struct with named fields",
+                ))
+            }
+        },
+        _ => return Err(Error::new(input.span(), "HidReport needs a struct")),
+    };
+
+    let report = report_attributes(input)?;
+    let mut bit_offset = 0u32;
+    let mut layout = Vec::<Field>::new();
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().expect("named field");
+        let attributes = field_attributes(field)?;
+        let (element_type, count) = match &field.ty {
+            Type::Array(array) => (&*array.elem, Some(array_length(&array.len)?)),
+            ty => (ty, None),
+        };
+        let element = element(element_type)?;
+        let size = match attributes.bits {
+            Some(bits) if bits == 0 || bits > element.bits => {
+                return Err(Error::new(
+                    field.span(),
+                    format!("bits must be between 1 and {}", element.bits),
+                ))
+            }
+            Some(bits) => bits,
+            None => element.bits,
+        };
+        if attributes.usages.is_empty()
+            && attributes.usage_minimum.is_none()
+            && !attributes.constant
+        {
+            return Err(Error::new(
+                field.span(),
+                "field needs a usage or usage_minimum, or `constant` for padding",
+            ));
+        }
+        if attributes.usage_minimum.is_some() != attributes.usage_maximum.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "usage_minimum and usage_maximum go together",
+            ));
+        }
+
+        layout.push(Field {
+            ident,
+            element_type,
+            count,
+            element,
+            size,
+            bit_offset,
+            attributes,
+        });
+        bit_offset += size * count.unwrap_or(1);
+    }
+
+    let report_struct = report_struct(&report, &layout)?;
+    let writes = layout.iter().map(write_field);
+    let reads = layout.iter().enumerate().map(|(index, field)| {
+        let ident = field.ident;
+        let read = read_field(index, field);
+        quote!(#ident: #read)
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::usb_descriptors::report::hid_report::HidReport
+            for #name #ty_generics #where_clause
+        {
+            const REPORT: ::usb_descriptors::report::report_struct::ReportStruct = #report_struct;
+
+            fn write_fields(&self, report: &mut [u8]) {
+                #(#writes)*
+            }
+
+            fn read_fields(report: &[u8]) -> Self {
+                Self { #(#reads,)* }
+            }
+        }
+    })
+}
+
+fn report_attributes(input: &DeriveInput) -> Result<ReportAttributes, Error> {
+    let mut report = ReportAttributes {
+        kind: Ident::new("Input", input.ident.span()),
+        application: None,
+        id: None,
+    };
+    for attribute in input.attrs.iter().filter(|a| a.path().is_ident("report")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("input") {
+                report.kind = Ident::new("Input", meta.path.span());
+            } else if meta.path.is_ident("output") {
+                report.kind = Ident::new("Output", meta.path.span());
+            } else if meta.path.is_ident("feature") {
+                report.kind = Ident::new("Feature", meta.path.span());
+            } else if meta.path.is_ident("application") {
+                report.application = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("id") {
+                let id = meta.value()?.parse::<Expr>()?;
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                }) = &id
+                {
+                    if lit.base10_parse::<u8>()? == 0 {
+                        return Err(Error::new(lit.span(), "report ID 0 is reserved"));
+                    }
+                }
+                report.id = Some(id);
+            } else {
+                return Err(meta.error("unknown report attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(report)
+}
+
+fn field_attributes(field: &syn::Field) -> Result<FieldAttributes, Error> {
+    let mut attributes = FieldAttributes::default();
+    for attribute in field.attrs.iter().filter(|a| a.path().is_ident("field")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("usage") {
+                attributes.usages.push(meta.value()?.parse()?);
+            } else if meta.path.is_ident("usage_minimum") {
+                attributes.usage_minimum = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("usage_maximum") {
+                attributes.usage_maximum = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("logical_minimum") {
+                attributes.logical_minimum = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("logical_maximum") {
+                attributes.logical_maximum = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("bits") {
+                attributes.bits = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+            } else if meta.path.is_ident("constant") {
+                attributes.constant = true;
+            } else if meta.path.is_ident("array") {
+                attributes.array = true;
+            } else if meta.path.is_ident("relative") {
+                attributes.relative = true;
+            } else {
+                return Err(meta.error("unknown field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    if !attributes.usages.is_empty() && attributes.usage_minimum.is_some() {
+        return Err(Error::new(
+            field.span(),
+            "usage and usage_minimum can not be combined",
+        ));
+    }
+    Ok(attributes)
+}
+
+fn array_length(length: &Expr) -> Result<u32, Error> {
+    match length {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(length),
+            ..
+        }) => length.base10_parse(),
+        _ => Err(Error::new(
+            length.span(),
+            "array length must be an integer literal",
+        )),
+    }
+}
+
+fn element(ty: &Type) -> Result<Element, Error> {
+    let ident = match ty {
+        Type::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+        _ => None,
+    };
+    let (bits, signed, boolean) = match ident.as_deref() {
+        Some("bool") => (1, false, true),
+        Some("u8") => (8, false, false),
+        Some("u16") => (16, false, false),
+        Some("u32") => (32, false, false),
+        Some("i8") => (8, true, false),
+        Some("i16") => (16, true, false),
+        Some("i32") => (32, true, false),
+        _ => {
+            return Err(Error::new(
+                ty.span(),
+                "field type must be bool, u8, u16, u32, i8, i16, i32 or an array of them",
+            ))
+        }
+    };
+    Ok(Element {
+        bits,
+        signed,
+        boolean,
+    })
+}
+
+/// The logical range of a field, defaulting to all values of `size` bits
+fn logical_range(field: &Field) -> Result<(TokenStream2, TokenStream2), Error> {
+    let (minimum, maximum) = if field.element.signed {
+        let half = 1i64 << (field.size - 1);
+        (-half, half - 1)
+    } else {
+        (0, (1i64 << field.size) - 1)
+    };
+    let default = |value: i64| {
+        i32::try_from(value)
+            .map(|value| quote!(#value))
+            .map_err(|_| {
+                Error::new(
+                    field.ident.span(),
+                    "the range of this field needs logical_minimum and logical_maximum",
+                )
+            })
+    };
+    let minimum = match &field.attributes.logical_minimum {
+        Some(expr) => quote!(#expr),
+        None => default(minimum)?,
+    };
+    let maximum = match &field.attributes.logical_maximum {
+        Some(expr) => quote!(#expr),
+        None => default(maximum)?,
+    };
+    Ok((minimum, maximum))
+}
+
+fn report_struct(report: &ReportAttributes, layout: &[Field]) -> Result<TokenStream2, Error> {
+    let kind = &report.kind;
+    let application = match &report.application {
+        Some(usage) => quote!(::core::option::Option::Some(#usage)),
+        None => quote!(::core::option::Option::None),
+    };
+    // IDs given as constants are checked when the report is evaluated
+    let report_id = match &report.id {
+        Some(id) => quote!({
+            let id: u8 = #id;
+            ::core::assert!(id != 0, "report ID 0 is reserved");
+            id
+        }),
+        None => quote!(0),
+    };
+
+    let mut fields = Vec::<TokenStream2>::new();
+    for field in layout.iter() {
+        let attributes = &field.attributes;
+        let usages = match (&attributes.usage_minimum, &attributes.usage_maximum) {
+            (Some(minimum), Some(maximum)) => quote! {
+                ::usb_descriptors::report::struct_usages::StructUsages::Range {
+                    minimum: #minimum,
+                    maximum: #maximum,
+                }
+            },
+            _ if attributes.usages.is_empty() => {
+                quote!(::usb_descriptors::report::struct_usages::StructUsages::None)
+            }
+            _ => {
+                let usages = &attributes.usages;
+                quote!(::usb_descriptors::report::struct_usages::StructUsages::List(&[#(#usages),*]))
+            }
+        };
+        let size = field.size;
+        let count = field.count.unwrap_or(1);
+        let (logical_minimum, logical_maximum) = logical_range(field)?;
+        let constant = attributes.constant;
+        let variable = !attributes.array && !attributes.constant;
+        let relative = attributes.relative;
+        fields.push(quote! {
+            ::usb_descriptors::report::report_struct_field::ReportStructField {
+                usages: #usages,
+                size: #size,
+                count: #count,
+                logical_minimum: #logical_minimum,
+                logical_maximum: #logical_maximum,
+                flags: ::usb_descriptors::report::data_flags::DataFlags {
+                    constant: #constant,
+                    variable: #variable,
+                    relative: #relative,
+                    wrap: false,
+                    non_linear: false,
+                    no_preferred: false,
+                    null_state: false,
+                    volatile: false,
+                    buffered_bytes: false,
+                },
+            }
+        });
+    }
+
+    Ok(quote! {
+        ::usb_descriptors::report::report_struct::ReportStruct {
+            kind: ::usb_descriptors::report::report_kind::ReportKind::#kind,
+            application: #application,
+            report_id: #report_id,
+            fields: &[#(#fields),*],
+        }
+    })
+}
+
+fn write_field(field: &Field) -> TokenStream2 {
+    let ident = field.ident;
+    let offset = field.bit_offset;
+    let size = field.size;
+    match field.count {
+        Some(_) => quote! {
+            for (index, value) in self.#ident.iter().enumerate() {
+                ::usb_descriptors::report::hid_report::write_bits(
+                    report,
+                    #offset + index as u32 * #size,
+                    #size,
+                    *value as u32,
+                );
+            }
+        },
+        None => quote! {
+            ::usb_descriptors::report::hid_report::write_bits(
+                report,
+                #offset,
+                #size,
+                self.#ident as u32,
+            );
+        },
+    }
+}
+
+fn read_field(index: usize, field: &Field) -> TokenStream2 {
+    let offset = field.bit_offset;
+    let size = field.size;
+    let element_type = field.element_type;
+    let read = |offset: TokenStream2| {
+        let bits = quote! {
+            ::usb_descriptors::report::hid_report::read_bits(
+                report,
+                #offset,
+                #size,
+                <Self as ::usb_descriptors::report::hid_report::HidReport>::REPORT.fields[#index]
+                    .signed(),
+            )
+        };
+        if field.element.boolean {
+            quote!(#bits != 0)
+        } else {
+            quote!(#bits as #element_type)
+        }
+    };
+    match field.count {
+        Some(_) => {
+            let element = read(quote!(#offset + index as u32 * #size));
+            quote!(::core::array::from_fn(|index| #element))
+        }
+        None => read(quote!(#offset)),
+    }
+}
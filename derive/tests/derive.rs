@@ -0,0 +1,129 @@
+use usb_descriptors::{
+    error::DecodeError,
+    hid::boot_protocol::BootProtocol,
+    report::{
+        hid_report::HidReport,
+        report_kind::ReportKind,
+        report_layout::ReportLayout,
+        usage_tables::{Button, GenericDesktop, Keyboard, Led},
+    },
+};
+use usb_descriptors_derive::HidReport;
+
+#[derive(Debug, PartialEq, HidReport)]
+#[report(input, application = GenericDesktop::KEYBOARD)]
+struct BootKeyboard {
+    #[field(usage_minimum = Keyboard::LEFT_CONTROL, usage_maximum = Keyboard::RIGHT_GUI)]
+    modifiers: [bool; 8],
+    #[field(constant)]
+    reserved: u8,
+    #[field(
+        array,
+        usage_minimum = Keyboard::NO_EVENT,
+        usage_maximum = Keyboard::APPLICATION,
+        logical_maximum = 0x65
+    )]
+    keys: [u8; 6],
+}
+
+#[derive(Debug, PartialEq, HidReport)]
+#[report(output, application = GenericDesktop::KEYBOARD, id = 3)]
+struct Leds {
+    #[field(usage = Led::NUM_LOCK, usage = Led::CAPS_LOCK, usage = Led::SCROLL_LOCK)]
+    leds: [bool; 3],
+    #[field(constant, bits = 5)]
+    padding: u8,
+}
+
+#[derive(Debug, PartialEq, HidReport)]
+#[report(application = GenericDesktop::MOUSE)]
+struct Mouse {
+    #[field(usage_minimum = Button::button(1), usage_maximum = Button::button(3))]
+    buttons: [bool; 3],
+    #[field(constant, bits = 5)]
+    padding: u8,
+    #[field(usage = GenericDesktop::X, logical_minimum = -127, logical_maximum = 127, relative)]
+    x: i8,
+    #[field(usage = GenericDesktop::Y, logical_minimum = -127, logical_maximum = 127, relative)]
+    y: i8,
+    #[field(usage = GenericDesktop::WHEEL, bits = 12, relative)]
+    wheel: i16,
+}
+
+#[test]
+fn test_boot_keyboard() {
+    let report = BootKeyboard::report().build().unwrap();
+    let layout = ReportLayout::decode(&report.items).unwrap();
+    assert_eq!(layout.report_length(ReportKind::Input, 0), 8);
+
+    let boot =
+        ReportLayout::decode(&BootProtocol::Keyboard.report().build().unwrap().items).unwrap();
+    assert_eq!(
+        layout.report_fields(ReportKind::Input, 0),
+        boot.report_fields(ReportKind::Input, 0)
+    );
+
+    let keyboard = BootKeyboard {
+        modifiers: [false, true, false, false, false, false, false, false],
+        reserved: 0,
+        keys: [Keyboard::A.id as u8, 0, 0, 0, 0, 0],
+    };
+    let bytes = keyboard.to_bytes();
+    assert_eq!(bytes, vec![0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(BootKeyboard::from_bytes(&bytes), Ok(keyboard));
+}
+
+#[test]
+fn test_report_id() {
+    let report = Leds::report().build().unwrap();
+    assert_eq!(
+        report.items,
+        vec![
+            0x05, 0x01, 0x09, 0x06, 0xA1, 0x01, 0x85, 0x03, 0x05, 0x08, 0x09, 0x01, 0x09, 0x02,
+            0x09, 0x03, 0x14, 0x25, 0x01, 0x75, 0x01, 0x95, 0x03, 0x91, 0x02, 0x75, 0x05, 0x95,
+            0x01, 0x91, 0x01, 0xC0,
+        ]
+    );
+
+    let leds = Leds {
+        leds: [true, true, false],
+        padding: 0,
+    };
+    assert_eq!(leds.to_bytes(), vec![0x03, 0x03]);
+    assert_eq!(Leds::from_bytes(&[0x03, 0x03]), Ok(leds));
+    assert_eq!(
+        Leds::from_bytes(&[0x01, 0x03]),
+        Err(DecodeError::InvalidValue {
+            field: "Report ID",
+            value: 1
+        })
+    );
+}
+
+#[test]
+fn test_mouse() {
+    let report = Mouse::report().build().unwrap();
+    let layout = ReportLayout::decode(&report.items).unwrap();
+    assert_eq!(layout.report_length(ReportKind::Input, 0), 5);
+
+    let mouse = Mouse {
+        buttons: [true, false, true],
+        padding: 0,
+        x: -5,
+        y: 7,
+        wheel: -2048,
+    };
+    let bytes = mouse.to_bytes();
+    assert_eq!(bytes, vec![0x05, 0xFB, 0x07, 0x00, 0x08]);
+    assert_eq!(Mouse::from_bytes(&bytes), Ok(mouse));
+
+    // The parsed descriptor agrees with the packed report
+    let fields = layout.report_fields(ReportKind::Input, 0);
+    assert_eq!(fields[2].value(&bytes, 0), Some(-5));
+    assert_eq!(fields[3].value(&bytes, 0), Some(7));
+    assert_eq!(fields[4].value(&bytes, 0), Some(-2048));
+    assert_eq!(
+        (fields[4].logical_minimum, fields[4].logical_maximum),
+        (-2048, 2047)
+    );
+}
@@ -1,18 +1,149 @@
 use alloc::vec::Vec;
 
-use crate::descriptor_type::DescriptorType;
+use crate::{
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
 pub trait EncodeBytes {
-    fn encode(&self) -> Result<Vec<u8>, &str>;
-    fn validate(&self) -> Result<(), &str> {
+    fn encode(&self) -> Result<Vec<u8>, Error>;
+    fn validate(&self) -> Result<(), Error> {
         Ok(())
     }
 }
 
 pub trait EncodeByte {
-    fn encode(&self) -> Result<u8, &str>;
+    fn encode(&self) -> Result<u8, Error>;
 }
 
-pub fn encode_w_value<'a>(descriptor_type: &'a DescriptorType, value: u8) -> Result<u16, &'a str> {
+pub trait DecodeBytes: Sized {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError>;
+}
+
+pub trait DecodeByte: Sized {
+    fn decode(byte: u8) -> Result<Self, DecodeError>;
+}
+
+pub fn encode_w_value(descriptor_type: &DescriptorType, value: u8) -> Result<u16, Error> {
     Ok((descriptor_type.encode()? as u16) << 8 | (value as u16))
 }
+
+/// Splits the first descriptor off `bytes` according to its `bLength`.
+/// Returns the descriptor and the remaining bytes.
+pub fn split_descriptor(bytes: &[u8]) -> Result<(&[u8], &[u8]), DecodeError> {
+    if bytes.len() < 2 {
+        return Err(DecodeError::Truncated {
+            expected: 2,
+            actual: bytes.len(),
+        });
+    }
+    let length = bytes[0];
+    if length < 2 {
+        return Err(DecodeError::InvalidLength {
+            descriptor_type: bytes[1],
+            length,
+        });
+    }
+    if bytes.len() < length as usize {
+        return Err(DecodeError::Truncated {
+            expected: length as usize,
+            actual: bytes.len(),
+        });
+    }
+    Ok(bytes.split_at(length as usize))
+}
+
+/// Checks `bDescriptorType` and, if given, `bLength` of the first descriptor in `bytes`.
+/// Returns exactly the bytes of that descriptor.
+pub fn expect_descriptor(
+    bytes: &[u8],
+    descriptor_type: DescriptorType,
+    length: Option<u8>,
+) -> Result<&[u8], DecodeError> {
+    let (descriptor, _) = split_descriptor(bytes)?;
+    let actual = DescriptorType::decode(descriptor[1])?;
+    if actual != descriptor_type {
+        return Err(DecodeError::UnexpectedDescriptorType {
+            expected: descriptor_type,
+            actual,
+        });
+    }
+    if let Some(length) = length {
+        if descriptor[0] != length {
+            return Err(DecodeError::InvalidLength {
+                descriptor_type: descriptor[1],
+                length: descriptor[0],
+            });
+        }
+    }
+    Ok(descriptor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_descriptor() {
+        let bytes = [4, 3, 0x09, 0x04, 7, 5];
+        let (descriptor, rest) = split_descriptor(&bytes).unwrap();
+        assert_eq!(descriptor, &[4, 3, 0x09, 0x04]);
+        assert_eq!(rest, &[7, 5]);
+
+        assert_eq!(
+            split_descriptor(rest),
+            Err(DecodeError::Truncated {
+                expected: 7,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            split_descriptor(&[0, 3]),
+            Err(DecodeError::InvalidLength {
+                descriptor_type: 3,
+                length: 0
+            })
+        );
+        assert_eq!(
+            split_descriptor(&[9]),
+            Err(DecodeError::Truncated {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_expect_descriptor() {
+        let bytes = [7, 5, 0x81, 0x03, 0x40, 0x00, 0x01];
+        assert_eq!(
+            expect_descriptor(&bytes, DescriptorType::Endpoint, Some(7)),
+            Ok(&bytes[..])
+        );
+        assert_eq!(
+            expect_descriptor(&bytes, DescriptorType::Interface, Some(7)),
+            Err(DecodeError::UnexpectedDescriptorType {
+                expected: DescriptorType::Interface,
+                actual: DescriptorType::Endpoint
+            })
+        );
+        assert_eq!(
+            expect_descriptor(&bytes[..6], DescriptorType::Endpoint, Some(7)),
+            Err(DecodeError::Truncated {
+                expected: 7,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            expect_descriptor(&[7, 0x42, 0, 0, 0, 0, 0], DescriptorType::Endpoint, Some(7)),
+            Err(DecodeError::UnknownDescriptorType(0x42))
+        );
+        assert_eq!(
+            expect_descriptor(&[6, 5, 0, 0, 0, 0], DescriptorType::Endpoint, Some(7)),
+            Err(DecodeError::InvalidLength {
+                descriptor_type: 5,
+                length: 6
+            })
+        );
+    }
+}
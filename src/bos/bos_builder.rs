@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+use crate::error::Error;
+
+use super::{
+    bos_bundle::BosBundle,
+    bos_descriptor::{BosDescriptor, BOS_DESCRIPTOR_TYPE},
+    device_capability_builder::DeviceCapabilityBuilder,
+    device_capability_descriptor::DeviceCapabilityDescriptor,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BosBuilder {
+    /// Turns into `bNumDeviceCaps` and the device capability descriptors
+    pub capabilities: Vec<DeviceCapabilityBuilder>,
+}
+
+impl BosBuilder {
+    /// `wTotalLength` is computed by the bundle
+    pub fn build(&self) -> Result<BosBundle, Error> {
+        let num_device_caps =
+            self.capabilities
+                .len()
+                .try_into()
+                .map_err(|_| Error::FieldOutOfRange {
+                    descriptor: BOS_DESCRIPTOR_TYPE,
+                    field: "bNumDeviceCaps",
+                    value: self.capabilities.len() as u32,
+                })?;
+
+        Ok(BosBundle {
+            bos: BosDescriptor {
+                total_length: 0,
+                num_device_caps,
+            },
+            capabilities: self
+                .capabilities
+                .iter()
+                .map(|capability| capability.build())
+                .collect::<Result<Vec<DeviceCapabilityDescriptor>, Error>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bos::device_capability_type::DeviceCapabilityType,
+        bos::{
+            container_id_builder::ContainerIdBuilder,
+            usb20_extension_builder::Usb20ExtensionBuilder,
+        },
+        descriptor::Descriptor,
+        descriptor_type::DescriptorType,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let mut builder = BosBuilder {
+            capabilities: vec![
+                DeviceCapabilityBuilder::Usb20Extension(Usb20ExtensionBuilder {
+                    link_power_management: true,
+                    besl: false,
+                    baseline_besl: None,
+                    deep_besl: None,
+                }),
+                DeviceCapabilityBuilder::ContainerId(ContainerIdBuilder {
+                    container_id: [0xAB; 16],
+                }),
+            ],
+        };
+        let bytes = builder.build().unwrap().encode().unwrap();
+        assert_eq!(bytes[..5], [0x05, 0x0F, 32, 0x00, 0x02]);
+        assert_eq!(bytes[5..12], [0x07, 0x10, 0x02, 0x02, 0x00, 0x00, 0x00]);
+        assert_eq!(bytes[12..16], [0x14, 0x10, 0x04, 0x00]);
+        assert_eq!(bytes[16..], [0xAB; 16]);
+
+        builder.capabilities[1] = DeviceCapabilityBuilder::Raw(DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::Platform,
+            data: vec![0x00; 253],
+        });
+        assert_eq!(
+            builder.build().unwrap().encode(),
+            Err(Error::LengthOverflow {
+                descriptor: DescriptorType::DeviceCapability,
+                field: "bLength",
+                length: 256
+            })
+        );
+    }
+}
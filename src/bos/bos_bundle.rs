@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+
+use crate::{descriptor::Descriptor, descriptor_type::DescriptorType, error::Error};
+
+use super::{
+    bos_descriptor::{BosDescriptor, BOS_DESCRIPTOR_TYPE},
+    device_capability_descriptor::DeviceCapabilityDescriptor,
+};
+
+/// The response to GET_DESCRIPTOR(BOS): the BOS descriptor followed by all device capabilities
+pub struct BosBundle {
+    /// Turns into the leading BOS descriptor. `wTotalLength` is computed on encode.
+    pub bos: BosDescriptor,
+    /// Device capabilities in the order they are sent
+    pub capabilities: Vec<DeviceCapabilityDescriptor>,
+}
+
+impl Descriptor for BosBundle {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = self.bos.encode()?;
+        for capability in self.capabilities.iter() {
+            bytes.append(&mut capability.encode()?);
+        }
+
+        let total_length: u16 = match bytes.len().try_into() {
+            Ok(total_length) => total_length,
+            Err(_) => {
+                return Err(Error::LengthOverflow {
+                    descriptor: BOS_DESCRIPTOR_TYPE,
+                    field: "wTotalLength",
+                    length: bytes.len(),
+                })
+            }
+        };
+        // wTotalLength
+        bytes[2..4].copy_from_slice(&total_length.to_le_bytes());
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        BOS_DESCRIPTOR_TYPE
+    }
+}
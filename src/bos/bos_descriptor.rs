@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use crate::{
+    binary::{expect_descriptor, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+pub const BOS_DESCRIPTOR_LENGTH: u8 = 5;
+pub const BOS_DESCRIPTOR_TYPE: DescriptorType = DescriptorType::Bos;
+
+/// The header of the Binary Device Object Store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BosDescriptor {
+    /// Turns into `wTotalLength`
+    pub total_length: u16,
+    /// Turns into `bNumDeviceCaps`
+    pub num_device_caps: u8,
+}
+
+impl Descriptor for BosDescriptor {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::<u8>::new();
+        bytes.push(BOS_DESCRIPTOR_LENGTH);
+        bytes.push(BOS_DESCRIPTOR_TYPE.encode()?);
+        bytes.extend_from_slice(&self.total_length.to_le_bytes());
+        bytes.push(self.num_device_caps);
+
+        if bytes.len() != BOS_DESCRIPTOR_LENGTH as usize {
+            return Err(Error::LengthMismatch {
+                descriptor: BOS_DESCRIPTOR_TYPE,
+                expected: BOS_DESCRIPTOR_LENGTH as usize,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        BOS_DESCRIPTOR_TYPE
+    }
+}
+
+impl DecodeDescriptor for BosDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(bytes, BOS_DESCRIPTOR_TYPE, Some(BOS_DESCRIPTOR_LENGTH))?;
+
+        Ok(BosDescriptor {
+            total_length: u16::from_le_bytes([bytes[2], bytes[3]]),
+            num_device_caps: bytes[4],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let descriptor = BosDescriptor {
+            total_length: 0x0016,
+            num_device_caps: 2,
+        };
+        let bytes = descriptor.encode().unwrap();
+        assert_eq!(bytes, vec![0x05, 0x0F, 0x16, 0x00, 0x02]);
+        assert_eq!(BosDescriptor::decode(&bytes), Ok(descriptor));
+    }
+}
@@ -0,0 +1,27 @@
+use alloc::vec::Vec;
+
+use crate::error::Error;
+
+use super::{
+    device_capability_descriptor::DeviceCapabilityDescriptor,
+    device_capability_type::DeviceCapabilityType,
+};
+
+/// The Container ID capability, a UUID shared by all functions of one physical device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerIdBuilder {
+    /// Turns into `ContainerID`, the 128-bit UUID in the order it is sent
+    pub container_id: [u8; 16],
+}
+
+impl ContainerIdBuilder {
+    pub fn build(&self) -> Result<DeviceCapabilityDescriptor, Error> {
+        let mut data = Vec::<u8>::new();
+        data.push(0); // bReserved
+        data.extend_from_slice(&self.container_id);
+        Ok(DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::ContainerId,
+            data,
+        })
+    }
+}
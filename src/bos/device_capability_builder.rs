@@ -0,0 +1,31 @@
+use crate::error::Error;
+
+use super::{
+    container_id_builder::ContainerIdBuilder,
+    device_capability_descriptor::DeviceCapabilityDescriptor,
+    super_speed_plus_builder::SuperSpeedPlusBuilder, super_speed_usb_builder::SuperSpeedUsbBuilder,
+    usb20_extension_builder::Usb20ExtensionBuilder,
+};
+
+/// A device capability of the BOS descriptor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceCapabilityBuilder {
+    Usb20Extension(Usb20ExtensionBuilder),
+    SuperSpeedUsb(SuperSpeedUsbBuilder),
+    SuperSpeedPlus(SuperSpeedPlusBuilder),
+    ContainerId(ContainerIdBuilder),
+    /// Any other capability, sent as is
+    Raw(DeviceCapabilityDescriptor),
+}
+
+impl DeviceCapabilityBuilder {
+    pub fn build(&self) -> Result<DeviceCapabilityDescriptor, Error> {
+        match self {
+            DeviceCapabilityBuilder::Usb20Extension(builder) => builder.build(),
+            DeviceCapabilityBuilder::SuperSpeedUsb(builder) => builder.build(),
+            DeviceCapabilityBuilder::SuperSpeedPlus(builder) => builder.build(),
+            DeviceCapabilityBuilder::ContainerId(builder) => builder.build(),
+            DeviceCapabilityBuilder::Raw(descriptor) => Ok(descriptor.clone()),
+        }
+    }
+}
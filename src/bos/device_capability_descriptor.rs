@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+use crate::{
+    binary::{expect_descriptor, DecodeByte, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+use super::device_capability_type::DeviceCapabilityType;
+
+/// `bLength`, `bDescriptorType` and `bDevCapabilityType`
+pub const DEVICE_CAPABILITY_HEADER_LENGTH: u8 = 3;
+pub const DEVICE_CAPABILITY_DESCRIPTOR_TYPE: DescriptorType = DescriptorType::DeviceCapability;
+
+/// A device capability inside the BOS descriptor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceCapabilityDescriptor {
+    /// Turns into `bDevCapabilityType`
+    pub capability_type: DeviceCapabilityType,
+    /// The capability specific fields following `bDevCapabilityType`
+    pub data: Vec<u8>,
+}
+
+impl Descriptor for DeviceCapabilityDescriptor {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let length = DEVICE_CAPABILITY_HEADER_LENGTH as usize + self.data.len();
+        let length: u8 = length.try_into().map_err(|_| Error::LengthOverflow {
+            descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+            field: "bLength",
+            length,
+        })?;
+
+        let mut bytes = Vec::<u8>::new();
+        bytes.push(length);
+        bytes.push(DEVICE_CAPABILITY_DESCRIPTOR_TYPE.encode()?);
+        bytes.push(self.capability_type.encode()?);
+        bytes.extend_from_slice(&self.data);
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        DEVICE_CAPABILITY_DESCRIPTOR_TYPE
+    }
+}
+
+impl DecodeDescriptor for DeviceCapabilityDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(bytes, DEVICE_CAPABILITY_DESCRIPTOR_TYPE, None)?;
+        if bytes.len() < DEVICE_CAPABILITY_HEADER_LENGTH as usize {
+            return Err(DecodeError::InvalidLength {
+                descriptor_type: bytes[1],
+                length: bytes[0],
+            });
+        }
+
+        Ok(DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::decode(bytes[2])?,
+            data: bytes[3..].to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let descriptor = DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::Usb20Extension,
+            data: vec![0x06, 0x00, 0x00, 0x00],
+        };
+        let bytes = descriptor.encode().unwrap();
+        assert_eq!(bytes, vec![0x07, 0x10, 0x02, 0x06, 0x00, 0x00, 0x00]);
+        assert_eq!(DeviceCapabilityDescriptor::decode(&bytes), Ok(descriptor));
+
+        assert_eq!(
+            DeviceCapabilityDescriptor::decode(&[0x03, 0x10, 0x42]),
+            Err(DecodeError::InvalidValue {
+                field: "bDevCapabilityType",
+                value: 0x42
+            })
+        );
+        assert_eq!(
+            DeviceCapabilityDescriptor::decode(&[0x02, 0x10]),
+            Err(DecodeError::InvalidLength {
+                descriptor_type: 0x10,
+                length: 0x02
+            })
+        );
+    }
+}
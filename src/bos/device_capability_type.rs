@@ -0,0 +1,78 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+/// `bDevCapabilityType` of a device capability descriptor
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DeviceCapabilityType {
+    WirelessUsb,
+    Usb20Extension,
+    SuperSpeedUsb,
+    ContainerId,
+    Platform,
+    PowerDelivery,
+    BatteryInfo,
+    PdConsumerPort,
+    PdProviderPort,
+    SuperSpeedPlus,
+    PrecisionTimeMeasurement,
+    WirelessUsbExtension,
+    Billboard,
+    Authentication,
+    BillboardExtension,
+    ConfigurationSummary,
+    FirmwareStatus,
+}
+
+impl EncodeByte for DeviceCapabilityType {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            DeviceCapabilityType::WirelessUsb => Ok(0x01),
+            DeviceCapabilityType::Usb20Extension => Ok(0x02),
+            DeviceCapabilityType::SuperSpeedUsb => Ok(0x03),
+            DeviceCapabilityType::ContainerId => Ok(0x04),
+            DeviceCapabilityType::Platform => Ok(0x05),
+            DeviceCapabilityType::PowerDelivery => Ok(0x06),
+            DeviceCapabilityType::BatteryInfo => Ok(0x07),
+            DeviceCapabilityType::PdConsumerPort => Ok(0x08),
+            DeviceCapabilityType::PdProviderPort => Ok(0x09),
+            DeviceCapabilityType::SuperSpeedPlus => Ok(0x0A),
+            DeviceCapabilityType::PrecisionTimeMeasurement => Ok(0x0B),
+            DeviceCapabilityType::WirelessUsbExtension => Ok(0x0C),
+            DeviceCapabilityType::Billboard => Ok(0x0D),
+            DeviceCapabilityType::Authentication => Ok(0x0E),
+            DeviceCapabilityType::BillboardExtension => Ok(0x0F),
+            DeviceCapabilityType::ConfigurationSummary => Ok(0x10),
+            DeviceCapabilityType::FirmwareStatus => Ok(0x11),
+        }
+    }
+}
+
+impl DecodeByte for DeviceCapabilityType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x01 => Ok(DeviceCapabilityType::WirelessUsb),
+            0x02 => Ok(DeviceCapabilityType::Usb20Extension),
+            0x03 => Ok(DeviceCapabilityType::SuperSpeedUsb),
+            0x04 => Ok(DeviceCapabilityType::ContainerId),
+            0x05 => Ok(DeviceCapabilityType::Platform),
+            0x06 => Ok(DeviceCapabilityType::PowerDelivery),
+            0x07 => Ok(DeviceCapabilityType::BatteryInfo),
+            0x08 => Ok(DeviceCapabilityType::PdConsumerPort),
+            0x09 => Ok(DeviceCapabilityType::PdProviderPort),
+            0x0A => Ok(DeviceCapabilityType::SuperSpeedPlus),
+            0x0B => Ok(DeviceCapabilityType::PrecisionTimeMeasurement),
+            0x0C => Ok(DeviceCapabilityType::WirelessUsbExtension),
+            0x0D => Ok(DeviceCapabilityType::Billboard),
+            0x0E => Ok(DeviceCapabilityType::Authentication),
+            0x0F => Ok(DeviceCapabilityType::BillboardExtension),
+            0x10 => Ok(DeviceCapabilityType::ConfigurationSummary),
+            0x11 => Ok(DeviceCapabilityType::FirmwareStatus),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bDevCapabilityType",
+                value: byte as u16,
+            }),
+        }
+    }
+}
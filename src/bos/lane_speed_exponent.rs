@@ -0,0 +1,39 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+/// The unit of the lane speed mantissa of a sublink speed attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LaneSpeedExponent {
+    BitsPerSecond,
+    KilobitsPerSecond,
+    MegabitsPerSecond,
+    GigabitsPerSecond,
+}
+
+impl EncodeByte for LaneSpeedExponent {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            LaneSpeedExponent::BitsPerSecond => Ok(0x00),
+            LaneSpeedExponent::KilobitsPerSecond => Ok(0x01),
+            LaneSpeedExponent::MegabitsPerSecond => Ok(0x02),
+            LaneSpeedExponent::GigabitsPerSecond => Ok(0x03),
+        }
+    }
+}
+
+impl DecodeByte for LaneSpeedExponent {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(LaneSpeedExponent::BitsPerSecond),
+            0x01 => Ok(LaneSpeedExponent::KilobitsPerSecond),
+            0x02 => Ok(LaneSpeedExponent::MegabitsPerSecond),
+            0x03 => Ok(LaneSpeedExponent::GigabitsPerSecond),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmSublinkSpeedAttr",
+                value: byte as u16,
+            }),
+        }
+    }
+}
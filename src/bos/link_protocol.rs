@@ -0,0 +1,32 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LinkProtocol {
+    SuperSpeed,
+    SuperSpeedPlus,
+}
+
+impl EncodeByte for LinkProtocol {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            LinkProtocol::SuperSpeed => Ok(0x00),
+            LinkProtocol::SuperSpeedPlus => Ok(0x01),
+        }
+    }
+}
+
+impl DecodeByte for LinkProtocol {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(LinkProtocol::SuperSpeed),
+            0x01 => Ok(LinkProtocol::SuperSpeedPlus),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmSublinkSpeedAttr",
+                value: byte as u16,
+            }),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod bos_builder;
+pub mod bos_bundle;
+pub mod bos_descriptor;
+pub mod container_id_builder;
+pub mod device_capability_builder;
+pub mod device_capability_descriptor;
+pub mod device_capability_type;
+pub mod lane_speed_exponent;
+pub mod link_protocol;
+pub mod sublink_direction;
+pub mod sublink_speed_attribute;
+pub mod sublink_type;
+pub mod super_speed_plus_builder;
+pub mod super_speed_usb_builder;
+pub mod usb20_extension_builder;
@@ -0,0 +1,6 @@
+/// The direction a sublink speed attribute describes, bit 7 of the Sublink Type
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SublinkDirection {
+    Rx,
+    Tx,
+}
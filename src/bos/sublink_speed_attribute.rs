@@ -0,0 +1,86 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    binary::{DecodeByte, DecodeBytes, EncodeByte, EncodeBytes},
+    error::{DecodeError, Error},
+};
+
+use super::{
+    device_capability_descriptor::DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+    lane_speed_exponent::LaneSpeedExponent, link_protocol::LinkProtocol, sublink_type::SublinkType,
+};
+
+/// One entry of `bmSublinkSpeedAttr`, e.g. 10 Gbit/s is mantissa 10 with exponent Gbit/s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SublinkSpeedAttribute {
+    /// Turns into the Sublink Speed Attribute ID, 0 to 15
+    pub id: u8,
+    /// Turns into the Lane Speed Exponent
+    pub exponent: LaneSpeedExponent,
+    /// Turns into the Sublink Type
+    pub sublink_type: SublinkType,
+    /// Turns into the Link Protocol
+    pub protocol: LinkProtocol,
+    /// Turns into the Lane Speed Mantissa
+    pub mantissa: u16,
+}
+
+impl EncodeBytes for SublinkSpeedAttribute {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        if self.id > 0x0F {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bmSublinkSpeedAttr",
+                value: self.id as u32,
+            });
+        }
+
+        let attribute = self.id | self.exponent.encode()? << 4 | self.sublink_type.encode()? << 6;
+        let protocol = self.protocol.encode()? << 6;
+        let mut bytes = vec![attribute, protocol];
+        bytes.extend_from_slice(&self.mantissa.to_le_bytes());
+        Ok(bytes)
+    }
+}
+
+impl DecodeBytes for SublinkSpeedAttribute {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        match bytes {
+            [attribute, protocol, mantissa_low, mantissa_high, ..] => Ok(SublinkSpeedAttribute {
+                id: attribute & 0x0F,
+                exponent: LaneSpeedExponent::decode(attribute >> 4 & 0x03)?,
+                sublink_type: SublinkType::decode(attribute >> 6)?,
+                protocol: LinkProtocol::decode(protocol >> 6)?,
+                mantissa: u16::from_le_bytes([*mantissa_low, *mantissa_high]),
+            }),
+            _ => Err(DecodeError::Truncated {
+                expected: 4,
+                actual: bytes.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bos::sublink_direction::SublinkDirection;
+
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let attribute = SublinkSpeedAttribute {
+            id: 1,
+            exponent: LaneSpeedExponent::GigabitsPerSecond,
+            sublink_type: SublinkType {
+                symmetric: false,
+                direction: SublinkDirection::Tx,
+            },
+            protocol: LinkProtocol::SuperSpeedPlus,
+            mantissa: 10,
+        };
+        let bytes = attribute.encode().unwrap();
+        assert_eq!(bytes, vec![0xF1, 0x40, 0x0A, 0x00]);
+        assert_eq!(SublinkSpeedAttribute::decode(&bytes), Ok(attribute));
+    }
+}
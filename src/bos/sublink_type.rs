@@ -0,0 +1,46 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+use super::sublink_direction::SublinkDirection;
+
+/// The Sublink Type of a sublink speed attribute. Symmetric links still describe each
+/// direction with its own attribute.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SublinkType {
+    /// Turns into bit 6, clear for a symmetric link
+    pub symmetric: bool,
+    /// Turns into bit 7
+    pub direction: SublinkDirection,
+}
+
+impl EncodeByte for SublinkType {
+    fn encode(&self) -> Result<u8, Error> {
+        let asymmetric = !self.symmetric as u8;
+        let direction = match self.direction {
+            SublinkDirection::Rx => 0,
+            SublinkDirection::Tx => 1,
+        };
+        Ok(asymmetric | direction << 1)
+    }
+}
+
+impl DecodeByte for SublinkType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        if byte > 0x03 {
+            return Err(DecodeError::InvalidValue {
+                field: "bmSublinkSpeedAttr",
+                value: byte as u16,
+            });
+        }
+
+        Ok(SublinkType {
+            symmetric: byte & 0x01 == 0,
+            direction: match byte & 0x02 {
+                0 => SublinkDirection::Rx,
+                _ => SublinkDirection::Tx,
+            },
+        })
+    }
+}
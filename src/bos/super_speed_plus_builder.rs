@@ -0,0 +1,200 @@
+use alloc::vec::Vec;
+
+use crate::{binary::EncodeBytes, error::Error};
+
+use super::{
+    device_capability_descriptor::{DeviceCapabilityDescriptor, DEVICE_CAPABILITY_DESCRIPTOR_TYPE},
+    device_capability_type::DeviceCapabilityType,
+    sublink_direction::SublinkDirection,
+    sublink_speed_attribute::SublinkSpeedAttribute,
+};
+
+/// The SuperSpeedPlus USB capability of Gen 2 and faster devices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuperSpeedPlusBuilder {
+    /// Turns into `bmSublinkSpeedAttr`. The Sublink Speed Attribute Count (SSAC) and the
+    /// Sublink Speed ID Count (SSIC) of `bmAttributes` are computed from the list.
+    /// Every ID has a receive entry immediately followed by a transmit entry, also on
+    /// symmetric links.
+    pub sublink_speed_attributes: Vec<SublinkSpeedAttribute>,
+    /// Turns into the Sublink Speed Attribute ID of `wFunctionalitySupport`,
+    /// the lowest speed with all functionality
+    pub functionality_support: u8,
+    /// Turns into the Min Rx Lane Count of `wFunctionalitySupport`, 0 to 15
+    pub min_rx_lane_count: u8,
+    /// Turns into the Min Tx Lane Count of `wFunctionalitySupport`, 0 to 15
+    pub min_tx_lane_count: u8,
+}
+
+impl SuperSpeedPlusBuilder {
+    pub fn build(&self) -> Result<DeviceCapabilityDescriptor, Error> {
+        let attribute_count = self.sublink_speed_attributes.len();
+        if attribute_count == 0 {
+            return Err(Error::Missing {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bmSublinkSpeedAttr",
+            });
+        }
+        if attribute_count > 32 {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bmAttributes",
+                value: attribute_count as u32,
+            });
+        }
+
+        let mut ids = Vec::<u8>::new();
+        for pair in self.sublink_speed_attributes.chunks(2) {
+            let paired = match pair {
+                [rx, tx] => {
+                    rx.id == tx.id
+                        && rx.sublink_type.direction == SublinkDirection::Rx
+                        && tx.sublink_type.direction == SublinkDirection::Tx
+                        && rx.sublink_type.symmetric == tx.sublink_type.symmetric
+                }
+                _ => false,
+            };
+            if !paired || ids.contains(&pair[0].id) {
+                return Err(Error::FieldOutOfRange {
+                    descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                    field: "bmSublinkSpeedAttr",
+                    value: pair[0].id as u32,
+                });
+            }
+            ids.push(pair[0].id);
+        }
+        if !ids.contains(&self.functionality_support) {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "wFunctionalitySupport",
+                value: self.functionality_support as u32,
+            });
+        }
+        for lane_count in [self.min_rx_lane_count, self.min_tx_lane_count] {
+            if lane_count > 0x0F {
+                return Err(Error::FieldOutOfRange {
+                    descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                    field: "wFunctionalitySupport",
+                    value: lane_count as u32,
+                });
+            }
+        }
+
+        // both counts are zero based
+        let attributes = (attribute_count as u32 - 1) | (ids.len() as u32 - 1) << 5;
+        let functionality_support = self.functionality_support as u16
+            | (self.min_rx_lane_count as u16) << 8
+            | (self.min_tx_lane_count as u16) << 12;
+
+        let mut data = Vec::<u8>::new();
+        data.push(0); // bReserved
+        data.extend_from_slice(&attributes.to_le_bytes());
+        data.extend_from_slice(&functionality_support.to_le_bytes());
+        data.extend_from_slice(&[0, 0]); // wReserved
+        for attribute in self.sublink_speed_attributes.iter() {
+            data.append(&mut attribute.encode()?);
+        }
+        Ok(DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::SuperSpeedPlus,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bos::{
+            lane_speed_exponent::LaneSpeedExponent, link_protocol::LinkProtocol,
+            sublink_type::SublinkType,
+        },
+        descriptor::Descriptor,
+    };
+
+    use super::*;
+
+    fn attribute(id: u8, symmetric: bool, direction: SublinkDirection) -> SublinkSpeedAttribute {
+        SublinkSpeedAttribute {
+            id,
+            exponent: LaneSpeedExponent::GigabitsPerSecond,
+            sublink_type: SublinkType {
+                symmetric,
+                direction,
+            },
+            protocol: LinkProtocol::SuperSpeedPlus,
+            mantissa: 10,
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        let mut builder = SuperSpeedPlusBuilder {
+            sublink_speed_attributes: vec![
+                attribute(0, false, SublinkDirection::Rx),
+                attribute(0, false, SublinkDirection::Tx),
+            ],
+            functionality_support: 0,
+            min_rx_lane_count: 1,
+            min_tx_lane_count: 1,
+        };
+        assert_eq!(
+            builder.build().unwrap().encode().unwrap(),
+            vec![
+                20, 0x10, 0x0A, // header
+                0x00, // bReserved
+                0x01, 0x00, 0x00, 0x00, // bmAttributes, SSAC 1, SSIC 0
+                0x00, 0x11, // wFunctionalitySupport
+                0x00, 0x00, // wReserved
+                0x70, 0x40, 0x0A, 0x00, // receive
+                0xF0, 0x40, 0x0A, 0x00, // transmit
+            ]
+        );
+
+        // a symmetric link has a receive and a transmit entry too
+        builder.sublink_speed_attributes = vec![
+            attribute(0, true, SublinkDirection::Rx),
+            attribute(0, true, SublinkDirection::Tx),
+        ];
+        assert_eq!(
+            builder.build().unwrap().encode().unwrap()[12..],
+            [0x30, 0x40, 0x0A, 0x00, 0xB0, 0x40, 0x0A, 0x00]
+        );
+
+        let error = Err(Error::FieldOutOfRange {
+            descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+            field: "bmSublinkSpeedAttr",
+            value: 0,
+        });
+        builder.sublink_speed_attributes.reverse();
+        assert_eq!(builder.build(), error);
+        builder.sublink_speed_attributes = vec![attribute(0, true, SublinkDirection::Rx)];
+        assert_eq!(builder.build(), error);
+        builder.sublink_speed_attributes = vec![
+            attribute(0, true, SublinkDirection::Rx),
+            attribute(0, false, SublinkDirection::Tx),
+        ];
+        assert_eq!(builder.build(), error);
+
+        builder.sublink_speed_attributes = vec![
+            attribute(1, true, SublinkDirection::Rx),
+            attribute(1, true, SublinkDirection::Tx),
+        ];
+        assert_eq!(
+            builder.build(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "wFunctionalitySupport",
+                value: 0
+            })
+        );
+
+        builder.sublink_speed_attributes.clear();
+        assert_eq!(
+            builder.build(),
+            Err(Error::Missing {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bmSublinkSpeedAttr"
+            })
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use crate::{error::Error, speed::Speed};
+
+use super::{
+    device_capability_descriptor::{DeviceCapabilityDescriptor, DEVICE_CAPABILITY_DESCRIPTOR_TYPE},
+    device_capability_type::DeviceCapabilityType,
+};
+
+/// The SuperSpeed USB capability every SuperSpeed device reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuperSpeedUsbBuilder {
+    /// Turns into the LTM bit of `bmAttributes`
+    pub latency_tolerance_messages: bool,
+    /// Turns into `wSpeedsSupported`, low to SuperSpeed
+    pub speeds_supported: Vec<Speed>,
+    /// Turns into `bFunctionalitySupport`, the lowest speed with all functionality
+    pub functionality_support: Speed,
+    /// Turns into `bU1DevExitLat` in µs, at most 10
+    pub u1_device_exit_latency: u8,
+    /// Turns into `wU2DevExitLat` in µs, at most 2047
+    pub u2_device_exit_latency: u16,
+}
+
+impl SuperSpeedUsbBuilder {
+    pub fn build(&self) -> Result<DeviceCapabilityDescriptor, Error> {
+        let mut speeds_supported = 0u16;
+        for speed in self.speeds_supported.iter() {
+            speeds_supported |= 1 << speed_bit(*speed, "wSpeedsSupported")?;
+        }
+        let functionality_support = speed_bit(self.functionality_support, "bFunctionalitySupport")?;
+        if !self.speeds_supported.contains(&self.functionality_support) {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bFunctionalitySupport",
+                value: functionality_support as u32,
+            });
+        }
+        if self.u1_device_exit_latency > 0x0A {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bU1DevExitLat",
+                value: self.u1_device_exit_latency as u32,
+            });
+        }
+        if self.u2_device_exit_latency > 0x07FF {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "wU2DevExitLat",
+                value: self.u2_device_exit_latency as u32,
+            });
+        }
+
+        let mut data = Vec::<u8>::new();
+        data.push((self.latency_tolerance_messages as u8) << 1);
+        data.extend_from_slice(&speeds_supported.to_le_bytes());
+        data.push(functionality_support);
+        data.push(self.u1_device_exit_latency);
+        data.extend_from_slice(&self.u2_device_exit_latency.to_le_bytes());
+        Ok(DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::SuperSpeedUsb,
+            data,
+        })
+    }
+}
+
+/// The bit of `wSpeedsSupported`, also the value of `bFunctionalitySupport`
+fn speed_bit(speed: Speed, field: &'static str) -> Result<u8, Error> {
+    match speed {
+        Speed::Low => Ok(0),
+        Speed::Full => Ok(1),
+        Speed::High => Ok(2),
+        Speed::Super => Ok(3),
+        // reported by the SuperSpeedPlus capability instead
+        Speed::SuperPlus => Err(Error::FieldOutOfRange {
+            descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+            field,
+            value: 4,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptor::Descriptor;
+
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let mut builder = SuperSpeedUsbBuilder {
+            latency_tolerance_messages: false,
+            speeds_supported: vec![Speed::Full, Speed::High, Speed::Super],
+            functionality_support: Speed::Full,
+            u1_device_exit_latency: 0x0A,
+            u2_device_exit_latency: 0x07FF,
+        };
+        assert_eq!(
+            builder.build().unwrap().encode().unwrap(),
+            vec![0x0A, 0x10, 0x03, 0x00, 0x0E, 0x00, 0x01, 0x0A, 0xFF, 0x07]
+        );
+
+        builder.functionality_support = Speed::Low;
+        assert_eq!(
+            builder.build(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bFunctionalitySupport",
+                value: 0
+            })
+        );
+
+        builder.functionality_support = Speed::Full;
+        builder.u1_device_exit_latency = 0x0B;
+        assert_eq!(
+            builder.build(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bU1DevExitLat",
+                value: 0x0B
+            })
+        );
+    }
+}
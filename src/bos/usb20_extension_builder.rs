@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use crate::error::Error;
+
+use super::{
+    device_capability_descriptor::{DeviceCapabilityDescriptor, DEVICE_CAPABILITY_DESCRIPTOR_TYPE},
+    device_capability_type::DeviceCapabilityType,
+};
+
+/// The USB 2.0 Extension capability of USB 2.0 LPM ECN
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Usb20ExtensionBuilder {
+    /// Turns into the LPM bit of `bmAttributes`
+    pub link_power_management: bool,
+    /// Turns into the BESL and alternate HIRD definitions bit of `bmAttributes`
+    pub besl: bool,
+    /// Turns into the recommended Baseline BESL value, 0 to 15
+    pub baseline_besl: Option<u8>,
+    /// Turns into the recommended Deep BESL value, 0 to 15
+    pub deep_besl: Option<u8>,
+}
+
+impl Usb20ExtensionBuilder {
+    pub fn build(&self) -> Result<DeviceCapabilityDescriptor, Error> {
+        let mut attributes = 0u32;
+        if self.link_power_management {
+            attributes |= 1 << 1;
+        }
+        if self.besl {
+            if !self.link_power_management {
+                return Err(Error::Missing {
+                    descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                    field: "LPM",
+                });
+            }
+            attributes |= 1 << 2;
+        }
+        for (besl, valid, shift) in [(self.baseline_besl, 3, 8), (self.deep_besl, 4, 12)] {
+            let besl = match besl {
+                Some(besl) => besl,
+                None => continue,
+            };
+            if !self.besl {
+                return Err(Error::Missing {
+                    descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                    field: "BESL",
+                });
+            }
+            if besl > 0x0F {
+                return Err(Error::FieldOutOfRange {
+                    descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                    field: "bmAttributes",
+                    value: besl as u32,
+                });
+            }
+            attributes |= 1 << valid | (besl as u32) << shift;
+        }
+
+        Ok(DeviceCapabilityDescriptor {
+            capability_type: DeviceCapabilityType::Usb20Extension,
+            data: Vec::from(attributes.to_le_bytes()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptor::Descriptor;
+
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let mut builder = Usb20ExtensionBuilder {
+            link_power_management: true,
+            besl: true,
+            baseline_besl: Some(0x04),
+            deep_besl: Some(0x0A),
+        };
+        assert_eq!(
+            builder.build().unwrap().encode().unwrap(),
+            vec![0x07, 0x10, 0x02, 0x1E, 0xA4, 0x00, 0x00]
+        );
+
+        builder.deep_besl = Some(0x10);
+        assert_eq!(
+            builder.build(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "bmAttributes",
+                value: 0x10
+            })
+        );
+
+        builder.link_power_management = false;
+        assert_eq!(
+            builder.build(),
+            Err(Error::Missing {
+                descriptor: DEVICE_CAPABILITY_DESCRIPTOR_TYPE,
+                field: "LPM"
+            })
+        );
+    }
+}
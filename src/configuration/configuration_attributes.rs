@@ -1,4 +1,7 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ConfigurationAttributes {
@@ -7,7 +10,7 @@ pub struct ConfigurationAttributes {
 }
 
 impl EncodeByte for ConfigurationAttributes {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         // D7 Reserved, set to 1. (USB 1.0 Bus Powered)
         // D6 Self Powered
         // D5 Remote Wakeup
@@ -19,6 +22,21 @@ impl EncodeByte for ConfigurationAttributes {
     }
 }
 
+impl DecodeByte for ConfigurationAttributes {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        if byte & 0b1001_1111 != 0b1000_0000 {
+            return Err(DecodeError::InvalidValue {
+                field: "bmAttributes",
+                value: byte as u16,
+            });
+        }
+        Ok(ConfigurationAttributes {
+            self_powered: byte & 0b0100_0000 != 0,
+            remote_wakeup: byte & 0b0010_0000 != 0,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,19 +48,40 @@ mod tests {
             remote_wakeup: false,
         };
         assert_eq!(attributes.encode().unwrap(), 0b1100_0000);
-        
+
         let attributes = ConfigurationAttributes {
             self_powered: false,
             remote_wakeup: true,
         };
-        
+
         assert_eq!(attributes.encode().unwrap(), 0b1010_0000);
-        
+
         let attributes = ConfigurationAttributes {
             self_powered: true,
             remote_wakeup: true,
         };
-        
+
         assert_eq!(attributes.encode().unwrap(), 0b1110_0000);
     }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            ConfigurationAttributes::decode(0b1010_0000),
+            Ok(ConfigurationAttributes {
+                self_powered: false,
+                remote_wakeup: true,
+            })
+        );
+        assert_eq!(
+            ConfigurationAttributes::decode(0b1100_0000),
+            Ok(ConfigurationAttributes {
+                self_powered: true,
+                remote_wakeup: false,
+            })
+        );
+        // D7 must be set and D4..0 must be clear
+        assert!(ConfigurationAttributes::decode(0b0100_0000).is_err());
+        assert!(ConfigurationAttributes::decode(0b1000_0001).is_err());
+    }
 }
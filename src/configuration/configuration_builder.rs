@@ -1,12 +1,32 @@
 use alloc::vec::Vec;
 
 use crate::{
-    interface::interface_builder::InterfaceBuilder, string::string_builder::StringBuidler,
+    binary::{split_descriptor, DecodeByte},
+    descriptor::DecodeDescriptor,
+    descriptor_type::DescriptorType,
+    endpoint::{
+        endpoint_builder::EndpointBuilder, endpoint_descriptor::EndpointDescriptor,
+        super_speed_endpoint_companion_descriptor::SuperSpeedEndpointCompanionDescriptor,
+        super_speed_plus_isochronous_endpoint_companion_descriptor::SuperSpeedPlusIsochronousEndpointCompanionDescriptor,
+    },
+    error::{DecodeError, Error},
+    hid::{hid_builder::HidBuilder, hid_descriptor::HidDescriptor},
+    interface::{
+        alternate_settings_builder::AlternateSettingsBuilder, interface_builder::InterfaceBuilder,
+        interface_descriptor::InterfaceDescriptor,
+    },
+    interface_association::{
+        interface_association_builder::InterfaceAssociationBuilder,
+        interface_association_descriptor::InterfaceAssociationDescriptor,
+    },
+    speed::Speed,
+    string::{string_builder::StringBuidler, string_descriptor::StringDescriptor},
 };
 
 use super::{
     configuration_attributes::ConfigurationAttributes,
-    configuration_descriptor::ConfigurationDescriptor, milliamperes::Milliamperes,
+    configuration_descriptor::ConfigurationDescriptor, configuration_entry::ConfigurationEntry,
+    milliamperes::Milliamperes,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,21 +38,49 @@ pub struct ConfigurationBuilder {
     /// Max power consumption of the USB device from the bus in milliamperes. (Will be divided by to to fit u8 later)
     pub max_power: Milliamperes,
     /// Turns into `configuration`
-    pub configuration: StringBuidler,
-    /// Turns into `num_interfaces`
-    pub interfaces: Vec<InterfaceBuilder>,
+    pub configuration: Option<StringBuidler>,
+    /// Turns into `num_interfaces` and the interface association descriptors.
+    /// Interfaces are numbered in order, an association can sit at any position.
+    pub interfaces: Vec<ConfigurationEntry>,
 }
 
 impl ConfigurationBuilder {
+    /// The interface with `bInterfaceNumber` `number`
+    pub fn interface(&self, number: usize) -> Option<&AlternateSettingsBuilder> {
+        self.interfaces
+            .iter()
+            .flat_map(|entry| entry.interfaces())
+            .nth(number)
+    }
+
+    pub fn interface_mut(&mut self, number: usize) -> Option<&mut AlternateSettingsBuilder> {
+        self.interfaces
+            .iter_mut()
+            .flat_map(|entry| entry.interfaces_mut())
+            .nth(number)
+    }
+
+    /// Whether the configuration has interface association descriptors
+    pub fn has_associations(&self) -> bool {
+        self.interfaces
+            .iter()
+            .any(|entry| matches!(entry, ConfigurationEntry::Association(_)))
+    }
+
+    /// `speed` is the speed the configuration is served at, it sets the unit of `bMaxPower`
     pub fn build(
         &self,
+        speed: Speed,
         total_length: u16,
         num_interfaces: u8,
         configuration_value: u8,
         configuration: u8,
-    ) -> Result<ConfigurationDescriptor, &str> {
+    ) -> Result<ConfigurationDescriptor, Error> {
         if num_interfaces == 0 {
-            return Err("ConfigurationDescriptor needs at least 1 interface.");
+            return Err(Error::Missing {
+                descriptor: DescriptorType::Configuration,
+                field: "interfaces",
+            });
         }
 
         Ok(ConfigurationDescriptor {
@@ -40,8 +88,435 @@ impl ConfigurationBuilder {
             num_interfaces,
             configuration_value,
             configuration,
-            attributes: self.attributes.clone(),
-            max_power: self.max_power,
+            attributes: self.attributes,
+            max_power: self.max_power.encode(speed)?,
         })
     }
+
+    /// Rebuilds a configuration from the `wTotalLength` bytes a device returns for
+    /// GET_DESCRIPTOR(Configuration). Descriptors this crate does not model (e.g. class
+    /// specific descriptors) are skipped. `speed` is the speed the device was read at.
+    /// `reports` holds the GET_DESCRIPTOR(Report) response of HID interfaces as
+    /// `(bConfigurationValue, bInterfaceNumber, report descriptor)`.
+    pub fn decode(
+        bytes: &[u8],
+        speed: Speed,
+        strings: &[StringDescriptor],
+        reports: &[(u8, u8, &[u8])],
+    ) -> Result<ConfigurationBuilder, DecodeError> {
+        let descriptor = ConfigurationDescriptor::decode(bytes)?;
+        let total_length = descriptor.total_length as usize;
+        if bytes.len() < total_length {
+            return Err(DecodeError::Truncated {
+                expected: total_length,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut settings = Vec::<InterfaceBuilder>::new();
+        let mut num_endpoints = Vec::<u8>::new();
+        let mut numbers = Vec::<(u8, u8)>::new();
+        let mut associations =
+            Vec::<(InterfaceAssociationDescriptor, InterfaceAssociationBuilder)>::new();
+        let (_, mut rest) = split_descriptor(&bytes[..total_length])?;
+        while !rest.is_empty() {
+            let (current, next) = split_descriptor(rest)?;
+            rest = next;
+            match DescriptorType::decode(current[1]) {
+                Ok(DescriptorType::Interface) => {
+                    let interface = InterfaceDescriptor::decode(current)?;
+                    numbers.push((interface.interface_number, interface.alternate_setting));
+                    num_endpoints.push(interface.num_endpoints);
+                    settings.push(InterfaceBuilder::from_descriptor(&interface, strings)?);
+                }
+                Ok(DescriptorType::Endpoint) => {
+                    let endpoint = EndpointDescriptor::decode(current)?;
+                    let error = DecodeError::UnexpectedDescriptorType {
+                        expected: DescriptorType::Interface,
+                        actual: DescriptorType::Endpoint,
+                    };
+                    let interface = settings.last_mut().ok_or(error)?;
+                    interface
+                        .endpoints
+                        .push(EndpointBuilder::from_descriptor(&endpoint, speed));
+                }
+                Ok(DescriptorType::SuperSpeedEndpointCompanion) => {
+                    let companion = SuperSpeedEndpointCompanionDescriptor::decode(current)?;
+                    let error = DecodeError::UnexpectedDescriptorType {
+                        expected: DescriptorType::Endpoint,
+                        actual: DescriptorType::SuperSpeedEndpointCompanion,
+                    };
+                    let endpoint = settings
+                        .last_mut()
+                        .and_then(|interface| interface.endpoints.last_mut())
+                        .ok_or(error)?;
+                    endpoint.apply_companion(&companion);
+                }
+                Ok(DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion) => {
+                    let companion =
+                        SuperSpeedPlusIsochronousEndpointCompanionDescriptor::decode(current)?;
+                    let error = DecodeError::UnexpectedDescriptorType {
+                        expected: DescriptorType::SuperSpeedEndpointCompanion,
+                        actual: DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion,
+                    };
+                    let endpoint = settings
+                        .last_mut()
+                        .and_then(|interface| interface.endpoints.last_mut())
+                        .ok_or(error)?;
+                    endpoint.super_speed_plus_bytes_per_interval =
+                        Some(companion.bytes_per_interval);
+                }
+                Ok(DescriptorType::InterfaceAssociation) => {
+                    let association = InterfaceAssociationDescriptor::decode(current)?;
+                    let builder =
+                        InterfaceAssociationBuilder::from_descriptor(&association, strings)?;
+                    associations.push((association, builder));
+                }
+                Ok(DescriptorType::Hid) => {
+                    let hid = HidDescriptor::decode(current)?;
+                    let error = DecodeError::UnexpectedDescriptorType {
+                        expected: DescriptorType::Interface,
+                        actual: DescriptorType::Hid,
+                    };
+                    let interface = settings.last_mut().ok_or(error)?;
+                    let (interface_number, _) = numbers[numbers.len() - 1];
+                    let (_, _, report) = reports
+                        .iter()
+                        .find(|(configuration_value, number, _)| {
+                            *configuration_value == descriptor.configuration_value
+                                && *number == interface_number
+                        })
+                        .ok_or(DecodeError::MissingReport {
+                            configuration_value: descriptor.configuration_value,
+                            interface_number,
+                        })?;
+                    interface.hid = Some(HidBuilder::from_descriptor(&hid, report)?);
+                }
+                Ok(DescriptorType::Report) | Err(_) => {}
+                Ok(actual) => {
+                    return Err(DecodeError::UnexpectedDescriptorType {
+                        expected: DescriptorType::Interface,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        for (interface, num_endpoints) in settings.iter().zip(num_endpoints) {
+            if interface.endpoints.len() != num_endpoints as usize {
+                return Err(DecodeError::InvalidValue {
+                    field: "bNumEndpoints",
+                    value: num_endpoints as u16,
+                });
+            }
+        }
+
+        // Interfaces and alternate settings are numbered by position when building,
+        // so they have to be numbered consecutively from zero.
+        let mut interfaces = Vec::<AlternateSettingsBuilder>::new();
+        for ((interface_number, alternate_setting), interface) in numbers.into_iter().zip(settings)
+        {
+            let number = interface_number as usize;
+            if number == interfaces.len() {
+                interfaces.push(AlternateSettingsBuilder {
+                    alternate_settings: Vec::new(),
+                });
+            }
+            let alternate_settings = match interfaces.get_mut(number) {
+                Some(alternate_settings) => &mut alternate_settings.alternate_settings,
+                None => {
+                    return Err(DecodeError::InvalidValue {
+                        field: "bInterfaceNumber",
+                        value: interface_number as u16,
+                    })
+                }
+            };
+            if alternate_setting as usize != alternate_settings.len() {
+                return Err(DecodeError::InvalidValue {
+                    field: "bAlternateSetting",
+                    value: alternate_setting as u16,
+                });
+            }
+            alternate_settings.push(interface);
+        }
+        if interfaces.len() != descriptor.num_interfaces as usize {
+            return Err(DecodeError::InvalidValue {
+                field: "bNumInterfaces",
+                value: descriptor.num_interfaces as u16,
+            });
+        }
+
+        // Associations take their interfaces out of the numbered interfaces, in order
+        let num_interfaces = interfaces.len();
+        let mut remaining = interfaces.into_iter();
+        let mut entries = Vec::<ConfigurationEntry>::new();
+        let mut interface_number = 0;
+        for (association, mut builder) in associations {
+            let first_interface = association.first_interface as usize;
+            if first_interface < interface_number || first_interface >= num_interfaces {
+                return Err(DecodeError::InvalidValue {
+                    field: "bFirstInterface",
+                    value: association.first_interface as u16,
+                });
+            }
+            let count = association.interface_count as usize;
+            if count == 0 || first_interface + count > num_interfaces {
+                return Err(DecodeError::InvalidValue {
+                    field: "bInterfaceCount",
+                    value: association.interface_count as u16,
+                });
+            }
+            entries.extend(
+                remaining
+                    .by_ref()
+                    .take(first_interface - interface_number)
+                    .map(ConfigurationEntry::Interface),
+            );
+            builder.interfaces = remaining.by_ref().take(count).collect();
+            interface_number = first_interface + count;
+            entries.push(ConfigurationEntry::Association(builder));
+        }
+        entries.extend(remaining.map(ConfigurationEntry::Interface));
+
+        Ok(ConfigurationBuilder {
+            configuration_value: descriptor.configuration_value,
+            attributes: descriptor.attributes,
+            max_power: Milliamperes::decode(descriptor.max_power, speed)?,
+            configuration: StringBuidler::resolve(descriptor.configuration, strings)?,
+            interfaces: entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        endpoint::{
+            address_assignment::AddressAssignment, direction::Direction,
+            endpoint_attributes::EndpointAttributes, interval::Interval, sync_type::SyncType,
+            transfer_type::TransferType, usage_type::UsageType,
+        },
+        hid::boot_protocol::BootProtocol,
+        interface::interface_class::InterfaceClass,
+    };
+
+    use super::*;
+
+    /// The report descriptor announced by `MOUSE_CONFIGURATION`
+    fn mouse_report() -> Vec<u8> {
+        BootProtocol::Mouse.report().build().unwrap().items
+    }
+
+    const MOUSE_CONFIGURATION: [u8; 34] = [
+        0x09, 0x02, 0x22, 0x00, 0x01, 0x01, 0x00, 0xa0, 0x32, // Configuration
+        0x09, 0x04, 0x00, 0x00, 0x01, 0x03, 0x01, 0x02, 0x04, // Interface
+        0x09, 0x21, 0x11, 0x01, 0x00, 0x01, 0x22, 0x30, 0x00, // HID
+        0x07, 0x05, 0x81, 0x03, 0x04, 0x00, 0x0a, // Endpoint
+    ];
+
+    #[test]
+    fn test_decode() {
+        let strings = vec![StringBuidler::text("Mouse").build(4)];
+        let report = mouse_report();
+        let reports = [(1, 0, report.as_slice())];
+        let configuration =
+            ConfigurationBuilder::decode(&MOUSE_CONFIGURATION, Speed::Full, &strings, &reports)
+                .unwrap();
+
+        assert_eq!(
+            configuration,
+            ConfigurationBuilder {
+                configuration_value: 1,
+                attributes: ConfigurationAttributes {
+                    self_powered: false,
+                    remote_wakeup: true,
+                },
+                max_power: Milliamperes(100),
+                configuration: None,
+                interfaces: vec![ConfigurationEntry::Interface(
+                    AlternateSettingsBuilder::single(InterfaceBuilder {
+                        interface_class: InterfaceClass::HumanInterfaceDevice,
+                        interface_suclass: 0x01,
+                        interface_protocol: 0x02,
+                        interface: Some(StringBuidler::text("Mouse")),
+                        hid: Some(BootProtocol::Mouse.hid()),
+                        endpoints: vec![EndpointBuilder {
+                            endpoint_address: AddressAssignment::fixed(1, Direction::In),
+                            attributes: EndpointAttributes {
+                                transfer_type: TransferType::Interrupt,
+                                sync_type: SyncType::NoSync,
+                                usage_type: UsageType::Data,
+                            },
+                            max_packet_size: 4,
+                            interval: Interval::Raw(10),
+                            high_speed_max_packet_size: None,
+                            high_speed_interval: None,
+                            high_speed_transactions: None,
+                            super_speed_max_packet_size: None,
+                            super_speed_interval: None,
+                            max_burst: None,
+                            max_streams: None,
+                            mult: None,
+                            bytes_per_interval: None,
+                            super_speed_plus_bytes_per_interval: None,
+                        }],
+                    })
+                )],
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let strings = vec![StringBuidler::text("Mouse").build(4)];
+        let report = mouse_report();
+        let reports = [(1, 0, report.as_slice())];
+
+        assert_eq!(
+            ConfigurationBuilder::decode(&MOUSE_CONFIGURATION, Speed::Full, &strings, &[]),
+            Err(DecodeError::MissingReport {
+                configuration_value: 1,
+                interface_number: 0
+            })
+        );
+        assert_eq!(
+            ConfigurationBuilder::decode(
+                &MOUSE_CONFIGURATION,
+                Speed::Full,
+                &strings,
+                &[(1, 0, &report[1..])]
+            ),
+            Err(DecodeError::InvalidValue {
+                field: "wDescriptorLength",
+                value: 0x30
+            })
+        );
+
+        assert_eq!(
+            ConfigurationBuilder::decode(
+                &MOUSE_CONFIGURATION[..30],
+                Speed::Full,
+                &strings,
+                &reports
+            ),
+            Err(DecodeError::Truncated {
+                expected: 34,
+                actual: 30
+            })
+        );
+        assert_eq!(
+            ConfigurationBuilder::decode(&MOUSE_CONFIGURATION, Speed::Full, &[], &reports),
+            Err(DecodeError::MissingString(4))
+        );
+
+        let mut wrong_num_endpoints = MOUSE_CONFIGURATION;
+        wrong_num_endpoints[13] = 2;
+        assert_eq!(
+            ConfigurationBuilder::decode(&wrong_num_endpoints, Speed::Full, &strings, &reports),
+            Err(DecodeError::InvalidValue {
+                field: "bNumEndpoints",
+                value: 2
+            })
+        );
+
+        let mut wrong_num_interfaces = MOUSE_CONFIGURATION;
+        wrong_num_interfaces[4] = 2;
+        assert_eq!(
+            ConfigurationBuilder::decode(&wrong_num_interfaces, Speed::Full, &strings, &reports),
+            Err(DecodeError::InvalidValue {
+                field: "bNumInterfaces",
+                value: 2
+            })
+        );
+
+        let mut wrong_interface_number = MOUSE_CONFIGURATION;
+        wrong_interface_number[11] = 1;
+        let reports_of_interface_1 = [(1, 1, report.as_slice())];
+        assert_eq!(
+            ConfigurationBuilder::decode(
+                &wrong_interface_number,
+                Speed::Full,
+                &strings,
+                &reports_of_interface_1
+            ),
+            Err(DecodeError::InvalidValue {
+                field: "bInterfaceNumber",
+                value: 1
+            })
+        );
+
+        let mut wrong_alternate_setting = MOUSE_CONFIGURATION;
+        wrong_alternate_setting[12] = 1;
+        assert_eq!(
+            ConfigurationBuilder::decode(&wrong_alternate_setting, Speed::Full, &strings, &reports),
+            Err(DecodeError::InvalidValue {
+                field: "bAlternateSetting",
+                value: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_alternate_settings() {
+        let configuration = [
+            0x09, 0x02, 0x22, 0x00, 0x01, 0x01, 0x00, 0x80, 0x32, // Configuration
+            0x09, 0x04, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, // Interface 0, setting 0
+            0x09, 0x04, 0x00, 0x01, 0x01, 0x01, 0x02, 0x00, 0x00, // Interface 0, setting 1
+            0x07, 0x05, 0x81, 0x05, 0xc0, 0x00, 0x01, // Endpoint
+        ];
+        let configuration =
+            ConfigurationBuilder::decode(&configuration, Speed::Full, &[], &[]).unwrap();
+
+        assert_eq!(configuration.interfaces.len(), 1);
+        let alternate_settings = &configuration.interface(0).unwrap().alternate_settings;
+        assert_eq!(alternate_settings.len(), 2);
+        assert!(alternate_settings[0].endpoints.is_empty());
+        assert_eq!(alternate_settings[1].endpoints.len(), 1);
+    }
+
+    #[test]
+    fn test_decode_association_after_interface() {
+        let mut configuration = [
+            0x09, 0x02, 0x2c, 0x00, 0x03, 0x01, 0x00, 0x80, 0x32, // Configuration
+            0x09, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // HID interface 0
+            0x08, 0x0b, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // Association of 1 and 2
+            0x09, 0x04, 0x01, 0x00, 0x00, 0x02, 0x02, 0x01, 0x00, // CDC interface 1
+            0x09, 0x04, 0x02, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, // CDC data interface 2
+        ];
+        let decoded = ConfigurationBuilder::decode(&configuration, Speed::Full, &[], &[]).unwrap();
+
+        assert_eq!(decoded.interfaces.len(), 2);
+        assert!(matches!(
+            decoded.interfaces[0],
+            ConfigurationEntry::Interface(_)
+        ));
+        match &decoded.interfaces[1] {
+            ConfigurationEntry::Association(association) => {
+                assert_eq!(association.interfaces.len(), 2)
+            }
+            entry => panic!("Expected an association, got {:?}", entry),
+        }
+        assert!(decoded.has_associations());
+        assert_eq!(
+            decoded.interface(2).unwrap().alternate_settings[0].interface_class,
+            InterfaceClass::CdcData
+        );
+
+        configuration[21] = 0x03;
+        assert_eq!(
+            ConfigurationBuilder::decode(&configuration, Speed::Full, &[], &[]),
+            Err(DecodeError::InvalidValue {
+                field: "bInterfaceCount",
+                value: 3
+            })
+        );
+        configuration[20] = 0x03;
+        assert_eq!(
+            ConfigurationBuilder::decode(&configuration, Speed::Full, &[], &[]),
+            Err(DecodeError::InvalidValue {
+                field: "bFirstInterface",
+                value: 3
+            })
+        );
+    }
 }
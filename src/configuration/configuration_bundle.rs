@@ -0,0 +1,131 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    binary::EncodeByte, descriptor::Descriptor, descriptor_type::DescriptorType, error::Error,
+};
+
+use super::configuration_descriptor::{ConfigurationDescriptor, CONFIGURATION_DESCRIPTOR_TYPE};
+
+/// The response to GET_DESCRIPTOR(Configuration): the configuration descriptor followed by
+/// all of its interface, class specific and endpoint descriptors.
+pub struct ConfigurationBundle {
+    /// Configuration, or Other Speed Configuration for the bundle as it looks at the speed
+    /// the device does not currently operate at
+    pub descriptor_type: DescriptorType,
+    /// Turns into the leading configuration descriptor. `wTotalLength` is computed on encode.
+    pub configuration: ConfigurationDescriptor,
+    /// Descriptors following the configuration descriptor in the order they are sent
+    pub descriptors: Vec<Box<dyn Descriptor>>,
+}
+
+impl Descriptor for ConfigurationBundle {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = self.configuration.encode()?;
+        bytes[1] = match self.descriptor_type {
+            DescriptorType::Configuration | DescriptorType::OtherSpeedConfiguration => {
+                self.descriptor_type.encode()?
+            }
+            descriptor_type => {
+                return Err(Error::FieldOutOfRange {
+                    descriptor: CONFIGURATION_DESCRIPTOR_TYPE,
+                    field: "bDescriptorType",
+                    value: descriptor_type.encode()? as u32,
+                })
+            }
+        };
+        for descriptor in self.descriptors.iter() {
+            bytes.append(&mut descriptor.encode()?);
+        }
+
+        let total_length: u16 = match bytes.len().try_into() {
+            Ok(total_length) => total_length,
+            Err(_) => {
+                return Err(Error::LengthOverflow {
+                    descriptor: CONFIGURATION_DESCRIPTOR_TYPE,
+                    field: "wTotalLength",
+                    length: bytes.len(),
+                })
+            }
+        };
+        // wTotalLength
+        bytes[2..4].copy_from_slice(&total_length.to_le_bytes());
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        self.descriptor_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        configuration::configuration_attributes::ConfigurationAttributes,
+        endpoint::{
+            direction::Direction, endpoint_address::EndpointAddress,
+            endpoint_attributes::EndpointAttributes, endpoint_descriptor::EndpointDescriptor,
+            sync_type::SyncType, transfer_type::TransferType, usage_type::UsageType,
+        },
+        interface::{interface_class::InterfaceClass, interface_descriptor::InterfaceDescriptor},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let mut bundle = ConfigurationBundle {
+            descriptor_type: DescriptorType::Configuration,
+            configuration: ConfigurationDescriptor {
+                total_length: 0,
+                num_interfaces: 1,
+                configuration_value: 1,
+                configuration: 0,
+                attributes: ConfigurationAttributes {
+                    self_powered: false,
+                    remote_wakeup: false,
+                },
+                max_power: 50,
+            },
+            descriptors: vec![
+                Box::new(InterfaceDescriptor {
+                    interface_number: 0,
+                    alternate_setting: 0,
+                    num_endpoints: 1,
+                    interface_class: InterfaceClass::VendorSpecific,
+                    interface_suclass: 0,
+                    interface_protocol: 0,
+                    interface: 0,
+                }),
+                Box::new(EndpointDescriptor {
+                    length: 7,
+                    endpoint_address: EndpointAddress {
+                        endpoint_number: 1,
+                        direction: Direction::In,
+                    },
+                    attributes: EndpointAttributes {
+                        transfer_type: TransferType::Bulk,
+                        sync_type: SyncType::NoSync,
+                        usage_type: UsageType::Data,
+                    },
+                    max_packet_size: 64,
+                    interval: 0,
+                }),
+            ],
+        };
+
+        assert_eq!(
+            bundle.encode().unwrap(),
+            vec![
+                0x09, 0x02, 25, 0x00, 0x01, 0x01, 0x00, 0x80, 50, // Configuration
+                0x09, 0x04, 0x00, 0x00, 0x01, 0xff, 0x00, 0x00, 0x00, // Interface
+                0x07, 0x05, 0x81, 0x02, 0x40, 0x00, 0x00, // Endpoint
+            ]
+        );
+
+        bundle.descriptor_type = DescriptorType::OtherSpeedConfiguration;
+        assert_eq!(bundle.encode().unwrap()[..4], [0x09, 0x07, 25, 0x00]);
+        bundle.descriptor_type = DescriptorType::Device;
+        assert!(bundle.encode().is_err());
+    }
+}
@@ -1,11 +1,19 @@
 use alloc::vec::Vec;
 
-use crate::{binary::EncodeByte, descriptor::Descriptor, descriptor_type::DescriptorType};
+use crate::{
+    binary::{expect_descriptor, DecodeByte, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
-use super::{configuration_attributes::ConfigurationAttributes, milliamperes::Milliamperes};
+use super::configuration_attributes::ConfigurationAttributes;
 
 pub const CONFIGURATION_DESCRIPTOR_LENGTH: u8 = 9;
 pub const CONFIGURATION_DESCRIPTOR_TYPE: DescriptorType = DescriptorType::Configuration;
+/// Same layout as the configuration descriptor, see `ConfigurationBundle`
+pub const OTHER_SPEED_CONFIGURATION_DESCRIPTOR_TYPE: DescriptorType =
+    DescriptorType::OtherSpeedConfiguration;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConfigurationDescriptor {
@@ -19,12 +27,12 @@ pub struct ConfigurationDescriptor {
     pub configuration: u8,
     /// Turns into `bmAttributes`
     pub attributes: ConfigurationAttributes,
-    /// Turns into `bMaxPower`
-    pub max_power: Milliamperes,
+    /// Turns into `bMaxPower`, in 2 mA units or 8 mA units at SuperSpeed, see `Milliamperes`
+    pub max_power: u8,
 }
 
 impl Descriptor for ConfigurationDescriptor {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
         let mut bytes = Vec::<u8>::new();
         bytes.push(CONFIGURATION_DESCRIPTOR_LENGTH);
         bytes.push(CONFIGURATION_DESCRIPTOR_TYPE.encode()?);
@@ -33,10 +41,14 @@ impl Descriptor for ConfigurationDescriptor {
         bytes.push(self.configuration_value);
         bytes.push(self.configuration);
         bytes.push(self.attributes.encode()?);
-        bytes.push(self.max_power.encode()?);
+        bytes.push(self.max_power);
 
         if bytes.len() != CONFIGURATION_DESCRIPTOR_LENGTH as usize {
-            return Err("configuration bLength not match the actual length");
+            return Err(Error::LengthMismatch {
+                descriptor: CONFIGURATION_DESCRIPTOR_TYPE,
+                expected: CONFIGURATION_DESCRIPTOR_LENGTH as usize,
+                actual: bytes.len(),
+            });
         }
 
         Ok(bytes)
@@ -47,13 +59,31 @@ impl Descriptor for ConfigurationDescriptor {
     }
 }
 
+impl DecodeDescriptor for ConfigurationDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            CONFIGURATION_DESCRIPTOR_TYPE,
+            Some(CONFIGURATION_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(ConfigurationDescriptor {
+            total_length: u16::from_le_bytes([bytes[2], bytes[3]]),
+            num_interfaces: bytes[4],
+            configuration_value: bytes[5],
+            configuration: bytes[6],
+            attributes: ConfigurationAttributes::decode(bytes[7])?,
+            max_power: bytes[8],
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_encode() {
-        let descriptor = ConfigurationDescriptor {
+    fn descriptor() -> ConfigurationDescriptor {
+        ConfigurationDescriptor {
             total_length: 34,
             num_interfaces: 1,
             configuration_value: 2,
@@ -62,11 +92,14 @@ mod tests {
                 self_powered: true,
                 remote_wakeup: false,
             },
-            max_power: Milliamperes(50),
-        };
+            max_power: 25,
+        }
+    }
 
+    #[test]
+    fn test_encode() {
         assert_eq!(
-            descriptor.encode().unwrap(),
+            descriptor().encode().unwrap(),
             vec![
                 9,           // bLength
                 2,           // bDescriptorType
@@ -80,4 +113,23 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_decode() {
+        let bytes = descriptor().encode().unwrap();
+        assert_eq!(ConfigurationDescriptor::decode(&bytes), Ok(descriptor()));
+
+        // trailing interface and endpoint descriptors are ignored
+        let mut bundle = bytes.clone();
+        bundle.extend_from_slice(&[9, 4, 0, 0, 1, 3, 0, 0, 0]);
+        assert_eq!(ConfigurationDescriptor::decode(&bundle), Ok(descriptor()));
+
+        assert_eq!(
+            ConfigurationDescriptor::decode(&bytes[..4]),
+            Err(DecodeError::Truncated {
+                expected: 9,
+                actual: 4
+            })
+        );
+    }
 }
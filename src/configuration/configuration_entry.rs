@@ -0,0 +1,44 @@
+use core::slice;
+
+use crate::{
+    interface::alternate_settings_builder::AlternateSettingsBuilder,
+    interface_association::interface_association_builder::InterfaceAssociationBuilder,
+};
+
+/// One entry of a configuration. Interfaces are numbered in the order of the entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigurationEntry {
+    /// An interface that belongs to no function
+    Interface(AlternateSettingsBuilder),
+    /// An interface association descriptor followed by its interfaces
+    Association(InterfaceAssociationBuilder),
+}
+
+impl ConfigurationEntry {
+    /// The interfaces of the entry in the order they are numbered
+    pub fn interfaces(&self) -> &[AlternateSettingsBuilder] {
+        match self {
+            ConfigurationEntry::Interface(interface) => slice::from_ref(interface),
+            ConfigurationEntry::Association(association) => &association.interfaces,
+        }
+    }
+
+    pub fn interfaces_mut(&mut self) -> &mut [AlternateSettingsBuilder] {
+        match self {
+            ConfigurationEntry::Interface(interface) => slice::from_mut(interface),
+            ConfigurationEntry::Association(association) => &mut association.interfaces,
+        }
+    }
+}
+
+impl From<AlternateSettingsBuilder> for ConfigurationEntry {
+    fn from(interface: AlternateSettingsBuilder) -> Self {
+        ConfigurationEntry::Interface(interface)
+    }
+}
+
+impl From<InterfaceAssociationBuilder> for ConfigurationEntry {
+    fn from(association: InterfaceAssociationBuilder) -> Self {
+        ConfigurationEntry::Association(association)
+    }
+}
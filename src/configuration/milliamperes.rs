@@ -1,20 +1,49 @@
-use crate::binary::EncodeByte;
+use crate::{
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+    speed::Speed,
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Milliamperes(pub u16);
 
-impl EncodeByte for Milliamperes {
-    fn encode(&self) -> Result<u8, &str> {
-        if self.0 > 500 {
-            return Err("Milliamperes cannot be greater than 500.");
+impl Milliamperes {
+    /// `bMaxPower` counts 2 mA units up to 500 mA, at SuperSpeed 8 mA units up to 900 mA
+    fn unit_and_maximum(speed: Speed) -> (u16, u16) {
+        match speed.is_super_speed() {
+            true => (8, 900),
+            false => (2, 500),
         }
+    }
 
-        let half = self.0 / 2;
+    /// Turns into `bMaxPower` at `speed`
+    pub fn encode(&self, speed: Speed) -> Result<u8, Error> {
+        let (unit, maximum) = Milliamperes::unit_and_maximum(speed);
+        if self.0 > maximum {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Configuration,
+                field: "bMaxPower",
+                value: self.0 as u32,
+            });
+        }
 
-        Ok(half as u8)
+        Ok((self.0 / unit) as u8)
     }
-}
 
+    /// Reads `bMaxPower` of a configuration descriptor read at `speed`
+    pub fn decode(byte: u8, speed: Speed) -> Result<Self, DecodeError> {
+        let (unit, maximum) = Milliamperes::unit_and_maximum(speed);
+        let milliamperes = byte as u16 * unit;
+        if milliamperes > maximum {
+            return Err(DecodeError::InvalidValue {
+                field: "bMaxPower",
+                value: byte as u16,
+            });
+        }
+
+        Ok(Milliamperes(milliamperes))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -22,10 +51,56 @@ mod tests {
 
     #[test]
     fn test_milliamperes_encode() {
-        assert_eq!(Milliamperes(0).encode().unwrap(), 0);
-        assert_eq!(Milliamperes(1).encode().unwrap(), 0);
-        assert_eq!(Milliamperes(2).encode().unwrap(), 1);
-        assert_eq!(Milliamperes(500).encode().unwrap(), 250);
-        assert_eq!(Milliamperes(501).encode(), Err("Milliamperes cannot be greater than 500."));
+        assert_eq!(Milliamperes(0).encode(Speed::Full).unwrap(), 0);
+        assert_eq!(Milliamperes(1).encode(Speed::Full).unwrap(), 0);
+        assert_eq!(Milliamperes(2).encode(Speed::Full).unwrap(), 1);
+        assert_eq!(Milliamperes(500).encode(Speed::High).unwrap(), 250);
+        assert_eq!(
+            Milliamperes(501).encode(Speed::Full),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Configuration,
+                field: "bMaxPower",
+                value: 501
+            })
+        );
+
+        assert_eq!(Milliamperes(500).encode(Speed::Super).unwrap(), 62);
+        assert_eq!(Milliamperes(900).encode(Speed::SuperPlus).unwrap(), 112);
+        assert_eq!(
+            Milliamperes(904).encode(Speed::Super),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Configuration,
+                field: "bMaxPower",
+                value: 904
+            })
+        );
+    }
+
+    #[test]
+    fn test_milliamperes_decode() {
+        assert_eq!(Milliamperes::decode(0, Speed::Full), Ok(Milliamperes(0)));
+        assert_eq!(
+            Milliamperes::decode(250, Speed::Full),
+            Ok(Milliamperes(500))
+        );
+        assert_eq!(
+            Milliamperes::decode(251, Speed::High),
+            Err(DecodeError::InvalidValue {
+                field: "bMaxPower",
+                value: 251
+            })
+        );
+
+        assert_eq!(
+            Milliamperes::decode(112, Speed::Super),
+            Ok(Milliamperes(896))
+        );
+        assert_eq!(
+            Milliamperes::decode(113, Speed::Super),
+            Err(DecodeError::InvalidValue {
+                field: "bMaxPower",
+                value: 113
+            })
+        );
     }
-}
\ No newline at end of file
+}
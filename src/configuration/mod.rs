@@ -1,4 +1,6 @@
 pub mod configuration_attributes;
-pub mod configuration_descriptor;
 pub mod configuration_builder;
+pub mod configuration_bundle;
+pub mod configuration_descriptor;
+pub mod configuration_entry;
 pub mod milliamperes;
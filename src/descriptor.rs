@@ -1,8 +1,16 @@
 use alloc::vec::Vec;
 
-use crate::descriptor_type::DescriptorType;
+use crate::{
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
 pub trait Descriptor {
-    fn encode(&self) -> Result<Vec<u8>, &str>;
+    fn encode(&self) -> Result<Vec<u8>, Error>;
     fn get_descriptor_type(&self) -> DescriptorType;
 }
+
+pub trait DecodeDescriptor: Sized {
+    /// Decodes the descriptor at the start of `bytes`. Bytes after `bLength` are ignored.
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError>;
+}
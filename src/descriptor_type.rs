@@ -1,6 +1,9 @@
 use core::fmt::Display;
 
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DescriptorType {
@@ -9,20 +12,30 @@ pub enum DescriptorType {
     String,
     Interface,
     Endpoint,
+    DeviceQualifier,
+    OtherSpeedConfiguration,
+    InterfaceAssociation,
+    Bos,
+    DeviceCapability,
+    SuperSpeedEndpointCompanion,
+    SuperSpeedPlusIsochronousEndpointCompanion,
     Hid,
     Report,
 }
 
 impl DescriptorType {
-    pub fn from_value(value: u16) -> Result<Self, &'static str> {
+    pub fn from_value(value: u16) -> Result<Self, DecodeError> {
         match value {
             0x0100 => Ok(DescriptorType::Device),
             0x0200 => Ok(DescriptorType::Configuration),
             0x0400 => Ok(DescriptorType::Interface),
             0x0500 => Ok(DescriptorType::Endpoint),
+            0x0600 => Ok(DescriptorType::DeviceQualifier),
+            0x0700 => Ok(DescriptorType::OtherSpeedConfiguration),
+            0x0F00 => Ok(DescriptorType::Bos),
             0x2100 => Ok(DescriptorType::Hid),
             0x2200 => Ok(DescriptorType::Report),
-            _ => Err("Invalid descriptor type"),
+            _ => Err(DecodeError::UnknownDescriptorType((value >> 8) as u8)),
         }
     }
 }
@@ -35,6 +48,17 @@ impl Display for DescriptorType {
             DescriptorType::String => write!(f, "String"),
             DescriptorType::Interface => write!(f, "Interface"),
             DescriptorType::Endpoint => write!(f, "Endpoint"),
+            DescriptorType::DeviceQualifier => write!(f, "Device Qualifier"),
+            DescriptorType::OtherSpeedConfiguration => write!(f, "Other Speed Configuration"),
+            DescriptorType::InterfaceAssociation => write!(f, "Interface Association"),
+            DescriptorType::Bos => write!(f, "BOS"),
+            DescriptorType::DeviceCapability => write!(f, "Device Capability"),
+            DescriptorType::SuperSpeedEndpointCompanion => {
+                write!(f, "SuperSpeed Endpoint Companion")
+            }
+            DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion => {
+                write!(f, "SuperSpeedPlus Isochronous Endpoint Companion")
+            }
             DescriptorType::Hid => write!(f, "Hid"),
             DescriptorType::Report => write!(f, "Report"),
         }
@@ -42,16 +66,45 @@ impl Display for DescriptorType {
 }
 
 impl EncodeByte for DescriptorType {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         match self {
             DescriptorType::Device => Ok(0x01),
             DescriptorType::Configuration => Ok(0x02),
             DescriptorType::String => Ok(0x03),
             DescriptorType::Interface => Ok(0x04),
             DescriptorType::Endpoint => Ok(0x05),
+            DescriptorType::DeviceQualifier => Ok(0x06),
+            DescriptorType::OtherSpeedConfiguration => Ok(0x07),
+            DescriptorType::InterfaceAssociation => Ok(0x0B),
+            DescriptorType::Bos => Ok(0x0F),
+            DescriptorType::DeviceCapability => Ok(0x10),
+            DescriptorType::SuperSpeedEndpointCompanion => Ok(0x30),
+            DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion => Ok(0x31),
             DescriptorType::Hid => Ok(0x21),
             DescriptorType::Report => Ok(0x22),
-        }   
+        }
+    }
+}
+
+impl DecodeByte for DescriptorType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x01 => Ok(DescriptorType::Device),
+            0x02 => Ok(DescriptorType::Configuration),
+            0x03 => Ok(DescriptorType::String),
+            0x04 => Ok(DescriptorType::Interface),
+            0x05 => Ok(DescriptorType::Endpoint),
+            0x06 => Ok(DescriptorType::DeviceQualifier),
+            0x07 => Ok(DescriptorType::OtherSpeedConfiguration),
+            0x0B => Ok(DescriptorType::InterfaceAssociation),
+            0x0F => Ok(DescriptorType::Bos),
+            0x10 => Ok(DescriptorType::DeviceCapability),
+            0x30 => Ok(DescriptorType::SuperSpeedEndpointCompanion),
+            0x31 => Ok(DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion),
+            0x21 => Ok(DescriptorType::Hid),
+            0x22 => Ok(DescriptorType::Report),
+            _ => Err(DecodeError::UnknownDescriptorType(byte)),
+        }
     }
 }
 
@@ -92,4 +145,28 @@ mod tests {
         let descriptor_type_endpoint = ENDPOINT_DESCRIPTOR_TYPE.encode().unwrap();
         assert_eq!(descriptor_type_endpoint, DESCRIPTOR_TYPE_ENDPOINT);
     }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            DescriptorType::decode(DESCRIPTOR_TYPE_DEVICE),
+            Ok(DescriptorType::Device)
+        );
+        assert_eq!(
+            DescriptorType::decode(DESCRIPTOR_TYPE_CONFIGURATION),
+            Ok(DescriptorType::Configuration)
+        );
+        assert_eq!(
+            DescriptorType::decode(DESCRIPTOR_TYPE_INTERFACE),
+            Ok(DescriptorType::Interface)
+        );
+        assert_eq!(
+            DescriptorType::decode(DESCRIPTOR_TYPE_ENDPOINT),
+            Ok(DescriptorType::Endpoint)
+        );
+        assert_eq!(
+            DescriptorType::decode(0x42),
+            Err(DecodeError::UnknownDescriptorType(0x42))
+        );
+    }
 }
@@ -1,34 +1,103 @@
 use alloc::vec::Vec;
 
-use crate::descriptors_builder::DescriptorsBuilder;
+use crate::{
+    descriptors_builder::DescriptorsBuilder, error::Error, request::setup_packet::SetupPacket,
+    speed::Speed,
+};
 
 pub struct Descriptors {
     pub descriptors: Vec<Vec<u8>>,
     pub w_values: Vec<u16>,
+    pub w_indexes: Vec<u16>,
+    /// The speed the descriptor is served at, `None` for all speeds
+    pub speeds: Vec<Option<Speed>>,
+    /// The `bConfigurationValue` the descriptor is served in, `None` for all configurations
+    pub configuration_values: Vec<Option<u8>>,
+    /// The speed the device operates at, full speed until `set_speed` is called
+    pub speed: Speed,
+    /// The selected `bConfigurationValue`. 0 until `set_configuration` is called, the first
+    /// configuration's descriptors are served until then.
+    pub configuration_value: u8,
 }
 
 impl Descriptors {
-    pub fn encode<'a>(builder: &DescriptorsBuilder) -> Result<Descriptors, &'a str> {
-        let descriptors: Vec<Vec<u8>> = builder
+    pub fn encode(builder: &DescriptorsBuilder) -> Result<Descriptors, Error> {
+        let descriptors = builder
             .descriptors
             .iter()
-            .map(|x| x.encode().unwrap()) // TODO: no unwrap
-            .collect();
+            .map(|x| x.encode())
+            .collect::<Result<Vec<Vec<u8>>, Error>>()?;
         Ok(Descriptors {
             descriptors,
             w_values: builder.w_values.clone(),
+            w_indexes: builder.w_indexes.clone(),
+            speeds: builder.speeds.clone(),
+            configuration_values: builder.configuration_values.clone(),
+            speed: Speed::Full,
+            configuration_value: 0,
         })
     }
 
+    /// Selects the device descriptor and configuration bundles for the speed the device
+    /// controller negotiated with the host
+    pub fn set_speed(&mut self, speed: Speed) {
+        self.speed = speed;
+    }
+
+    /// Selects the class descriptors, e.g. report descriptors, of the configuration the host
+    /// selected with SET_CONFIGURATION
+    pub fn set_configuration(&mut self, configuration_value: u8) {
+        self.configuration_value = configuration_value;
+    }
+
+    /// Whether descriptor `i` is served at the current speed and in the current configuration
+    fn is_served(&self, i: usize) -> bool {
+        let at_speed = match self.speeds.get(i) {
+            Some(Some(speed)) => *speed == self.speed,
+            _ => true,
+        };
+        let in_configuration = match self.configuration_values.get(i) {
+            Some(Some(configuration_value)) if self.configuration_value != 0 => {
+                *configuration_value == self.configuration_value
+            }
+            _ => true,
+        };
+        at_speed && in_configuration
+    }
+
     pub fn get_descriptor(&self, w_value: u16) -> Option<&Vec<u8>> {
         for i in 0..self.w_values.len() {
             let current_w_value = self.w_values.get(i)?;
-            if *current_w_value == w_value {
+            if *current_w_value == w_value && self.is_served(i) {
+                return self.descriptors.get(i);
+            }
+        }
+        None
+    }
+
+    /// Like `get_descriptor` but also matches `wIndex`, e.g. the language ID of a string
+    pub fn get_indexed_descriptor(&self, w_value: u16, w_index: u16) -> Option<&Vec<u8>> {
+        for i in 0..self.w_values.len() {
+            if self.w_values[i] == w_value
+                && self.w_indexes.get(i) == Some(&w_index)
+                && self.is_served(i)
+            {
                 return self.descriptors.get(i);
             }
         }
         None
     }
+
+    /// Answers a standard GET_DESCRIPTOR request. The response is truncated to `wLength`.
+    /// Returns `None` if the request should be stalled.
+    pub fn handle_get_descriptor(&self, setup: &SetupPacket) -> Option<&[u8]> {
+        if !setup.is_get_descriptor() {
+            return None;
+        }
+        let descriptor = self.get_indexed_descriptor(setup.value, setup.index)?;
+        let length = descriptor.len().min(setup.length as usize);
+        Some(&descriptor[..length])
+    }
 }
 
 #[cfg(test)]
@@ -36,70 +105,110 @@ mod tests {
     use alloc::vec::Vec;
 
     use crate::{
+        bos::{
+            bos_builder::BosBuilder, device_capability_builder::DeviceCapabilityBuilder,
+            lane_speed_exponent::LaneSpeedExponent, link_protocol::LinkProtocol,
+            sublink_direction::SublinkDirection, sublink_speed_attribute::SublinkSpeedAttribute,
+            sublink_type::SublinkType, super_speed_plus_builder::SuperSpeedPlusBuilder,
+            usb20_extension_builder::Usb20ExtensionBuilder,
+        },
         configuration::{
             configuration_attributes::ConfigurationAttributes,
-            configuration_builder::ConfigurationBuilder, milliamperes::Milliamperes,
+            configuration_builder::ConfigurationBuilder, configuration_entry::ConfigurationEntry,
+            milliamperes::Milliamperes,
         },
+        descriptor_type::DescriptorType,
         descriptors::{Descriptors, DescriptorsBuilder},
         device::{device_builder::DeviceBuilder, device_class::DeviceClass},
         endpoint::{
-            direction::Direction, endpoint_address::EndpointAddress,
+            address_assignment::AddressAssignment, direction::Direction,
             endpoint_attributes::EndpointAttributes, endpoint_builder::EndpointBuilder,
-            sync_type::SyncType, transfer_type::TransferType, usage_type::UsageType,
+            endpoint_pool::EndpointPool, interval::Interval, sync_type::SyncType,
+            transfer_type::TransferType, usage_type::UsageType,
+        },
+        error::Error,
+        hid::{hid_builder::HidBuilder, hid_descriptor::HID1_11},
+        interface::{
+            alternate_settings_builder::AlternateSettingsBuilder,
+            interface_builder::InterfaceBuilder, interface_class::InterfaceClass,
+        },
+        interface_association::interface_association_builder::InterfaceAssociationBuilder,
+        report::{
+            collection_type::CollectionType, global_item::GlobalItem, item::Item,
+            local_item::LocalItem, main_item::MainItem, report_builder::ReportBuilder,
         },
-        interface::{interface_builder::InterfaceBuilder, interface_class::InterfaceClass},
-        string::{language_code::EN_US, string_builder::StringBuidler},
-        version::USB2_0,
+        request::setup_packet::SetupPacket,
+        speed::Speed,
+        string::{
+            language_code::{DE_DE, EN_US},
+            string_builder::StringBuidler,
+            string_descriptor::StringDescriptor,
+        },
+        version::{USB1_1, USB2_0, USB2_1, USB3_0, USB3_2},
     };
 
-    #[test]
-    fn test_descriptor_store() {
-        let builder = DescriptorsBuilder::build(
-            &DeviceBuilder {
-                usb: USB2_0,
-                device_class: DeviceClass::Device,
-                device_suclass: 0x00,
-                device_protocol: 0x00,
-                max_packet_size_0: 0x40,
-                id_vendor: 0x1234,
-                id_product: 0x1234,
-                device: USB2_0,
-                manufacturer: StringBuidler::text("MA"),
-                product: StringBuidler::text("PR"),
-                serial_number: StringBuidler::text("SE"),
-                configurations: vec![ConfigurationBuilder {
-                    configuration: StringBuidler::text("C0"),
-                    configuration_value: 0x01,
-                    attributes: ConfigurationAttributes {
-                        self_powered: false,
-                        remote_wakeup: false,
-                    },
-                    max_power: Milliamperes(500),
-                    interfaces: vec![InterfaceBuilder {
-                        interface: StringBuidler::text("I0"),
-                        alternate_setting: 0x00,
-                        interface_class: InterfaceClass::HumanInterfaceDevice,
+    fn device_builder() -> DeviceBuilder {
+        DeviceBuilder {
+            usb: USB2_0,
+            device_class: DeviceClass::Device,
+            device_suclass: 0x00,
+            device_protocol: 0x00,
+            max_packet_size_0: 0x40,
+            max_speed: Speed::Full,
+            id_vendor: 0x1234,
+            id_product: 0x1234,
+            device: USB2_0,
+            manufacturer: Some(StringBuidler::text("MA")),
+            product: Some(StringBuidler::text("PR")),
+            serial_number: Some(StringBuidler::text("SE")),
+            configurations: vec![ConfigurationBuilder {
+                configuration: Some(StringBuidler::text("C0")),
+                configuration_value: 0x01,
+                attributes: ConfigurationAttributes {
+                    self_powered: false,
+                    remote_wakeup: false,
+                },
+                max_power: Milliamperes(500),
+                interfaces: vec![ConfigurationEntry::Interface(
+                    AlternateSettingsBuilder::single(InterfaceBuilder {
+                        interface: Some(StringBuidler::text("I0")),
+                        interface_class: InterfaceClass::VendorSpecific,
                         interface_suclass: 0x00,
                         interface_protocol: 0x00,
+                        hid: None,
                         endpoints: vec![EndpointBuilder {
-                            endpoint_address: EndpointAddress {
-                                endpoint_number: 0x01,
-                                direction: Direction::In,
-                            },
+                            endpoint_address: AddressAssignment::fixed(0x01, Direction::In),
                             attributes: EndpointAttributes {
                                 transfer_type: TransferType::Interrupt,
                                 sync_type: SyncType::NoSync,
                                 usage_type: UsageType::Data,
                             },
                             max_packet_size: 0x40,
-                            interval: 0x01,
+                            interval: Interval::Raw(0x01),
+                            high_speed_max_packet_size: None,
+                            high_speed_interval: None,
+                            high_speed_transactions: None,
+                            super_speed_max_packet_size: None,
+                            super_speed_interval: None,
+                            max_burst: None,
+                            max_streams: None,
+                            mult: None,
+                            bytes_per_interval: None,
+                            super_speed_plus_bytes_per_interval: None,
                         }],
-                    }],
-                }],
-            },
-            &StringBuidler::languages(vec![EN_US]),
-        )
-        .unwrap();
+                    }),
+                )],
+            }],
+            bos: None,
+            endpoint_pool: EndpointPool::default(),
+        }
+    }
+
+    #[test]
+    fn test_descriptor_store() {
+        let builder =
+            DescriptorsBuilder::build(&device_builder(), &StringBuidler::languages(vec![EN_US]))
+                .unwrap();
 
         let store = Descriptors::encode(&builder).unwrap();
 
@@ -111,13 +220,13 @@ mod tests {
 
         // descriptos list and descriptor_values have to be equal
         assert_eq!(store.w_values.len(), store.descriptors.len());
+        assert_eq!(store.w_indexes.len(), store.descriptors.len());
 
         // check descriptor_values for dubplicates
         let mut found_descriptor_values = Vec::<u16>::new();
         for value in store.w_values.iter() {
-            assert_eq!(
-                found_descriptor_values.contains(value),
-                false,
+            assert!(
+                !found_descriptor_values.contains(value),
                 "Duplicate descriptor_value found: 0x{:x}",
                 value
             );
@@ -125,9 +234,8 @@ mod tests {
 
             // try to get descriptor by value
             let descriptor = store.get_descriptor(*value);
-            assert_eq!(
+            assert!(
                 descriptor.is_some(),
-                true,
                 "Descriptor not found for value: 0x{:x}",
                 value
             );
@@ -142,13 +250,34 @@ mod tests {
             );
         }
 
-        let expected = vec![
+        let expected = [
             // String Descriptor - Language
             vec![
                 0x04, // bLength = 4
                 0x03, // bDescriptorType = String
                 0x09, 0x04, // wLANGID[0] = 0x0409 (English - United States)
             ],
+            // String Descriptor - Manufacturer
+            vec![
+                0x06, // bLength = 6
+                0x03, // bDescriptorType = String
+                77, 0x00, // M
+                65, 0x00, // A
+            ],
+            // String Descriptor - Product
+            vec![
+                0x06, // bLength = 6
+                0x03, // bDescriptorType = String
+                0x50, 0x00, // P
+                0x52, 0x00, // R
+            ],
+            // String Descriptor - Serial Number
+            vec![
+                0x06, // bLength = 6
+                0x03, // bDescriptorType = String
+                0x53, 0x00, // S
+                0x45, 0x00, // E
+            ],
             // Device descriptor
             vec![
                 0x12, // bLength = 18
@@ -166,66 +295,42 @@ mod tests {
                 0x03, // iSerialNumber = 3
                 0x01, // bNumConfigurations = 1
             ],
-            // String Descriptor - Manufacturer
-            vec![
-                0x06, // bLength = 4
-                0x03, // bDescriptorType = String
-                77, 0x00, // M
-                65, 0x00, // A
-            ],
-            // String Descriptor - Product
+            // String Descriptor - Configuration
             vec![
-                0x06, // bLength = 20
+                0x06, // bLength = 6
                 0x03, // bDescriptorType = String
-                0x50, 0x00, // P
-                0x52, 0x00, // R
+                0x43, 0x00, // C
+                0x30, 0x00, // 0
             ],
-            // String Descriptor - Serial Number
+            // String Descriptor - Interface
             vec![
-                0x06, // bLength = 12
+                0x06, // bLength = 6
                 0x03, // bDescriptorType = String
-                0x53, 0x00, // S
-                0x45, 0x00, // E
+                0x49, 0x00, // I
+                0x30, 0x00, // 0
             ],
-            // Configuration descriptor
+            // Configuration bundle
             vec![
+                // Configuration descriptor
                 0x09, // bLength = 9
                 0x02, // bDescriptorType = Configuration
-                16, 0x00, // wTotalLength = 16
+                25, 0x00, // wTotalLength = 25
                 0x01, // bNumInterfaces = 1
-                0x02, // bConfigurationValue = 2
-                0x03, // iConfiguration = 3
+                0x01, // bConfigurationValue = 1
+                0x04, // iConfiguration = 4
                 0x80, // bmAttributes = 0x80
                 250,  // bMaxPower = 500mA
-            ],
-            // String Descriptor - Configuration
-            vec![
-                0x06, // bLength = 6
-                0x03, // bDescriptorType = String
-                0x43, 0x00, // C
-                0x30, 0x00, // 0
-            ],
-            // Interface descriptor
-            vec![
+                // Interface descriptor
                 0x09, // bLength = 9
                 0x04, // bDescriptorType = Interface
                 0x00, // bInterfaceNumber = 0
                 0x00, // bAlternateSetting = 0
                 0x01, // bNumEndpoints = 1
-                0x03, // bInterfaceClass = HID
+                0xFF, // bInterfaceClass = Vendor Specific
                 0x00, // bInterfaceSubClass = 0
                 0x00, // bInterfaceProtocol = 0
-                0x04, // iInterface = 0
-            ],
-            // String Descriptor - Interface
-            vec![
-                0x06, // bLength = 6
-                0x03, // bDescriptorType = String
-                0x49, 0x00, // I
-                0x30, 0x00, // 0
-            ],
-            // Endpoint descriptor
-            vec![
+                0x05, // iInterface = 5
+                // Endpoint descriptor
                 0x07, // bLength = 7
                 0x05, // bDescriptorType = Endpoint
                 0x81, // bEndpointAddress = IN 1
@@ -234,6 +339,7 @@ mod tests {
                 0x01, // bInterval = 1
             ],
         ];
+        assert_eq!(store.w_values.len(), expected.len());
         for i in 0..store.w_values.len() {
             let w_value = store.w_values[i];
             let descriptor = &store.descriptors[i];
@@ -245,4 +351,768 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_configuration_bundle_round_trip() {
+        let device = device_builder();
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        assert_eq!(
+            u16::from_le_bytes([bundle[2], bundle[3]]) as usize,
+            bundle.len()
+        );
+
+        let strings = (1..=5)
+            .map(|index| {
+                let bytes = store.get_descriptor(0x0300 | index as u16).unwrap();
+                StringDescriptor::decode(index, bytes).unwrap()
+            })
+            .collect::<Vec<StringDescriptor>>();
+        let decoded = DeviceBuilder::decode(
+            store.get_descriptor(0x0100).unwrap(),
+            &[bundle],
+            Speed::Full,
+            &strings,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(decoded, device);
+    }
+
+    #[test]
+    fn test_decode_at_speed() {
+        // decodes descriptors read at `speed` and serves the same bytes at that speed
+        let round_trip = |device: &[u8], bundle: &[u8], speed: Speed| {
+            let decoded = DeviceBuilder::decode(device, &[bundle], speed, &[], &[]).unwrap();
+            assert_eq!(decoded.max_speed, speed);
+            let builder =
+                DescriptorsBuilder::build(&decoded, &StringBuidler::languages(vec![EN_US]))
+                    .unwrap();
+            let mut store = Descriptors::encode(&builder).unwrap();
+            store.set_speed(speed);
+            assert_eq!(store.get_descriptor(0x0100).unwrap(), device);
+            assert_eq!(store.get_descriptor(0x0200).unwrap(), bundle);
+        };
+
+        round_trip(
+            &[
+                0x12, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x34, 0x12, 0x78, 0x56, 0x00, 0x01,
+                0x00, 0x00, 0x00, 0x01,
+            ],
+            &[
+                0x09, 0x02, 0x20, 0x00, 0x01, 0x01, 0x00, 0x80, 0x32, // configuration
+                0x09, 0x04, 0x00, 0x00, 0x02, 0xFF, 0x00, 0x00, 0x00, // interface
+                0x07, 0x05, 0x81, 0x02, 0x00, 0x02, 0x00, // bulk IN, 512 bytes
+                0x07, 0x05, 0x02, 0x02, 0x00, 0x02, 0x00, // bulk OUT, 512 bytes
+            ],
+            Speed::High,
+        );
+
+        round_trip(
+            &[
+                0x12, 0x01, 0x20, 0x03, 0x00, 0x00, 0x00, 0x09, 0x34, 0x12, 0x78, 0x56, 0x00, 0x01,
+                0x00, 0x00, 0x00, 0x01,
+            ],
+            &[
+                0x09, 0x02, 0x2C, 0x00, 0x01, 0x01, 0x00, 0x80, 0x0C, // configuration, 96 mA
+                0x09, 0x04, 0x00, 0x00, 0x02, 0xFF, 0x00, 0x00, 0x00, // interface
+                0x07, 0x05, 0x81, 0x03, 0x00, 0x04, 0x04, // interrupt IN, 1024 bytes, 1 ms
+                0x06, 0x30, 0x01, 0x00, 0x00, 0x08, // companion, 2 packet bursts
+                0x07, 0x05, 0x02, 0x02, 0x00, 0x04, 0x00, // bulk OUT, 1024 bytes
+                0x06, 0x30, 0x0F, 0x04, 0x00, 0x00, // companion, 16 streams
+            ],
+            Speed::Super,
+        );
+    }
+
+    #[test]
+    fn test_absent_strings() {
+        let mut device = device_builder();
+        device.serial_number = None;
+        device.configurations[0]
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings[0]
+            .interface = None;
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let device_descriptor = store.get_descriptor(0x0100).unwrap();
+        assert_eq!(device_descriptor[16], 0); // iSerialNumber
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        assert_eq!(bundle[17], 0); // iInterface
+        assert_eq!(store.get_descriptor(0x0304), None);
+
+        let strings = (1..=3)
+            .map(|index| {
+                let bytes = store.get_descriptor(0x0300 | index as u16).unwrap();
+                StringDescriptor::decode(index, bytes).unwrap()
+            })
+            .collect::<Vec<StringDescriptor>>();
+        let decoded =
+            DeviceBuilder::decode(device_descriptor, &[bundle], Speed::Full, &strings, &[])
+                .unwrap();
+        assert_eq!(decoded, device);
+    }
+
+    #[test]
+    fn test_duplicate_endpoint() {
+        let mut device = device_builder();
+        let interface = device.configurations[0].interfaces[0].clone();
+        device.configurations[0].interfaces.push(interface);
+
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::DuplicateEndpoint {
+                configuration_value: 0x01,
+                address: 0x81
+            })
+        );
+    }
+
+    #[test]
+    fn test_alternate_settings() {
+        let mut device = device_builder();
+        let configuration = &mut device.configurations[0];
+        let interface = configuration.interface_mut(0).unwrap();
+        let setting = interface.alternate_settings[0].clone();
+        interface.alternate_settings.push(setting.clone());
+        let mut other = setting.clone();
+        other.endpoints[0].endpoint_address = AddressAssignment::fixed(0x02, Direction::In);
+        configuration
+            .interfaces
+            .push(AlternateSettingsBuilder::single(other).into());
+
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        assert_eq!(bundle[4], 2); // bNumInterfaces
+        assert_eq!(&bundle[11..13], &[0x00, 0x00]);
+        assert_eq!(&bundle[27..29], &[0x00, 0x01]);
+        assert_eq!(&bundle[43..45], &[0x01, 0x00]);
+
+        // Other interfaces may not reuse the endpoints of an alternate setting
+        let interface = device.configurations[0].interface_mut(1).unwrap();
+        interface.alternate_settings[0].endpoints[0] = setting.endpoints[0].clone();
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::DuplicateEndpoint {
+                configuration_value: 0x01,
+                address: 0x81
+            })
+        );
+    }
+
+    #[test]
+    fn test_auto_endpoints() {
+        let mut device = device_builder();
+        // IN and OUT share the endpoint numbers 1 to 3
+        device.endpoint_pool = EndpointPool {
+            endpoint_numbers: vec![1, 2, 3],
+            bidirectional: false,
+        };
+        let configuration = &mut device.configurations[0];
+        let interface = configuration.interface_mut(0).unwrap();
+        let setting = interface.alternate_settings[0].clone();
+        let mut alternate = setting.clone();
+        alternate.endpoints.push(setting.endpoints[0].clone());
+        alternate.endpoints[1].endpoint_address = AddressAssignment::Auto(Direction::Out);
+        interface.alternate_settings.push(alternate);
+        let mut other = setting.clone();
+        other.endpoints[0].endpoint_address = AddressAssignment::Auto(Direction::In);
+        configuration
+            .interfaces
+            .push(AlternateSettingsBuilder::single(other).into());
+
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        assert_eq!(bundle[20], 0x81);
+        // the alternate setting reuses 0x81, 0x01 would share its number
+        assert_eq!(bundle[36], 0x81);
+        assert_eq!(bundle[43], 0x02);
+        assert_eq!(bundle[59], 0x83);
+
+        let endpoints = &mut device.configurations[0]
+            .interface_mut(1)
+            .unwrap()
+            .alternate_settings[0]
+            .endpoints;
+        endpoints.push(endpoints[0].clone());
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::EndpointsExhausted {
+                configuration_value: 0x01
+            })
+        );
+
+        let endpoints = &mut device.configurations[0]
+            .interface_mut(1)
+            .unwrap()
+            .alternate_settings[0]
+            .endpoints;
+        endpoints[1].endpoint_address = AddressAssignment::fixed(0x01, Direction::Out);
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::DuplicateEndpoint {
+                configuration_value: 0x01,
+                address: 0x01
+            })
+        );
+
+        let endpoints = &mut device.configurations[0]
+            .interface_mut(1)
+            .unwrap()
+            .alternate_settings[0]
+            .endpoints;
+        endpoints[1].endpoint_address = AddressAssignment::fixed(0x04, Direction::In);
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bEndpointAddress",
+                value: 0x84
+            })
+        );
+    }
+
+    #[test]
+    fn test_interface_association() {
+        let mut device = device_builder();
+        device.device_class = DeviceClass::Miscellaneous;
+        device.device_suclass = 0x02;
+        device.device_protocol = 0x01;
+        let mut control = device.configurations[0]
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings[0]
+            .clone();
+        control.interface_class = InterfaceClass::CommunicationAndCDCControl;
+        control.endpoints[0].endpoint_address = AddressAssignment::fixed(0x02, Direction::In);
+        let data = InterfaceBuilder {
+            interface_class: InterfaceClass::CdcData,
+            endpoints: Vec::new(),
+            ..control.clone()
+        };
+        // The function follows a standalone interface 0
+        let association = InterfaceAssociationBuilder {
+            function_class: InterfaceClass::CommunicationAndCDCControl,
+            function_suclass: 0x02,
+            function_protocol: 0x01,
+            function: Some(StringBuidler::text("ACM")),
+            interfaces: vec![
+                AlternateSettingsBuilder::single(control),
+                AlternateSettingsBuilder::single(data),
+            ],
+        };
+        device.configurations[0]
+            .interfaces
+            .push(association.clone().into());
+
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        assert_eq!(bundle[4], 3); // bNumInterfaces
+        assert_eq!(&bundle[9..12], &[9, 0x04, 0]);
+        assert_eq!(&bundle[25..33], &[8, 0x0B, 1, 2, 0x02, 0x02, 0x01, 6]);
+        assert_eq!(&bundle[33..36], &[9, 0x04, 1]);
+        assert_eq!(&bundle[49..52], &[9, 0x04, 2]);
+
+        let strings = (1..=8)
+            .map(|index| {
+                let bytes = store.get_descriptor(0x0300 | index as u16).unwrap();
+                StringDescriptor::decode(index, bytes).unwrap()
+            })
+            .collect::<Vec<StringDescriptor>>();
+        let decoded = DeviceBuilder::decode(
+            store.get_descriptor(0x0100).unwrap(),
+            &[bundle],
+            Speed::Full,
+            &strings,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(decoded, device);
+
+        device.device_class = DeviceClass::Device;
+        device.device_suclass = 0x00;
+        device.device_protocol = 0x00;
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::InvalidClass {
+                descriptor: DescriptorType::Device,
+                class: 0x00,
+                subclass: 0x00,
+                protocol: 0x00
+            })
+        );
+
+        device.device_class = DeviceClass::Miscellaneous;
+        device.device_suclass = 0x02;
+        device.device_protocol = 0x01;
+        device.configurations[0].interfaces[1] =
+            ConfigurationEntry::Association(InterfaceAssociationBuilder {
+                function_class: InterfaceClass::Image,
+                function_suclass: 0x02,
+                ..association
+            });
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::InvalidClass {
+                descriptor: DescriptorType::InterfaceAssociation,
+                class: 0x06,
+                subclass: 0x02,
+                protocol: 0x01
+            })
+        );
+    }
+
+    #[test]
+    fn test_hid() {
+        let report = vec![0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0xC0];
+        let items = vec![
+            Item::Global(GlobalItem::UsagePage(0x01)),
+            Item::Local(LocalItem::Usage(0x02)),
+            Item::Main(MainItem::Collection(CollectionType::Application)),
+            Item::Main(MainItem::EndCollection),
+        ];
+        let mut device = device_builder();
+        let setting = &mut device.configurations[0]
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings[0];
+        setting.interface_class = InterfaceClass::HumanInterfaceDevice;
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::Missing {
+                descriptor: DescriptorType::Interface,
+                field: "hid"
+            })
+        );
+
+        let hid_builder = HidBuilder {
+            bcd_hid: HID1_11,
+            country_code: 0,
+            report: ReportBuilder { items },
+        };
+        device.configurations[0]
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings[0]
+            .hid = Some(hid_builder.clone());
+
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        let hid = [0x09, 0x21, 0x11, 0x01, 0x00, 0x01, 0x22, 0x07, 0x00];
+        assert_eq!(bundle.len(), 34);
+        assert_eq!(&bundle[18..27], &hid);
+        assert_eq!(bundle[28], 0x05); // Endpoint follows the HID descriptor
+
+        assert_eq!(store.get_indexed_descriptor(0x2100, 0), Some(&hid.to_vec()));
+        assert_eq!(store.get_indexed_descriptor(0x2200, 0), Some(&report));
+        assert_eq!(store.get_indexed_descriptor(0x2200, 1), None);
+
+        // Decoding rebuilds the HID descriptor from the report descriptor
+        let strings = (1..=5)
+            .map(|index| {
+                let bytes = store.get_descriptor(0x0300 | index as u16).unwrap();
+                StringDescriptor::decode(index, bytes).unwrap()
+            })
+            .collect::<Vec<StringDescriptor>>();
+        let decoded = DeviceBuilder::decode(
+            store.get_descriptor(0x0100).unwrap(),
+            &[bundle],
+            Speed::Full,
+            &strings,
+            &[(1, 0, report.as_slice())],
+        )
+        .unwrap();
+        assert_eq!(decoded, device);
+
+        // Alternate settings share the HID descriptor, every configuration has its own
+        let interface = device.configurations[0].interface_mut(0).unwrap();
+        interface
+            .alternate_settings
+            .push(interface.alternate_settings[0].clone());
+        let mut mouse = device.configurations[0].clone();
+        mouse.configuration_value = 0x02;
+        let other_report = vec![0x05, 0x01, 0x09, 0x06, 0xA1, 0x01, 0xC0];
+        let other_hid = HidBuilder {
+            report: ReportBuilder {
+                items: vec![
+                    Item::Global(GlobalItem::UsagePage(0x01)),
+                    Item::Local(LocalItem::Usage(0x06)),
+                    Item::Main(MainItem::Collection(CollectionType::Application)),
+                    Item::Main(MainItem::EndCollection),
+                ],
+            },
+            ..hid_builder
+        };
+        for setting in mouse
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings
+            .iter_mut()
+        {
+            setting.hid = Some(other_hid.clone());
+        }
+        device.configurations.push(mouse);
+
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let mut store = Descriptors::encode(&builder).unwrap();
+        let reports = store.w_values.iter().filter(|value| **value == 0x2200);
+        assert_eq!(reports.count(), 2);
+        assert_eq!(store.get_indexed_descriptor(0x2200, 0), Some(&report));
+        store.set_configuration(0x02);
+        assert_eq!(store.get_indexed_descriptor(0x2200, 0), Some(&other_report));
+        store.set_configuration(0x01);
+        assert_eq!(store.get_indexed_descriptor(0x2200, 0), Some(&report));
+
+        let interface = device.configurations[0].interface_mut(0).unwrap();
+        interface.alternate_settings[1].hid = Some(other_hid);
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::ConflictingHid {
+                configuration_value: 0x01,
+                interface_number: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_too_many_strings() {
+        let mut device = device_builder();
+        let configuration = device.configurations[0].clone();
+        for _ in 0..127 {
+            device.configurations.push(configuration.clone());
+        }
+
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(result.err(), Some(Error::TooManyStrings { count: 257 }));
+    }
+
+    #[test]
+    fn test_handle_get_descriptor() {
+        let builder =
+            DescriptorsBuilder::build(&device_builder(), &StringBuidler::languages(vec![EN_US]))
+                .unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+
+        // the host asks for the first 8 bytes of the device descriptor first
+        let device = store.get_descriptor(0x0100).unwrap();
+        let setup = SetupPacket::get_descriptor(0x0100, 0x0000, 8);
+        assert_eq!(store.handle_get_descriptor(&setup), Some(&device[..8]));
+        let setup = SetupPacket::get_descriptor(0x0100, 0x0000, 0xff);
+        assert_eq!(store.handle_get_descriptor(&setup), Some(&device[..]));
+
+        // wTotalLength is read from the first 9 bytes of the configuration bundle
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        let setup = SetupPacket::get_descriptor(0x0200, 0x0000, 9);
+        assert_eq!(store.handle_get_descriptor(&setup), Some(&bundle[..9]));
+        let setup = SetupPacket::get_descriptor(0x0200, 0x0000, bundle.len() as u16);
+        assert_eq!(store.handle_get_descriptor(&setup), Some(&bundle[..]));
+
+        // strings other than the language string are selected by the language ID in wIndex
+        let setup = SetupPacket::get_descriptor(0x0300, 0x0000, 0xff);
+        assert_eq!(
+            store.handle_get_descriptor(&setup),
+            Some(&[0x04, 0x03, 0x09, 0x04][..])
+        );
+        let setup = SetupPacket::get_descriptor(0x0301, 0x0409, 0xff);
+        assert_eq!(
+            store.handle_get_descriptor(&setup),
+            Some(&[0x06, 0x03, 77, 0x00, 65, 0x00][..])
+        );
+        let setup = SetupPacket::get_descriptor(0x0301, 0x0407, 0xff);
+        assert_eq!(store.handle_get_descriptor(&setup), None);
+
+        // unknown descriptors and other requests are stalled
+        let setup = SetupPacket::get_descriptor(0x0201, 0x0000, 0xff);
+        assert_eq!(store.handle_get_descriptor(&setup), None);
+        let mut setup = SetupPacket::get_descriptor(0x0100, 0x0000, 0xff);
+        setup.request = 0x05;
+        assert_eq!(store.handle_get_descriptor(&setup), None);
+    }
+
+    #[test]
+    fn test_device_qualifier() {
+        let mut device = device_builder();
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        assert_eq!(store.get_descriptor(0x0600), None);
+
+        device.max_speed = Speed::High;
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let setup = SetupPacket::get_descriptor(0x0600, 0x0000, 0xff);
+        assert_eq!(
+            store.handle_get_descriptor(&setup),
+            Some(&[0x0A, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x01, 0x00][..])
+        );
+
+        device.usb = USB1_1;
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Device,
+                field: "bcdUSB",
+                value: 0x0110
+            })
+        );
+    }
+
+    #[test]
+    fn test_other_speed_configuration() {
+        let mut device = device_builder();
+        device.max_speed = Speed::High;
+        let endpoint = &mut device.configurations[0]
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings[0]
+            .endpoints[0];
+        endpoint.high_speed_max_packet_size = Some(0x80);
+        endpoint.high_speed_interval = Some(Interval::millis(1));
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let mut store = Descriptors::encode(&builder).unwrap();
+
+        // the endpoint descriptor is the last 7 bytes of the bundle
+        let endpoint = |store: &Descriptors, w_value: u16| {
+            let bundle = store.get_descriptor(w_value).unwrap();
+            assert_eq!(bundle[1], (w_value >> 8) as u8);
+            bundle[18..].to_vec()
+        };
+        assert_eq!(
+            endpoint(&store, 0x0200),
+            vec![0x07, 0x05, 0x81, 0x03, 0x40, 0x00, 0x01]
+        );
+        assert_eq!(
+            endpoint(&store, 0x0700),
+            vec![0x07, 0x05, 0x81, 0x03, 0x80, 0x00, 0x04]
+        );
+
+        store.set_speed(Speed::High);
+        assert_eq!(
+            endpoint(&store, 0x0200),
+            vec![0x07, 0x05, 0x81, 0x03, 0x80, 0x00, 0x04]
+        );
+        assert_eq!(
+            endpoint(&store, 0x0700),
+            vec![0x07, 0x05, 0x81, 0x03, 0x40, 0x00, 0x01]
+        );
+        let setup = SetupPacket::get_descriptor(0x0700, 0x0000, 0x04);
+        assert_eq!(
+            store.handle_get_descriptor(&setup),
+            Some(&[0x09, 0x07, 25, 0x00][..])
+        );
+
+        // single speed devices have no other speed configuration
+        let store = Descriptors::encode(
+            &DescriptorsBuilder::build(&device_builder(), &StringBuidler::languages(vec![EN_US]))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(store.get_descriptor(0x0700), None);
+    }
+
+    #[test]
+    fn test_super_speed() {
+        let mut device = device_builder();
+        device.usb = USB3_0;
+        device.max_speed = Speed::Super;
+        let endpoint = &mut device.configurations[0]
+            .interface_mut(0)
+            .unwrap()
+            .alternate_settings[0]
+            .endpoints[0];
+        endpoint.high_speed_max_packet_size = Some(0x80);
+        endpoint.super_speed_max_packet_size = Some(0x0400);
+        endpoint.super_speed_interval = Some(Interval::millis(16));
+        endpoint.max_burst = Some(1);
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let mut store = Descriptors::encode(&builder).unwrap();
+
+        // bcdUSB, bMaxPacketSize0 and the endpoint at each speed
+        let current = |store: &Descriptors| {
+            let device = store.get_descriptor(0x0100).unwrap();
+            let bundle = store.get_descriptor(0x0200).unwrap();
+            (device[2..4].to_vec(), device[7], bundle[22..].to_vec())
+        };
+        assert_eq!(
+            current(&store),
+            (vec![0x10, 0x02], 0x40, vec![0x40, 0x00, 0x01])
+        );
+        store.set_speed(Speed::High);
+        assert_eq!(
+            current(&store),
+            (vec![0x10, 0x02], 0x40, vec![0x80, 0x00, 0x01])
+        );
+        // bMaxPower counts 2 mA units below SuperSpeed
+        assert_eq!(store.get_descriptor(0x0200).unwrap()[8], 250);
+        assert!(store.get_descriptor(0x0600).is_some());
+        // the endpoint companion follows the endpoint at SuperSpeed
+        store.set_speed(Speed::Super);
+        assert_eq!(
+            current(&store),
+            (
+                vec![0x00, 0x03],
+                0x09,
+                vec![0x00, 0x04, 0x08, 0x06, 0x30, 0x01, 0x00, 0x00, 0x08]
+            )
+        );
+        let bundle = store.get_descriptor(0x0200).unwrap();
+        // and 8 mA units at SuperSpeed
+        assert_eq!(bundle[8], 62);
+        let strings = (1..=5)
+            .map(|index| {
+                let bytes = store.get_descriptor(0x0300 | index as u16).unwrap();
+                StringDescriptor::decode(index, bytes).unwrap()
+            })
+            .collect::<Vec<StringDescriptor>>();
+        let decoded = ConfigurationBuilder::decode(bundle, Speed::Super, &strings, &[]).unwrap();
+        assert_eq!(decoded.max_power, Milliamperes(496));
+        let endpoint = &decoded.interface(0).unwrap().alternate_settings[0].endpoints[0];
+        assert_eq!(endpoint.max_burst, Some(1));
+        assert_eq!(endpoint.bytes_per_interval, Some(0x0800));
+        assert_eq!(store.get_descriptor(0x0600), None);
+        assert_eq!(store.get_descriptor(0x0700), None);
+
+        device.usb = USB2_1;
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Device,
+                field: "bcdUSB",
+                value: 0x0210
+            })
+        );
+    }
+
+    #[test]
+    fn test_bos() {
+        let mut device = device_builder();
+        device.usb = USB2_1;
+        device.bos = Some(BosBuilder {
+            capabilities: vec![DeviceCapabilityBuilder::Usb20Extension(
+                Usb20ExtensionBuilder {
+                    link_power_management: true,
+                    besl: false,
+                    baseline_besl: None,
+                    deep_besl: None,
+                },
+            )],
+        });
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        let setup = SetupPacket::get_descriptor(0x0F00, 0x0000, 0xff);
+        assert_eq!(
+            store.handle_get_descriptor(&setup),
+            Some(
+                &[
+                    0x05, 0x0F, 12, 0x00, 0x01, // BOS
+                    0x07, 0x10, 0x02, 0x02, 0x00, 0x00, 0x00, // USB 2.0 Extension
+                ][..]
+            )
+        );
+
+        device.usb = USB2_0;
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Bos,
+                field: "bcdUSB",
+                value: 0x0200
+            })
+        );
+
+        // the SuperSpeedPlus capability needs USB 3.1
+        let bos = device.bos.as_mut().unwrap();
+        bos.capabilities
+            .push(DeviceCapabilityBuilder::SuperSpeedPlus(
+                SuperSpeedPlusBuilder {
+                    sublink_speed_attributes: [SublinkDirection::Rx, SublinkDirection::Tx]
+                        .map(|direction| SublinkSpeedAttribute {
+                            id: 0,
+                            exponent: LaneSpeedExponent::GigabitsPerSecond,
+                            sublink_type: SublinkType {
+                                symmetric: true,
+                                direction,
+                            },
+                            protocol: LinkProtocol::SuperSpeedPlus,
+                            mantissa: 10,
+                        })
+                        .to_vec(),
+                    functionality_support: 0,
+                    min_rx_lane_count: 1,
+                    min_tx_lane_count: 1,
+                },
+            ));
+        device.usb = USB3_0;
+        let result = DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US]));
+        assert_eq!(
+            result.err(),
+            Some(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Bos,
+                field: "bcdUSB",
+                value: 0x0300
+            })
+        );
+        device.usb = USB3_2;
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US])).unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+        assert_eq!(
+            store.get_descriptor(0x0F00).unwrap()[2..5],
+            [32, 0x00, 0x02]
+        );
+    }
+
+    #[test]
+    fn test_localized_strings() {
+        let mut device = device_builder();
+        device.product = Some(StringBuidler::localized(vec![
+            (EN_US, "Mouse"),
+            (DE_DE, "Maus"),
+        ]));
+        let builder =
+            DescriptorsBuilder::build(&device, &StringBuidler::languages(vec![EN_US, DE_DE]))
+                .unwrap();
+        let store = Descriptors::encode(&builder).unwrap();
+
+        let product = |language: u16| {
+            let setup = SetupPacket::get_descriptor(0x0302, language, 0xff);
+            StringDescriptor::decode(2, store.handle_get_descriptor(&setup).unwrap()).unwrap()
+        };
+        assert_eq!(product(0x0409), StringBuidler::text("Mouse").build(2));
+        assert_eq!(product(0x0407), StringBuidler::text("Maus").build(2));
+
+        // texts without translations are served for every language
+        let setup = SetupPacket::get_descriptor(0x0301, 0x0407, 0xff);
+        assert!(store.handle_get_descriptor(&setup).is_some());
+    }
 }
@@ -1,173 +1,529 @@
 use alloc::{boxed::Box, vec::Vec};
 
 use crate::{
-    binary::encode_w_value,
+    binary::{encode_w_value, EncodeByte},
+    bos::bos_descriptor::BOS_DESCRIPTOR_TYPE,
     configuration::{
         configuration_builder::ConfigurationBuilder,
-        configuration_descriptor::CONFIGURATION_DESCRIPTOR_TYPE,
+        configuration_bundle::ConfigurationBundle,
+        configuration_descriptor::{
+            CONFIGURATION_DESCRIPTOR_TYPE, OTHER_SPEED_CONFIGURATION_DESCRIPTOR_TYPE,
+        },
+        configuration_entry::ConfigurationEntry,
     },
     descriptor::Descriptor,
-    device::{device_builder::DeviceBuilder, device_descriptor::DEVICE_DESCRIPTOR_TYPE},
+    descriptor_type::DescriptorType,
+    device::{
+        device_builder::DeviceBuilder, device_descriptor::DEVICE_DESCRIPTOR_TYPE,
+        device_qualifier_descriptor::DEVICE_QUALIFIER_DESCRIPTOR_TYPE,
+    },
     endpoint::{
-        endpoint_builder::EndpointBuilder,
-        endpoint_descriptor::{ENDPOINT_DESCRIPTOR_LENGTH, ENDPOINT_DESCRIPTOR_TYPE},
+        address_assignment::AddressAssignment, endpoint_address::EndpointAddress,
+        endpoint_pool::EndpointPool,
     },
-    interface::{
-        interface_builder::InterfaceBuilder,
-        interface_descriptor::{INTERFACE_DESCRIPTOR_LENGTH, INTERFACE_DESCRIPTOR_TYPE},
+    error::Error,
+    hid::hid_descriptor::HID_DESCRIPTOR_TYPE,
+    interface::alternate_settings_builder::AlternateSettingsBuilder,
+    report::report_descriptor::REPORT_DESCRIPTOR_TYPE,
+    speed::Speed,
+    string::{
+        language_code::LanguageCode, string_builder::StringBuidler, string_content::StringContent,
+        string_descriptor::STRING_DESCRIPTOR_TYPE,
     },
-    string::{string_builder::StringBuidler, string_descriptor::STRING_DESCRIPTOR_TYPE},
 };
 
+#[derive(Default)]
 pub struct DescriptorsBuilder {
     pub descriptors: Vec<Box<dyn Descriptor>>,
     pub w_values: Vec<u16>,
-    configuration_total_length: u16,
-    configuration_descriptor_index: u8, // counting up
-    interface_descriptor_index: u8,     // counting up
-    string_descriptor_index: u8,        // counting down
+    /// `wIndex` the descriptor is served for. The language ID for strings, 0 otherwise.
+    pub w_indexes: Vec<u16>,
+    /// The speed the descriptor is served at, `None` for all speeds
+    pub speeds: Vec<Option<Speed>>,
+    /// The `bConfigurationValue` the descriptor is served in, `None` for all configurations
+    pub configuration_values: Vec<Option<u8>>,
+    languages: Vec<LanguageCode>,
+    string_descriptor_index: usize, // counting up, 0 is the language string
 }
 
-impl DescriptorsBuilder {
-    fn add_endpoint_descriptor<'a>(&mut self, builder: &'a EndpointBuilder) -> Result<(), &'a str> {
-        let descriptor = builder.build()?;
-        let w_value = encode_w_value(
-            &ENDPOINT_DESCRIPTOR_TYPE,
-            self.configuration_descriptor_index,
-        )
-        .unwrap(); // TODO
-        self.configuration_descriptor_index += 1;
-        self.configuration_total_length += ENDPOINT_DESCRIPTOR_LENGTH as u16;
-        self.descriptors.push(Box::new(descriptor));
-        self.w_values.push(w_value);
+/// The descriptors of one configuration bundle, built once for every speed the device
+/// operates at. Full and high speed also get a set for the Other Speed Configuration.
+struct SpeedDescriptors {
+    /// The speed each set is built for
+    speeds: Vec<Speed>,
+    /// The `bDescriptorType` of each bundle and the speed it is served at
+    served: Vec<(DescriptorType, Option<Speed>)>,
+    descriptors: Vec<Vec<Box<dyn Descriptor>>>,
+}
+
+impl SpeedDescriptors {
+    fn new(device_speeds: &[Speed]) -> SpeedDescriptors {
+        let mut speeds = Vec::new();
+        let mut served = Vec::new();
+        for speed in device_speeds {
+            speeds.push(*speed);
+            served.push((
+                CONFIGURATION_DESCRIPTOR_TYPE,
+                served_at(*speed, device_speeds),
+            ));
+        }
+        for speed in device_speeds {
+            let other_speed = speed.other_speed();
+            if let Some(other_speed) = other_speed.filter(|other| device_speeds.contains(other)) {
+                speeds.push(*speed);
+                served.push((OTHER_SPEED_CONFIGURATION_DESCRIPTOR_TYPE, Some(other_speed)));
+            }
+        }
+        SpeedDescriptors {
+            descriptors: speeds.iter().map(|_| Vec::new()).collect(),
+            speeds,
+            served,
+        }
+    }
+
+    fn push<D: Descriptor + 'static>(
+        &mut self,
+        build: impl Fn(Speed) -> Result<D, Error>,
+    ) -> Result<(), Error> {
+        for (speed, descriptors) in self.speeds.iter().zip(self.descriptors.iter_mut()) {
+            descriptors.push(Box::new(build(*speed)?));
+        }
         Ok(())
     }
 
-    fn add_interface_descriptor<'a>(
+    /// Like `push` for descriptors that only exist at some speeds
+    fn push_some<D: Descriptor + 'static>(
         &mut self,
-        builder: &'a InterfaceBuilder,
-    ) -> Result<(), &'a str> {
-        let num_endpoints = builder.endpoints.len() as u8;
+        build: impl Fn(Speed) -> Result<Option<D>, Error>,
+    ) -> Result<(), Error> {
+        for (speed, descriptors) in self.speeds.iter().zip(self.descriptors.iter_mut()) {
+            if let Some(descriptor) = build(*speed)? {
+                descriptors.push(Box::new(descriptor));
+            }
+        }
+        Ok(())
+    }
+}
 
-        self.add_string_descriptor(&builder.interface)?;
+/// Assigns the endpoint addresses of one configuration.
+/// Alternate settings of one interface may reuse endpoints, other interfaces may not.
+struct EndpointAllocator<'a> {
+    pool: &'a EndpointPool,
+    configuration_value: u8,
+    /// The fixed addresses of the whole configuration, automatic addresses avoid them
+    reserved: Vec<u8>,
+    /// The addresses of the previous interfaces
+    claimed: Vec<u8>,
+    /// The addresses of every alternate setting of the current interface
+    interface: Vec<u8>,
+    /// The addresses of the current alternate setting
+    setting: Vec<u8>,
+}
 
-        let descriptor = builder.build(
-            self.interface_descriptor_index,
-            self.string_descriptor_index,
-            num_endpoints,
-        )?;
-        let w_value =
-            encode_w_value(&INTERFACE_DESCRIPTOR_TYPE, self.interface_descriptor_index).unwrap(); // TODO
-        self.configuration_descriptor_index += 1;
-        self.configuration_total_length += INTERFACE_DESCRIPTOR_LENGTH as u16;
-        self.descriptors.push(Box::new(descriptor));
-        self.w_values.push(w_value);
-        Ok(())
+impl<'a> EndpointAllocator<'a> {
+    fn new(pool: &'a EndpointPool, builder: &ConfigurationBuilder) -> EndpointAllocator<'a> {
+        let reserved = builder
+            .interfaces
+            .iter()
+            .flat_map(|entry| entry.interfaces())
+            .flat_map(|interface| interface.alternate_settings.iter())
+            .flat_map(|setting| setting.endpoints.iter())
+            .filter_map(|endpoint| match endpoint.endpoint_address {
+                AddressAssignment::Fixed(address) => address.encode().ok(),
+                AddressAssignment::Auto(_) => None,
+            })
+            .collect();
+        EndpointAllocator {
+            pool,
+            configuration_value: builder.configuration_value,
+            reserved,
+            claimed: Vec::new(),
+            interface: Vec::new(),
+            setting: Vec::new(),
+        }
+    }
+
+    /// Whether `address` collides with an endpoint of another interface or the current setting
+    fn taken(&self, address: u8) -> bool {
+        self.claimed
+            .iter()
+            .chain(self.setting.iter())
+            .any(|other| self.pool.conflicts(address, *other))
+    }
+
+    /// Checks a fixed address against the pool and the other endpoints, or takes the next
+    /// free endpoint from the pool
+    fn assign(&mut self, assignment: AddressAssignment) -> Result<EndpointAddress, Error> {
+        let address = match assignment {
+            AddressAssignment::Fixed(address) => {
+                let encoded = self.pool.validate(address)?;
+                if self.taken(encoded) {
+                    return Err(Error::DuplicateEndpoint {
+                        configuration_value: self.configuration_value,
+                        address: encoded,
+                    });
+                }
+                address
+            }
+            AddressAssignment::Auto(direction) => self
+                .pool
+                .allocate(direction, |address| {
+                    self.taken(address)
+                        || self
+                            .reserved
+                            .iter()
+                            .any(|other| self.pool.conflicts(address, *other))
+                })?
+                .ok_or(Error::EndpointsExhausted {
+                    configuration_value: self.configuration_value,
+                })?,
+        };
+        self.setting.push(address.encode()?);
+        Ok(address)
+    }
+
+    /// The next alternate setting of the same interface may reuse the endpoints
+    fn end_setting(&mut self) {
+        for address in self.setting.drain(..) {
+            if !self.interface.contains(&address) {
+                self.interface.push(address);
+            }
+        }
     }
 
-    fn add_configuration_descriptor<'a>(
+    /// The next interfaces may not reuse the endpoints
+    fn end_interface(&mut self) {
+        self.end_setting();
+        self.claimed.append(&mut self.interface);
+    }
+}
+
+impl DescriptorsBuilder {
+    /// Adds the configuration bundle for every speed in `speeds`. The full and high speed
+    /// bundles are also served as Other Speed Configuration at the other speed.
+    fn add_configuration_bundle(
         &mut self,
-        builder: &'a ConfigurationBuilder,
-    ) -> Result<(), &'a str> {
-        let num_interfaces = builder.interfaces.len() as u8;
+        index: u8,
+        builder: &ConfigurationBuilder,
+        speeds: &[Speed],
+        endpoint_pool: &EndpointPool,
+    ) -> Result<(), Error> {
+        let configuration = self.add_optional_string_descriptor(&builder.configuration)?;
 
-        self.add_string_descriptor(&builder.configuration)?;
+        let mut descriptors = SpeedDescriptors::new(speeds);
+        let mut endpoints = EndpointAllocator::new(endpoint_pool, builder);
+        let mut interface_number = 0;
+        for entry in builder.interfaces.iter() {
+            if let ConfigurationEntry::Association(association) = entry {
+                let function = self.add_optional_string_descriptor(&association.function)?;
+                let first_interface = count(
+                    interface_number,
+                    DescriptorType::InterfaceAssociation,
+                    "bFirstInterface",
+                )?;
+                let interface_count = count(
+                    association.interfaces.len(),
+                    DescriptorType::InterfaceAssociation,
+                    "bInterfaceCount",
+                )?;
+                descriptors
+                    .push(|_| association.build(first_interface, interface_count, function))?;
+            }
 
-        let descriptor = builder.build(
-            self.configuration_total_length,
-            num_interfaces,
-            self.configuration_descriptor_index,
-            self.string_descriptor_index,
+            for alternate_settings in entry.interfaces() {
+                self.add_interface(
+                    interface_number,
+                    alternate_settings,
+                    builder.configuration_value,
+                    &mut descriptors,
+                    &mut endpoints,
+                )?;
+                interface_number += 1;
+            }
+        }
+        let num_interfaces = count(
+            interface_number,
+            DescriptorType::Configuration,
+            "bNumInterfaces",
         )?;
-        let w_value = encode_w_value(
-            &CONFIGURATION_DESCRIPTOR_TYPE,
-            self.interface_descriptor_index,
-        )
-        .unwrap(); // TODO
-        self.configuration_descriptor_index += 1;
-        self.configuration_total_length = 0;
-        self.descriptors.push(Box::new(descriptor));
-        self.w_values.push(w_value);
+
+        let SpeedDescriptors {
+            speeds,
+            served,
+            descriptors,
+        } = descriptors;
+        for ((speed, (descriptor_type, served_at)), descriptors) in
+            speeds.into_iter().zip(served).zip(descriptors)
+        {
+            // wTotalLength is computed by the bundle
+            let descriptor = builder.build(
+                speed,
+                0,
+                num_interfaces,
+                builder.configuration_value,
+                configuration,
+            )?;
+            let w_value = encode_w_value(&descriptor_type, index)?;
+            self.push_for_speed(
+                Box::new(ConfigurationBundle {
+                    descriptor_type,
+                    configuration: descriptor,
+                    descriptors,
+                }),
+                w_value,
+                0,
+                served_at,
+            );
+        }
         Ok(())
     }
 
-    fn add_device_descriptor<'a>(&mut self, builder: &'a DeviceBuilder) -> Result<(), &'a str> {
-        let num_configurations = builder.configurations.len() as u8;
+    fn add_device_descriptor(&mut self, builder: &DeviceBuilder) -> Result<(), Error> {
+        let num_configurations = count(
+            builder.configurations.len(),
+            DescriptorType::Device,
+            "bNumConfigurations",
+        )?;
 
-        self.add_string_descriptor(&builder.serial_number)?;
-        self.add_string_descriptor(&builder.product)?;
-        self.add_string_descriptor(&builder.manufacturer)?;
+        let manufacturer = self.add_optional_string_descriptor(&builder.manufacturer)?;
+        let product = self.add_optional_string_descriptor(&builder.product)?;
+        let serial_number = self.add_optional_string_descriptor(&builder.serial_number)?;
 
-        let descriptor = builder.build(
-            num_configurations,
-            self.string_descriptor_index + 1,
-            self.string_descriptor_index + 2,
-            self.string_descriptor_index + 3,
-        )?;
-        let w_value =
-            encode_w_value(&DEVICE_DESCRIPTOR_TYPE, self.interface_descriptor_index).unwrap(); // TODO
-        self.configuration_descriptor_index += 1;
-        self.configuration_total_length = 0;
-        self.descriptors.push(Box::new(descriptor));
-        self.w_values.push(w_value);
+        let speeds = builder.speeds();
+        for speed in speeds.iter().copied() {
+            let descriptor = builder.build(
+                speed,
+                num_configurations,
+                manufacturer,
+                product,
+                serial_number,
+            )?;
+            let w_value = encode_w_value(&DEVICE_DESCRIPTOR_TYPE, 0)?;
+            self.push_for_speed(Box::new(descriptor), w_value, 0, served_at(speed, &speeds));
+
+            if let Some(qualifier) = builder.build_qualifier(speed, num_configurations)? {
+                let w_value = encode_w_value(&DEVICE_QUALIFIER_DESCRIPTOR_TYPE, 0)?;
+                self.push_for_speed(Box::new(qualifier), w_value, 0, served_at(speed, &speeds));
+            }
+        }
+
+        if let Some(bos) = builder.build_bos()? {
+            let w_value = encode_w_value(&BOS_DESCRIPTOR_TYPE, 0)?;
+            self.push(Box::new(bos), w_value, 0);
+        }
         Ok(())
     }
 
-    fn add_string_descriptor<'a>(&mut self, builder: &'a StringBuidler) -> Result<(), &'a str> {
-        let string_index = self.string_descriptor_index;
-        if string_index > 0 {
-            self.string_descriptor_index -= 1;
+    /// Adds every alternate setting of an interface with its endpoints.
+    /// Alternate settings of one interface may reuse endpoints, other interfaces may not.
+    fn add_interface(
+        &mut self,
+        interface_number: usize,
+        builder: &AlternateSettingsBuilder,
+        configuration_value: u8,
+        descriptors: &mut SpeedDescriptors,
+        endpoints: &mut EndpointAllocator,
+    ) -> Result<(), Error> {
+        builder.validate()?;
+        let interface_number = count(
+            interface_number,
+            DescriptorType::Interface,
+            "bInterfaceNumber",
+        )?;
+
+        let mut registered_hid = None;
+        for (alternate_setting, interface_builder) in builder.alternate_settings.iter().enumerate()
+        {
+            let alternate_setting = count(
+                alternate_setting,
+                DescriptorType::Interface,
+                "bAlternateSetting",
+            )?;
+            let interface = self.add_optional_string_descriptor(&interface_builder.interface)?;
+            let num_endpoints = count(
+                interface_builder.endpoints.len(),
+                DescriptorType::Interface,
+                "bNumEndpoints",
+            )?;
+            descriptors.push(|_| {
+                interface_builder.build(
+                    interface_number,
+                    alternate_setting,
+                    interface,
+                    num_endpoints,
+                )
+            })?;
+
+            // The HID descriptor sits between the interface and its endpoints
+            if let Some(hid_builder) = &interface_builder.hid {
+                let report = hid_builder.report.build()?;
+                let length = report.encode()?.len();
+                let report_length = u16::try_from(length).map_err(|_| Error::LengthOverflow {
+                    descriptor: DescriptorType::Report,
+                    field: "wDescriptorLength",
+                    length,
+                })?;
+                let hid = hid_builder.build(report_length);
+                descriptors.push(|_| Ok(hid.clone()))?;
+
+                // GET_DESCRIPTOR(HID/Report) is answered per interface of the configuration
+                match registered_hid {
+                    Some(registered) if registered != hid_builder => {
+                        return Err(Error::ConflictingHid {
+                            configuration_value,
+                            interface_number,
+                        });
+                    }
+                    Some(_) => {}
+                    None => {
+                        let w_value = encode_w_value(&HID_DESCRIPTOR_TYPE, 0)?;
+                        self.push_for_configuration(
+                            Box::new(hid),
+                            w_value,
+                            interface_number as u16,
+                            configuration_value,
+                        );
+                        let w_value = encode_w_value(&REPORT_DESCRIPTOR_TYPE, 0)?;
+                        self.push_for_configuration(
+                            Box::new(report),
+                            w_value,
+                            interface_number as u16,
+                            configuration_value,
+                        );
+                        registered_hid = Some(hid_builder);
+                    }
+                }
+            }
+
+            for endpoint_builder in interface_builder.endpoints.iter() {
+                let address = endpoints.assign(endpoint_builder.endpoint_address)?;
+                descriptors.push(|speed| endpoint_builder.build(address, speed))?;
+                descriptors.push_some(|speed| endpoint_builder.build_companion(speed))?;
+                descriptors
+                    .push_some(|speed| endpoint_builder.build_super_speed_plus_companion(speed))?;
+            }
+            endpoints.end_setting();
         }
-        let descriptor = builder.build(string_index);
-        let w_value = encode_w_value(&STRING_DESCRIPTOR_TYPE, string_index)?;
-        self.descriptors.push(Box::new(descriptor));
-        self.w_values.push(w_value);
+        endpoints.end_interface();
         Ok(())
     }
 
-    pub fn build<'a>(
-        device_builder: &'a DeviceBuilder,
-        language: &'a StringBuidler,
-    ) -> Result<DescriptorsBuilder, &'a str> {
-        let mut helper = DescriptorsBuilder::default();
+    /// Like `add_string_descriptor`, but returns index 0 for `None`
+    fn add_optional_string_descriptor(
+        &mut self,
+        builder: &Option<StringBuidler>,
+    ) -> Result<u8, Error> {
+        match builder {
+            Some(builder) => self.add_string_descriptor(builder),
+            None => Ok(0),
+        }
+    }
+
+    /// Stores one string descriptor per supported language.
+    /// Returns the index the string descriptor was stored at.
+    fn add_string_descriptor(&mut self, builder: &StringBuidler) -> Result<u8, Error> {
+        let string_index: u8 = match self.string_descriptor_index.try_into() {
+            Ok(string_index) => string_index,
+            Err(_) => {
+                return Err(Error::TooManyStrings {
+                    count: self.string_descriptor_index + 1,
+                })
+            }
+        };
+        self.string_descriptor_index += 1;
 
-        let mut string_descriptor_num = 4; // 1x language + 3x in device descriptor
-        for configuration_builder in device_builder.configurations.iter() {
-            for _ in configuration_builder.interfaces.iter() {
-                string_descriptor_num += 1;
+        let w_value = encode_w_value(&STRING_DESCRIPTOR_TYPE, string_index)?;
+        if string_index == 0 {
+            self.push(Box::new(builder.build(string_index)), w_value, 0);
+        } else {
+            for language in self.languages.clone() {
+                let descriptor = builder.build_for_language(string_index, language);
+                self.push(Box::new(descriptor), w_value, language.0);
             }
-            string_descriptor_num += 1;
         }
-        helper.string_descriptor_index = string_descriptor_num as u8 - 1;
+        Ok(string_index)
+    }
 
-        for configuration_builder in device_builder.configurations.iter().rev() {
-            for interface_builder in configuration_builder.interfaces.iter().rev() {
-                for endpoint_builder in interface_builder.endpoints.iter().rev() {
-                    helper.add_endpoint_descriptor(endpoint_builder)?;
-                }
-                helper.add_interface_descriptor(interface_builder)?;
+    fn push(&mut self, descriptor: Box<dyn Descriptor>, w_value: u16, w_index: u16) {
+        self.push_for_speed(descriptor, w_value, w_index, None);
+    }
+
+    fn push_for_speed(
+        &mut self,
+        descriptor: Box<dyn Descriptor>,
+        w_value: u16,
+        w_index: u16,
+        speed: Option<Speed>,
+    ) {
+        self.descriptors.push(descriptor);
+        self.w_values.push(w_value);
+        self.w_indexes.push(w_index);
+        self.speeds.push(speed);
+        self.configuration_values.push(None);
+    }
+
+    /// Like `push` for class descriptors that belong to one configuration
+    fn push_for_configuration(
+        &mut self,
+        descriptor: Box<dyn Descriptor>,
+        w_value: u16,
+        w_index: u16,
+        configuration_value: u8,
+    ) {
+        self.descriptors.push(descriptor);
+        self.w_values.push(w_value);
+        self.w_indexes.push(w_index);
+        self.speeds.push(None);
+        self.configuration_values.push(Some(configuration_value));
+    }
+
+    pub fn build(
+        device_builder: &DeviceBuilder,
+        language: &StringBuidler,
+    ) -> Result<DescriptorsBuilder, Error> {
+        let languages = match &language.string {
+            StringContent::Languages(languages) => languages.clone(),
+            _ => {
+                return Err(Error::Missing {
+                    descriptor: DescriptorType::String,
+                    field: "wLANGID",
+                })
             }
-            helper.add_configuration_descriptor(configuration_builder)?;
-        }
-        helper.add_device_descriptor(&device_builder)?;
-        helper.add_string_descriptor(&language)?;
+        };
+        let mut helper = DescriptorsBuilder {
+            languages,
+            ..Default::default()
+        };
 
-        helper.descriptors.reverse();
-        helper.w_values.reverse();
+        helper.add_string_descriptor(language)?;
+        helper.add_device_descriptor(device_builder)?;
+        let speeds = device_builder.speeds();
+        for (index, configuration_builder) in device_builder.configurations.iter().enumerate() {
+            helper.add_configuration_bundle(
+                index as u8,
+                configuration_builder,
+                &speeds,
+                &device_builder.endpoint_pool,
+            )?;
+        }
 
         Ok(helper)
     }
 }
 
-impl Default for DescriptorsBuilder {
-    fn default() -> Self {
-        DescriptorsBuilder {
-            descriptors: Vec::<Box<dyn Descriptor>>::new(),
-            w_values: Vec::<u16>::new(),
-            configuration_total_length: 0,
-            configuration_descriptor_index: 0,
-            interface_descriptor_index: 0,
-            string_descriptor_index: 0,
-        }
+/// Descriptors of devices with a single speed are served at every speed
+fn served_at(speed: Speed, speeds: &[Speed]) -> Option<Speed> {
+    match speeds.len() {
+        1 => None,
+        _ => Some(speed),
     }
 }
+
+/// Converts the number of child descriptors into a `bNum*` field
+fn count(len: usize, descriptor: DescriptorType, field: &'static str) -> Result<u8, Error> {
+    len.try_into().map_err(|_| Error::FieldOutOfRange {
+        descriptor,
+        field,
+        value: len as u32,
+    })
+}
@@ -1,12 +1,26 @@
 use alloc::vec::Vec;
 
 use crate::{
+    bos::{
+        bos_builder::BosBuilder, bos_bundle::BosBundle, bos_descriptor::BOS_DESCRIPTOR_TYPE,
+        device_capability_builder::DeviceCapabilityBuilder,
+    },
     configuration::configuration_builder::ConfigurationBuilder,
-    string::string_builder::StringBuidler, version::Version,
+    descriptor::DecodeDescriptor,
+    endpoint::endpoint_pool::EndpointPool,
+    error::{DecodeError, Error},
+    speed::Speed,
+    string::{string_builder::StringBuidler, string_descriptor::StringDescriptor},
+    version::{Version, USB1_0, USB2_0, USB2_1, USB3_0, USB3_1},
 };
 
-use super::{device_class::DeviceClass, device_descriptor::DeviceDescriptor};
+use super::{
+    device_class::DeviceClass,
+    device_descriptor::{DeviceDescriptor, DEVICE_DESCRIPTOR_TYPE},
+    device_qualifier_descriptor::DeviceQualifierDescriptor,
+};
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeviceBuilder {
     /// Turns into `bcdUSB`
     pub usb: Version,
@@ -16,8 +30,11 @@ pub struct DeviceBuilder {
     pub device_suclass: u8,
     /// Turns into `bDeviceProtocol`
     pub device_protocol: u8,
-    /// Turns into `bMaxPacketSize0`
+    /// Turns into `bMaxPacketSize0` at full speed, the other speeds have a fixed size
     pub max_packet_size_0: u8,
+    /// The fastest speed the device operates at, see `Speed::up_to`.
+    /// High-speed capable devices have a device qualifier.
+    pub max_speed: Speed,
     /// Turns into `idVendor`
     pub id_vendor: u16,
     /// Turns into `idProduct`
@@ -25,39 +42,309 @@ pub struct DeviceBuilder {
     /// Turns into `bcdDevice`
     pub device: Version,
     /// Turns into `iManufacturer`
-    pub manufacturer: StringBuidler,
+    pub manufacturer: Option<StringBuidler>,
     /// Turns into `iProduct`
-    pub product: StringBuidler,
+    pub product: Option<StringBuidler>,
     /// Turns into `iSerialNumber`
-    pub serial_number: StringBuidler,
+    pub serial_number: Option<StringBuidler>,
     /// Turns into `bNumConfigurations`
     pub configurations: Vec<ConfigurationBuilder>,
+    /// Turns into the BOS descriptor, requires `bcdUSB` 2.1 or later
+    pub bos: Option<BosBuilder>,
+    /// The endpoints of the device controller. Fixed endpoint addresses have to be in the
+    /// pool, automatic ones are taken from it.
+    pub endpoint_pool: EndpointPool,
 }
 
 impl DeviceBuilder {
+    /// Builds the device descriptor as it looks at `speed`
     pub fn build(
         &self,
+        speed: Speed,
         num_configurations: u8,
         manufacturer: u8,
         product: u8,
         serial_number: u8,
-    ) -> Result<DeviceDescriptor, &str> {
+    ) -> Result<DeviceDescriptor, Error> {
         self.device_class
             .validate(self.device_suclass, self.device_protocol)?;
+        if self
+            .configurations
+            .iter()
+            .any(|configuration| configuration.has_associations())
+        {
+            self.device_class
+                .validate_interface_association(self.device_suclass, self.device_protocol)?;
+        }
 
         Ok(DeviceDescriptor {
-            usb: self.usb,
+            usb: self.usb_at(speed)?,
             device_class: self.device_class,
             device_suclass: self.device_suclass,
             device_protocol: self.device_protocol,
-            max_packet_size_0: self.max_packet_size_0,
+            max_packet_size_0: self.max_packet_size_0_at(speed)?,
             id_vendor: self.id_vendor,
             id_product: self.id_product,
             device: self.device,
-            manufacturer: manufacturer,
-            product: product,
-            serial_number: serial_number,
+            manufacturer,
+            product,
+            serial_number,
             num_configurations,
         })
     }
+
+    /// The speeds the device can operate at
+    pub fn speeds(&self) -> Vec<Speed> {
+        self.max_speed.up_to()
+    }
+
+    /// `bcdUSB` at `speed`. SuperSpeed devices report 2.1 (0x0210) at the USB 2.0 speeds.
+    /// The BOS descriptor tells the host about the SuperSpeed capabilities.
+    pub fn usb_at(&self, speed: Speed) -> Result<Version, Error> {
+        let minimum = match speed {
+            Speed::Low | Speed::Full => USB1_0,
+            Speed::High => USB2_0,
+            Speed::Super => USB3_0,
+            Speed::SuperPlus => USB3_1,
+        };
+        if self.usb < minimum {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DEVICE_DESCRIPTOR_TYPE,
+                field: "bcdUSB",
+                value: self.usb.bcd() as u32,
+            });
+        }
+
+        if !speed.is_super_speed() && self.usb >= USB3_0 {
+            Ok(USB2_1)
+        } else {
+            Ok(self.usb)
+        }
+    }
+
+    /// `bMaxPacketSize0` at `speed`. At SuperSpeed it is the exponent of 512.
+    pub fn max_packet_size_0_at(&self, speed: Speed) -> Result<u8, Error> {
+        match speed {
+            Speed::Low => Ok(8),
+            Speed::Full => match self.max_packet_size_0 {
+                8 | 16 | 32 | 64 => Ok(self.max_packet_size_0),
+                max_packet_size_0 => Err(Error::FieldOutOfRange {
+                    descriptor: DEVICE_DESCRIPTOR_TYPE,
+                    field: "bMaxPacketSize0",
+                    value: max_packet_size_0 as u32,
+                }),
+            },
+            Speed::High => Ok(64),
+            Speed::Super | Speed::SuperPlus => Ok(9),
+        }
+    }
+
+    /// The device qualifier served at `speed`, `None` if the device has no other speed there.
+    /// The qualifier describes the other speed, which has the same configurations.
+    pub fn build_qualifier(
+        &self,
+        speed: Speed,
+        num_configurations: u8,
+    ) -> Result<Option<DeviceQualifierDescriptor>, Error> {
+        let other_speed = match speed.other_speed() {
+            Some(other_speed) if self.max_speed >= Speed::High => other_speed,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(DeviceQualifierDescriptor {
+            usb: self.usb_at(other_speed)?,
+            device_class: self.device_class,
+            device_suclass: self.device_suclass,
+            device_protocol: self.device_protocol,
+            max_packet_size_0: self.max_packet_size_0_at(other_speed)?,
+            num_configurations,
+        }))
+    }
+
+    /// The BOS descriptor with its device capabilities, `None` if the device has none
+    pub fn build_bos(&self) -> Result<Option<BosBundle>, Error> {
+        let bos = match &self.bos {
+            Some(bos) => bos,
+            None => return Ok(None),
+        };
+        // the SuperSpeedPlus capability belongs to USB 3.1 and later
+        let super_speed_plus = bos
+            .capabilities
+            .iter()
+            .any(|capability| matches!(capability, DeviceCapabilityBuilder::SuperSpeedPlus(_)));
+        let minimum = if super_speed_plus { USB3_1 } else { USB2_1 };
+        if self.usb < minimum {
+            return Err(Error::FieldOutOfRange {
+                descriptor: BOS_DESCRIPTOR_TYPE,
+                field: "bcdUSB",
+                value: self.usb.bcd() as u32,
+            });
+        }
+
+        Ok(Some(bos.build()?))
+    }
+
+    /// Rebuilds the builder tree from a device descriptor, the GET_DESCRIPTOR(Configuration)
+    /// response of every configuration and the string descriptors of the device.
+    /// `speed` is the speed the device was read at, it becomes the maximum speed.
+    /// `reports` are the report descriptors of HID interfaces, see `ConfigurationBuilder::decode`.
+    pub fn decode(
+        device: &[u8],
+        configurations: &[&[u8]],
+        speed: Speed,
+        strings: &[StringDescriptor],
+        reports: &[(u8, u8, &[u8])],
+    ) -> Result<DeviceBuilder, DecodeError> {
+        let descriptor = DeviceDescriptor::decode(device)?;
+        if descriptor.num_configurations as usize != configurations.len() {
+            return Err(DecodeError::InvalidValue {
+                field: "bNumConfigurations",
+                value: descriptor.num_configurations as u16,
+            });
+        }
+        // the sizes of the faster speeds are fixed, see `max_packet_size_0_at`
+        let max_packet_size_0 = match (speed, descriptor.max_packet_size_0) {
+            (Speed::Low | Speed::Full, max_packet_size_0) => max_packet_size_0,
+            (Speed::High, 64) | (Speed::Super | Speed::SuperPlus, 9) => 64,
+            (_, max_packet_size_0) => {
+                return Err(DecodeError::InvalidValue {
+                    field: "bMaxPacketSize0",
+                    value: max_packet_size_0 as u16,
+                })
+            }
+        };
+
+        Ok(DeviceBuilder {
+            usb: descriptor.usb,
+            device_class: descriptor.device_class,
+            device_suclass: descriptor.device_suclass,
+            device_protocol: descriptor.device_protocol,
+            max_packet_size_0,
+            max_speed: speed,
+            id_vendor: descriptor.id_vendor,
+            id_product: descriptor.id_product,
+            device: descriptor.device,
+            manufacturer: StringBuidler::resolve(descriptor.manufacturer, strings)?,
+            product: StringBuidler::resolve(descriptor.product, strings)?,
+            serial_number: StringBuidler::resolve(descriptor.serial_number, strings)?,
+            configurations: configurations
+                .iter()
+                .map(|bytes| ConfigurationBuilder::decode(bytes, speed, strings, reports))
+                .collect::<Result<Vec<ConfigurationBuilder>, DecodeError>>()?,
+            bos: None,
+            endpoint_pool: EndpointPool::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        descriptor::Descriptor,
+        descriptor_type::DescriptorType,
+        version::{USB1_1, USB3_2},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let device = [
+            0x12, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x34, 0x12, 0x78, 0x56, 0x00, 0x01,
+            0x01, 0x02, 0x00, 0x01,
+        ];
+        let configuration = [
+            0x09, 0x02, 0x12, 0x00, 0x01, 0x01, 0x00, 0x80, 0xfa, // Configuration
+            0x09, 0x04, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, // Interface
+        ];
+        let strings = vec![
+            StringDescriptor::decode(0, &[4, 3, 0x09, 0x04]).unwrap(),
+            StringBuidler::text("MA").build(1),
+            StringBuidler::text("PR").build(2),
+        ];
+
+        let builder =
+            DeviceBuilder::decode(&device, &[&configuration], Speed::Full, &strings, &[]).unwrap();
+        assert_eq!(builder.usb, USB2_0);
+        assert_eq!(builder.device_class, DeviceClass::Device);
+        assert_eq!(builder.id_vendor, 0x1234);
+        assert_eq!(builder.id_product, 0x5678);
+        assert_eq!(builder.manufacturer, Some(StringBuidler::text("MA")));
+        assert_eq!(builder.product, Some(StringBuidler::text("PR")));
+        assert_eq!(builder.serial_number, None);
+        assert_eq!(builder.configurations.len(), 1);
+        assert_eq!(builder.configurations[0].interfaces.len(), 1);
+
+        assert_eq!(
+            DeviceBuilder::decode(&device, &[], Speed::Full, &strings, &[]),
+            Err(DecodeError::InvalidValue {
+                field: "bNumConfigurations",
+                value: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_speeds() {
+        let device = [
+            0x12, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, 0x34, 0x12, 0x78, 0x56, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let mut builder = DeviceBuilder::decode(&device, &[], Speed::Full, &[], &[]).unwrap();
+        assert_eq!(builder.speeds(), vec![Speed::Full]);
+        assert_eq!(builder.build_qualifier(Speed::Full, 0), Ok(None));
+
+        // the qualifier describes the other speed
+        builder.max_speed = Speed::High;
+        let qualifier = builder.build_qualifier(Speed::Full, 0).unwrap().unwrap();
+        assert_eq!(qualifier.usb, USB2_0);
+        assert_eq!(qualifier.max_packet_size_0, 64);
+        assert_eq!(qualifier.num_configurations, 0);
+        builder.max_packet_size_0 = 8;
+        let qualifier = builder.build_qualifier(Speed::High, 0).unwrap().unwrap();
+        assert_eq!(qualifier.max_packet_size_0, 8);
+
+        builder.max_packet_size_0 = 9;
+        assert_eq!(
+            builder.build_qualifier(Speed::High, 0),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Device,
+                field: "bMaxPacketSize0",
+                value: 9
+            })
+        );
+
+        builder.max_packet_size_0 = 64;
+        builder.usb = USB1_1;
+        assert_eq!(
+            builder.build_qualifier(Speed::Full, 0),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Device,
+                field: "bcdUSB",
+                value: 0x0110
+            })
+        );
+
+        builder.usb = USB3_2;
+        builder.max_speed = Speed::SuperPlus;
+        let descriptor = builder.build(Speed::Super, 0, 0, 0, 0).unwrap();
+        assert_eq!(descriptor.usb, USB3_2);
+        assert_eq!(descriptor.max_packet_size_0, 9);
+        let descriptor = builder.build(Speed::High, 0, 0, 0, 0).unwrap();
+        assert_eq!(descriptor.usb, USB2_1);
+        assert_eq!(descriptor.encode().unwrap()[2..4], [0x10, 0x02]);
+        assert_eq!(builder.usb_at(Speed::Full), Ok(USB2_1));
+        assert_eq!(descriptor.max_packet_size_0, 64);
+        assert_eq!(builder.build_qualifier(Speed::Super, 0), Ok(None));
+
+        builder.usb = USB2_1;
+        assert_eq!(
+            builder.build(Speed::Super, 0, 0, 0, 0),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Device,
+                field: "bcdUSB",
+                value: 0x0210
+            })
+        );
+    }
 }
@@ -1,4 +1,8 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DeviceClass {
@@ -9,10 +13,12 @@ pub enum DeviceClass {
     Diagnostic,
     Miscellaneous,
     VendorSpecific,
+    /// A class code without a variant, e.g. decoded from a device. It is not validated.
+    Other(u8),
 }
 
 impl EncodeByte for DeviceClass {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         match *self {
             DeviceClass::Device => Ok(0x00),
             DeviceClass::CommunicationAndCDCControl => Ok(0x02),
@@ -21,13 +27,34 @@ impl EncodeByte for DeviceClass {
             DeviceClass::Diagnostic => Ok(0xDC),
             DeviceClass::Miscellaneous => Ok(0xEF),
             DeviceClass::VendorSpecific => Ok(0xFF),
+            DeviceClass::Other(class) => Ok(class),
+        }
+    }
+}
+
+impl DecodeByte for DeviceClass {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(DeviceClass::Device),
+            0x02 => Ok(DeviceClass::CommunicationAndCDCControl),
+            0x09 => Ok(DeviceClass::Hub),
+            0x11 => Ok(DeviceClass::Billboard),
+            0xDC => Ok(DeviceClass::Diagnostic),
+            0xEF => Ok(DeviceClass::Miscellaneous),
+            0xFF => Ok(DeviceClass::VendorSpecific),
+            _ => Ok(DeviceClass::Other(byte)),
         }
     }
 }
 
 impl DeviceClass {
-    pub fn validate(&self, suclass: u8, protocol: u8) -> Result<(), &str> {
-        let error: Result<(), &str> = Err("The device base class is not compatible with the interface subclass and protocol. Pease check https://www.usb.org/defined-class-codes");
+    pub fn validate(&self, suclass: u8, protocol: u8) -> Result<(), Error> {
+        let error = Err(Error::InvalidClass {
+            descriptor: DescriptorType::Device,
+            class: self.encode()?,
+            subclass: suclass,
+            protocol,
+        });
         match self {
             DeviceClass::Device => match suclass {
                 0x00 => match protocol {
@@ -39,7 +66,7 @@ impl DeviceClass {
             DeviceClass::CommunicationAndCDCControl => Ok(()),
             DeviceClass::Hub => match suclass {
                 0x00 => match protocol {
-                    0x00 | 0x01 | 0x02 => Ok(()),
+                    0x00..=0x02 => Ok(()),
                     _ => error,
                 },
                 _ => error,
@@ -56,7 +83,7 @@ impl DeviceClass {
                     0x01 => Ok(()),
                     _ => error,
                 },
-                0x02 | 0x03 | 0x04 | 0x05 | 0x06 | 0x07 => match protocol {
+                0x02..=0x07 => match protocol {
                     0x00 | 0x01 => Ok(()),
                     _ => error,
                 },
@@ -77,16 +104,31 @@ impl DeviceClass {
                     _ => error,
                 },
                 0x04 => match protocol {
-                    0x01 | 0x02 | 0x03 | 0x04 | 0x05 | 0x06 | 0x07 => Ok(()),
+                    0x01..=0x07 => Ok(()),
                     _ => error,
                 },
                 0x05 | 0x07 => match protocol {
-                    0x00 | 0x01 | 0x02 => Ok(()),
+                    0x00..=0x02 => Ok(()),
                     _ => error,
                 },
                 _ => error,
             },
             DeviceClass::VendorSpecific => Ok(()),
+            DeviceClass::Other(_) => Ok(()),
+        }
+    }
+
+    /// Devices with interface association descriptors have to use the
+    /// Miscellaneous / Common Class / Interface Association triple.
+    pub fn validate_interface_association(&self, suclass: u8, protocol: u8) -> Result<(), Error> {
+        match (self, suclass, protocol) {
+            (DeviceClass::Miscellaneous, 0x02, 0x01) => Ok(()),
+            _ => Err(Error::InvalidClass {
+                descriptor: DescriptorType::Device,
+                class: self.encode()?,
+                subclass: suclass,
+                protocol,
+            }),
         }
     }
 }
@@ -122,4 +164,37 @@ mod tests {
             DEVICE_VENDOR_SPECIFIC
         );
     }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(DeviceClass::Miscellaneous.validate(0x02, 0x01), Ok(()));
+        assert_eq!(
+            DeviceClass::Device.validate(0x01, 0x00),
+            Err(Error::InvalidClass {
+                descriptor: DescriptorType::Device,
+                class: 0x00,
+                subclass: 0x01,
+                protocol: 0x00
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        for class in [
+            DeviceClass::Device,
+            DeviceClass::CommunicationAndCDCControl,
+            DeviceClass::Hub,
+            DeviceClass::Billboard,
+            DeviceClass::Diagnostic,
+            DeviceClass::Miscellaneous,
+            DeviceClass::VendorSpecific,
+        ] {
+            assert_eq!(DeviceClass::decode(class.encode().unwrap()), Ok(class));
+        }
+        // e.g. the application specific class of a firmware update device
+        assert_eq!(DeviceClass::decode(0xFE), Ok(DeviceClass::Other(0xFE)));
+        assert_eq!(DeviceClass::Other(0xFE).encode(), Ok(0xFE));
+        assert_eq!(DeviceClass::Other(0xFE).validate(0x01, 0x02), Ok(()));
+    }
 }
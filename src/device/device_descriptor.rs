@@ -1,9 +1,10 @@
 use alloc::vec::Vec;
 
 use crate::{
-    binary::{EncodeByte, EncodeBytes},
-    descriptor::Descriptor,
+    binary::{expect_descriptor, DecodeByte, DecodeBytes, EncodeByte, EncodeBytes},
+    descriptor::{DecodeDescriptor, Descriptor},
     descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
     version::Version,
 };
 
@@ -41,7 +42,7 @@ pub struct DeviceDescriptor {
 }
 
 impl Descriptor for DeviceDescriptor {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
         self.device_class
             .validate(self.device_suclass, self.device_protocol)?;
 
@@ -62,7 +63,11 @@ impl Descriptor for DeviceDescriptor {
         bytes.push(self.num_configurations);
 
         if bytes.len() != DEVICE_DESCRIPTOR_LENGTH as usize {
-            return Err("device bLength does not match the actual length");
+            return Err(Error::LengthMismatch {
+                descriptor: DEVICE_DESCRIPTOR_TYPE,
+                expected: DEVICE_DESCRIPTOR_LENGTH as usize,
+                actual: bytes.len(),
+            });
         }
 
         Ok(bytes)
@@ -73,15 +78,39 @@ impl Descriptor for DeviceDescriptor {
     }
 }
 
+impl DecodeDescriptor for DeviceDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            DEVICE_DESCRIPTOR_TYPE,
+            Some(DEVICE_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(DeviceDescriptor {
+            usb: Version::decode(&bytes[2..4])?,
+            device_class: DeviceClass::decode(bytes[4])?,
+            device_suclass: bytes[5],
+            device_protocol: bytes[6],
+            max_packet_size_0: bytes[7],
+            id_vendor: u16::from_le_bytes([bytes[8], bytes[9]]),
+            id_product: u16::from_le_bytes([bytes[10], bytes[11]]),
+            device: Version::decode(&bytes[12..14])?,
+            manufacturer: bytes[14],
+            product: bytes[15],
+            serial_number: bytes[16],
+            num_configurations: bytes[17],
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::version::USB2_0;
 
     use super::*;
 
-    #[test]
-    fn test_encode() {
-        let descriptor = DeviceDescriptor {
+    fn descriptor() -> DeviceDescriptor {
+        DeviceDescriptor {
             usb: USB2_0,
             device_class: DeviceClass::Miscellaneous,
             device_suclass: 0x01,
@@ -97,10 +126,13 @@ mod tests {
             product: 0x02,
             serial_number: 0x03,
             num_configurations: 0x01,
-        };
+        }
+    }
 
+    #[test]
+    fn test_encode() {
         assert_eq!(
-            descriptor.encode().unwrap(),
+            descriptor().encode().unwrap(),
             vec![
                 18, // bLength
                 1,  // bDescriptorType
@@ -119,4 +151,45 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_decode() {
+        let bytes = descriptor().encode().unwrap();
+        assert_eq!(DeviceDescriptor::decode(&bytes), Ok(descriptor()));
+
+        assert_eq!(
+            DeviceDescriptor::decode(&bytes[..17]),
+            Err(DecodeError::Truncated {
+                expected: 18,
+                actual: 17
+            })
+        );
+
+        let mut wrong_length = bytes.clone();
+        wrong_length[0] = 17;
+        assert_eq!(
+            DeviceDescriptor::decode(&wrong_length),
+            Err(DecodeError::InvalidLength {
+                descriptor_type: 0x01,
+                length: 17
+            })
+        );
+
+        let mut wrong_type = bytes.clone();
+        wrong_type[1] = 0x02;
+        assert_eq!(
+            DeviceDescriptor::decode(&wrong_type),
+            Err(DecodeError::UnexpectedDescriptorType {
+                expected: DescriptorType::Device,
+                actual: DescriptorType::Configuration
+            })
+        );
+
+        let mut unknown_type = bytes.clone();
+        unknown_type[1] = 0x42;
+        assert_eq!(
+            DeviceDescriptor::decode(&unknown_type),
+            Err(DecodeError::UnknownDescriptorType(0x42))
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+
+use crate::{
+    binary::{expect_descriptor, DecodeByte, DecodeBytes, EncodeByte, EncodeBytes},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+    version::Version,
+};
+
+use super::device_class::DeviceClass;
+
+pub const DEVICE_QUALIFIER_DESCRIPTOR_LENGTH: u8 = 10;
+pub const DEVICE_QUALIFIER_DESCRIPTOR_TYPE: DescriptorType = DescriptorType::DeviceQualifier;
+
+/// How a high-speed capable device would look at the other speed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceQualifierDescriptor {
+    /// Turns into `bcdUSB`
+    pub usb: Version,
+    /// Turns into `bDeviceClass`
+    pub device_class: DeviceClass,
+    /// Turns into `bDeviceSubClass`
+    pub device_suclass: u8,
+    /// Turns into `bDeviceProtocol`
+    pub device_protocol: u8,
+    /// Turns into `bMaxPacketSize0` at the other speed
+    pub max_packet_size_0: u8,
+    /// Turns into `bNumConfigurations` at the other speed
+    pub num_configurations: u8,
+}
+
+impl Descriptor for DeviceQualifierDescriptor {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        self.device_class
+            .validate(self.device_suclass, self.device_protocol)?;
+
+        let mut bytes = Vec::<u8>::new();
+        bytes.push(DEVICE_QUALIFIER_DESCRIPTOR_LENGTH);
+        bytes.push(DEVICE_QUALIFIER_DESCRIPTOR_TYPE.encode()?);
+        bytes.append(self.usb.encode()?.as_mut());
+        bytes.push(self.device_class.encode()?);
+        bytes.push(self.device_suclass);
+        bytes.push(self.device_protocol);
+        bytes.push(self.max_packet_size_0);
+        bytes.push(self.num_configurations);
+        bytes.push(0); // bReserved
+
+        if bytes.len() != DEVICE_QUALIFIER_DESCRIPTOR_LENGTH as usize {
+            return Err(Error::LengthMismatch {
+                descriptor: DEVICE_QUALIFIER_DESCRIPTOR_TYPE,
+                expected: DEVICE_QUALIFIER_DESCRIPTOR_LENGTH as usize,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        DEVICE_QUALIFIER_DESCRIPTOR_TYPE
+    }
+}
+
+impl DecodeDescriptor for DeviceQualifierDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            DEVICE_QUALIFIER_DESCRIPTOR_TYPE,
+            Some(DEVICE_QUALIFIER_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(DeviceQualifierDescriptor {
+            usb: Version::decode(&bytes[2..4])?,
+            device_class: DeviceClass::decode(bytes[4])?,
+            device_suclass: bytes[5],
+            device_protocol: bytes[6],
+            max_packet_size_0: bytes[7],
+            num_configurations: bytes[8],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::version::USB2_0;
+
+    use super::*;
+
+    fn descriptor() -> DeviceQualifierDescriptor {
+        DeviceQualifierDescriptor {
+            usb: USB2_0,
+            device_class: DeviceClass::Miscellaneous,
+            device_suclass: 0x02,
+            device_protocol: 0x01,
+            max_packet_size_0: 0x40,
+            num_configurations: 0x01,
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            descriptor().encode().unwrap(),
+            vec![
+                10, // bLength
+                6,  // bDescriptorType
+                0x00, 0x02, // bcdUSB
+                0xef, // bDeviceClass
+                0x02, // bDeviceSubClass
+                0x01, // bDeviceProtocol
+                0x40, // bMaxPacketSize0
+                0x01, // bNumConfigurations
+                0x00, // bReserved
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let bytes = descriptor().encode().unwrap();
+        assert_eq!(DeviceQualifierDescriptor::decode(&bytes), Ok(descriptor()));
+
+        let mut wrong_type = bytes.clone();
+        wrong_type[1] = 0x01;
+        assert_eq!(
+            DeviceQualifierDescriptor::decode(&wrong_type),
+            Err(DecodeError::UnexpectedDescriptorType {
+                expected: DescriptorType::DeviceQualifier,
+                actual: DescriptorType::Device
+            })
+        );
+    }
+}
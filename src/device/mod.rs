@@ -1,3 +1,4 @@
 pub mod device_class;
 pub mod device_descriptor;
 pub mod device_builder;
+pub mod device_qualifier_descriptor;
@@ -0,0 +1,20 @@
+use super::{direction::Direction, endpoint_address::EndpointAddress};
+
+/// How an endpoint gets its `bEndpointAddress`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AddressAssignment {
+    /// A hand-picked address, it has to be in the endpoint pool of the device
+    Fixed(EndpointAddress),
+    /// The next free endpoint number of the device's endpoint pool in the given direction
+    Auto(Direction),
+}
+
+impl AddressAssignment {
+    /// Shorthand for a fixed address
+    pub const fn fixed(endpoint_number: u8, direction: Direction) -> AddressAssignment {
+        AddressAssignment::Fixed(EndpointAddress {
+            endpoint_number,
+            direction,
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Direction {
@@ -7,7 +10,7 @@ pub enum Direction {
 }
 
 impl EncodeByte for Direction {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         match *self {
             Direction::Out => Ok(0x00),
             Direction::In => Ok(0x01),
@@ -15,6 +18,19 @@ impl EncodeByte for Direction {
     }
 }
 
+impl DecodeByte for Direction {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(Direction::Out),
+            0x01 => Ok(Direction::In),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bEndpointAddress",
+                value: byte as u16,
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,4 +43,10 @@ mod tests {
         assert_eq!(Direction::Out.encode().unwrap(), DIRECTION_OUT);
         assert_eq!(Direction::In.encode().unwrap(), DIRECTION_IN);
     }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(Direction::decode(DIRECTION_OUT), Ok(Direction::Out));
+        assert_eq!(Direction::decode(DIRECTION_IN), Ok(Direction::In));
+    }
 }
@@ -1,4 +1,8 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
 use super::direction::Direction;
 
@@ -14,9 +18,30 @@ impl EncodeByte for EndpointAddress {
     // Bits 0..3b Endpoint Number.
     // Bits 4..6b Reserved. Set to Zero
     // Bits 7 Direction 0 = Out, 1 = In (Ignored for Control Endpoints)
-    fn encode(&self) -> Result<u8, &str> {
-        let byte: u8 = 0;
-        Ok(byte | self.endpoint_number | (self.direction as u8) << 7)
+    fn encode(&self) -> Result<u8, Error> {
+        if self.endpoint_number > 0b0000_1111 {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bEndpointAddress",
+                value: self.endpoint_number as u32,
+            });
+        }
+        Ok(self.endpoint_number | self.direction.encode()? << 7)
+    }
+}
+
+impl DecodeByte for EndpointAddress {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        if byte & 0b0111_0000 != 0 {
+            return Err(DecodeError::InvalidValue {
+                field: "bEndpointAddress",
+                value: byte as u16,
+            });
+        }
+        Ok(EndpointAddress {
+            endpoint_number: byte & 0b0000_1111,
+            direction: Direction::decode(byte >> 7)?,
+        })
     }
 }
 
@@ -43,5 +68,43 @@ mod tests {
             direction: Direction::In,
         };
         assert_eq!(endpoint_address3.encode().unwrap(), 131);
+
+        let endpoint_address4 = EndpointAddress {
+            endpoint_number: 16,
+            direction: Direction::Out,
+        };
+        assert_eq!(
+            endpoint_address4.encode(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bEndpointAddress",
+                value: 16
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            EndpointAddress::decode(129),
+            Ok(EndpointAddress {
+                endpoint_number: 1,
+                direction: Direction::In,
+            })
+        );
+        assert_eq!(
+            EndpointAddress::decode(2),
+            Ok(EndpointAddress {
+                endpoint_number: 2,
+                direction: Direction::Out,
+            })
+        );
+        assert_eq!(
+            EndpointAddress::decode(0x91),
+            Err(DecodeError::InvalidValue {
+                field: "bEndpointAddress",
+                value: 0x91
+            })
+        );
     }
 }
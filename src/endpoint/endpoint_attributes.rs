@@ -1,4 +1,7 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 use super::{sync_type::SyncType, transfer_type::TransferType, usage_type::UsageType};
 
@@ -26,7 +29,7 @@ impl EncodeByte for EndpointAttributes {
     //   01 = Feedback Endpoint
     //   10 = Explicit Feedback Data Endpoint
     //   11 = Reserved
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         let transfer_type = self.transfer_type as u8;
         let sync_type = self.sync_type as u8;
         let usage_type = self.usage_type as u8;
@@ -35,6 +38,22 @@ impl EncodeByte for EndpointAttributes {
     }
 }
 
+impl DecodeByte for EndpointAttributes {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        if byte & 0b1100_0000 != 0 {
+            return Err(DecodeError::InvalidValue {
+                field: "bmAttributes",
+                value: byte as u16,
+            });
+        }
+        Ok(EndpointAttributes {
+            transfer_type: TransferType::decode(byte & 0b11)?,
+            sync_type: SyncType::decode((byte >> 2) & 0b11)?,
+            usage_type: UsageType::decode((byte >> 4) & 0b11)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +70,32 @@ mod tests {
         let endpoint_attributes_encoded = 0b00000011;
         assert_eq!(endpoint_attributes, endpoint_attributes_encoded);
     }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            EndpointAttributes::decode(0b0000_0011),
+            Ok(EndpointAttributes {
+                transfer_type: TransferType::Interrupt,
+                sync_type: SyncType::NoSync,
+                usage_type: UsageType::Data,
+            })
+        );
+        assert_eq!(
+            EndpointAttributes::decode(0b0001_0101),
+            Ok(EndpointAttributes {
+                transfer_type: TransferType::Isochronous,
+                sync_type: SyncType::Asynchronous,
+                usage_type: UsageType::Feedback,
+            })
+        );
+        // usage type 0b11 is reserved
+        assert_eq!(
+            EndpointAttributes::decode(0b0011_0001),
+            Err(DecodeError::InvalidValue {
+                field: "bmAttributes",
+                value: 0b11
+            })
+        );
+    }
 }
@@ -1,33 +1,585 @@
+use crate::{descriptor_type::DescriptorType, error::Error, speed::Speed};
+
 use super::{
+    address_assignment::AddressAssignment,
     endpoint_address::EndpointAddress,
     endpoint_attributes::EndpointAttributes,
     endpoint_descriptor::{EndpointDescriptor, ENDPOINT_DESCRIPTOR_LENGTH},
+    interval::Interval,
+    super_speed_endpoint_companion_descriptor::{
+        SuperSpeedEndpointCompanionDescriptor, SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+    },
+    super_speed_plus_isochronous_endpoint_companion_descriptor::{
+        SuperSpeedPlusIsochronousEndpointCompanionDescriptor,
+        SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+    },
+    transfer_type::TransferType,
 };
 
+/// The SSP ISO Companion bit in `bmAttributes` of the SuperSpeed endpoint companion
+const SUPER_SPEED_PLUS_ISOCHRONOUS_COMPANION: u8 = 0x80;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EndpointBuilder {
-    /// Turns into `bEndpointAddress`
-    pub endpoint_address: EndpointAddress,
+    /// Turns into `bEndpointAddress`, either fixed or taken from the device's endpoint pool
+    pub endpoint_address: AddressAssignment,
     /// Turns into `bmAttributes`
     pub attributes: EndpointAttributes,
-    /// Turns into `wMaxPacketSize`
+    /// Turns into `wMaxPacketSize` at full speed
     pub max_packet_size: u16,
-    /// Turns into `bInterval`
-    pub interval: u8,
+    /// Turns into `bInterval` at full speed
+    pub interval: Interval,
+    /// Turns into `wMaxPacketSize` at high speed, `None` to use the full-speed value
+    pub high_speed_max_packet_size: Option<u16>,
+    /// Turns into `bInterval` at high speed, `None` to use the full-speed value
+    pub high_speed_interval: Option<Interval>,
+    /// Turns into bits 12..11 of `wMaxPacketSize` at high speed, 1 to 3 transactions per
+    /// microframe of a high-bandwidth periodic endpoint. `None` for one transaction.
+    pub high_speed_transactions: Option<u8>,
+    /// Turns into `wMaxPacketSize` at SuperSpeed and faster, `None` to use the high-speed value
+    pub super_speed_max_packet_size: Option<u16>,
+    /// Turns into `bInterval` at SuperSpeed and faster, `None` to use the high-speed value
+    pub super_speed_interval: Option<Interval>,
+    /// Turns into `bMaxBurst` of the SuperSpeed endpoint companion, 0 to 15
+    pub max_burst: Option<u8>,
+    /// Turns into MaxStreams of the SuperSpeed endpoint companion, 2^n streams of a bulk
+    /// endpoint, 0 to 16
+    pub max_streams: Option<u8>,
+    /// Turns into Mult of the SuperSpeed endpoint companion, the bursts per interval of an
+    /// isochronous endpoint minus one, 0 to 2
+    pub mult: Option<u8>,
+    /// Turns into `wBytesPerInterval` of the SuperSpeed endpoint companion of a periodic
+    /// endpoint, `None` for as many bytes as the packet size, burst and mult allow
+    pub bytes_per_interval: Option<u16>,
+    /// Turns into `dwBytesPerInterval` of the SuperSpeedPlus isochronous endpoint companion,
+    /// only for isochronous endpoints at SuperSpeedPlus
+    pub super_speed_plus_bytes_per_interval: Option<u32>,
 }
 
 impl EndpointBuilder {
-    pub fn build(&self) -> Result<EndpointDescriptor, &str> {
-        if self.max_packet_size > 255 {
-            return Err("max_packet_size must be less than or equal to 255.");
-        }
+    /// Builds the endpoint descriptor as it looks at `speed` with the address the endpoint
+    /// was assigned
+    pub fn build(
+        &self,
+        endpoint_address: EndpointAddress,
+        speed: Speed,
+    ) -> Result<EndpointDescriptor, Error> {
+        let (max_packet_size, interval) = self.values_at(speed);
+        let transactions = match speed {
+            Speed::High => self.high_speed_transactions.unwrap_or(1),
+            _ => 1,
+        };
+        self.validate_max_packet_size(speed, max_packet_size, transactions)?;
 
         Ok(EndpointDescriptor {
             length: ENDPOINT_DESCRIPTOR_LENGTH,
-            endpoint_address: self.endpoint_address,
+            endpoint_address,
             attributes: self.attributes.clone(),
-            max_packet_size: self.max_packet_size,
-            interval: self.interval,
+            max_packet_size: max_packet_size | ((transactions - 1) as u16) << 11,
+            interval: interval.encode(speed, self.attributes.transfer_type)?,
         })
     }
+
+    /// Checks the packet size against the sizes the transfer type allows at `speed`
+    fn validate_max_packet_size(
+        &self,
+        speed: Speed,
+        max_packet_size: u16,
+        transactions: u8,
+    ) -> Result<(), Error> {
+        let transfer_type = self.attributes.transfer_type;
+        let periodic = matches!(
+            transfer_type,
+            TransferType::Isochronous | TransferType::Interrupt
+        );
+        if !(1..=3).contains(&transactions) || (transactions > 1 && !periodic) {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "wMaxPacketSize",
+                value: transactions as u32,
+            });
+        }
+
+        // bursts of periodic endpoints need full packets
+        let burst = self.max_burst.unwrap_or(0) > 0;
+        let valid = match (speed, transfer_type) {
+            (Speed::Low, TransferType::Control) => max_packet_size == 8,
+            (Speed::Low, TransferType::Interrupt) => max_packet_size <= 8,
+            (Speed::Low, _) => false,
+            (Speed::Full, TransferType::Control | TransferType::Bulk) => {
+                matches!(max_packet_size, 8 | 16 | 32 | 64)
+            }
+            (Speed::Full, TransferType::Interrupt) => max_packet_size <= 64,
+            (Speed::Full, TransferType::Isochronous) => max_packet_size <= 1023,
+            (Speed::High, TransferType::Control) => max_packet_size == 64,
+            (Speed::High, TransferType::Bulk) => max_packet_size == 512,
+            (Speed::High, _) => match transactions {
+                1 => max_packet_size <= 1024,
+                2 => (513..=1024).contains(&max_packet_size),
+                _ => (683..=1024).contains(&max_packet_size),
+            },
+            (_, TransferType::Control) => max_packet_size == 512,
+            (_, TransferType::Bulk) => max_packet_size == 1024,
+            (_, TransferType::Interrupt) => {
+                (1..=1024).contains(&max_packet_size) && (!burst || max_packet_size == 1024)
+            }
+            (_, TransferType::Isochronous) => {
+                max_packet_size <= 1024 && (!burst || max_packet_size == 1024)
+            }
+        };
+        if !valid {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "wMaxPacketSize",
+                value: max_packet_size as u32,
+            });
+        }
+        Ok(())
+    }
+
+    /// The SuperSpeed endpoint companion following the endpoint descriptor, `None` below
+    /// SuperSpeed
+    pub fn build_companion(
+        &self,
+        speed: Speed,
+    ) -> Result<Option<SuperSpeedEndpointCompanionDescriptor>, Error> {
+        if !speed.is_super_speed() {
+            return Ok(None);
+        }
+        let transfer_type = self.attributes.transfer_type;
+        let out_of_range = |field: &'static str, value: u32| Error::FieldOutOfRange {
+            descriptor: SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+            field,
+            value,
+        };
+
+        let max_burst = self.max_burst.unwrap_or(0);
+        // interrupt endpoints burst at most 3 packets
+        let max_burst_limit = match transfer_type {
+            TransferType::Control => 0,
+            TransferType::Interrupt => 2,
+            _ => 15,
+        };
+        if max_burst > max_burst_limit {
+            return Err(out_of_range("bMaxBurst", max_burst as u32));
+        }
+
+        let attributes = match (self.max_streams, self.mult) {
+            (Some(streams), _) if transfer_type != TransferType::Bulk || streams > 16 => {
+                return Err(out_of_range("bmAttributes", streams as u32));
+            }
+            // Mult has to be 0 without bursts
+            (_, Some(mult))
+                if transfer_type != TransferType::Isochronous
+                    || mult > 2
+                    || (mult > 0 && max_burst == 0) =>
+            {
+                return Err(out_of_range("bmAttributes", mult as u32));
+            }
+            (streams, mult) => streams.or(mult).unwrap_or(0),
+        };
+
+        let periodic = matches!(
+            transfer_type,
+            TransferType::Isochronous | TransferType::Interrupt
+        );
+        let (max_packet_size, _) = self.values_at(speed);
+        let maximum =
+            max_packet_size as u32 * (max_burst as u32 + 1) * (self.mult.unwrap_or(0) as u32 + 1);
+        let bytes_per_interval = match self.bytes_per_interval {
+            None if periodic => maximum.min(u16::MAX as u32) as u16,
+            None => 0,
+            Some(bytes) if periodic && bytes as u32 <= maximum => bytes,
+            Some(bytes) => return Err(out_of_range("wBytesPerInterval", bytes as u32)),
+        };
+
+        // the SuperSpeedPlus companion replaces Mult and wBytesPerInterval
+        if self.build_super_speed_plus_companion(speed)?.is_some() {
+            return Ok(Some(SuperSpeedEndpointCompanionDescriptor {
+                max_burst,
+                attributes: SUPER_SPEED_PLUS_ISOCHRONOUS_COMPANION,
+                bytes_per_interval: 1,
+            }));
+        }
+
+        Ok(Some(SuperSpeedEndpointCompanionDescriptor {
+            max_burst,
+            attributes,
+            bytes_per_interval,
+        }))
+    }
+
+    /// The SuperSpeedPlus isochronous endpoint companion following the SuperSpeed endpoint
+    /// companion, `None` below SuperSpeedPlus or without `super_speed_plus_bytes_per_interval`
+    pub fn build_super_speed_plus_companion(
+        &self,
+        speed: Speed,
+    ) -> Result<Option<SuperSpeedPlusIsochronousEndpointCompanionDescriptor>, Error> {
+        let bytes_per_interval = match self.super_speed_plus_bytes_per_interval {
+            Some(bytes_per_interval) => bytes_per_interval,
+            None => return Ok(None),
+        };
+        if self.attributes.transfer_type != TransferType::Isochronous {
+            return Err(Error::FieldOutOfRange {
+                descriptor: SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+                field: "dwBytesPerInterval",
+                value: bytes_per_interval,
+            });
+        }
+        if speed != Speed::SuperPlus {
+            return Ok(None);
+        }
+
+        Ok(Some(SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+            bytes_per_interval,
+        }))
+    }
+
+    /// `wMaxPacketSize` and `bInterval` at `speed`
+    fn values_at(&self, speed: Speed) -> (u16, Interval) {
+        let high_speed_max_packet_size = self
+            .high_speed_max_packet_size
+            .unwrap_or(self.max_packet_size);
+        let high_speed_interval = self.high_speed_interval.unwrap_or(self.interval);
+        match speed {
+            Speed::Low | Speed::Full => (self.max_packet_size, self.interval),
+            Speed::High => (high_speed_max_packet_size, high_speed_interval),
+            Speed::Super | Speed::SuperPlus => (
+                self.super_speed_max_packet_size
+                    .unwrap_or(high_speed_max_packet_size),
+                self.super_speed_interval.unwrap_or(high_speed_interval),
+            ),
+        }
+    }
+
+    /// Takes over the burst and stream configuration of a decoded companion descriptor
+    pub fn apply_companion(&mut self, descriptor: &SuperSpeedEndpointCompanionDescriptor) {
+        self.max_burst = Some(descriptor.max_burst);
+        // taken from the SuperSpeedPlus companion that follows
+        if descriptor.attributes & SUPER_SPEED_PLUS_ISOCHRONOUS_COMPANION != 0 {
+            return;
+        }
+        match self.attributes.transfer_type {
+            TransferType::Bulk => self.max_streams = Some(descriptor.attributes & 0x1F),
+            TransferType::Isochronous => self.mult = Some(descriptor.attributes & 0x03),
+            _ => {}
+        }
+        if matches!(
+            self.attributes.transfer_type,
+            TransferType::Isochronous | TransferType::Interrupt
+        ) {
+            self.bytes_per_interval = Some(descriptor.bytes_per_interval);
+        }
+    }
+
+    /// Rebuilds an endpoint from its descriptor as read at `speed`. The values go to the
+    /// fields of `speed`, the slower speeds the descriptor does not describe get the closest
+    /// values their limits allow, so the endpoint builds at every speed up to `speed`.
+    pub fn from_descriptor(descriptor: &EndpointDescriptor, speed: Speed) -> EndpointBuilder {
+        let transfer_type = descriptor.attributes.transfer_type;
+        let max_packet_size = descriptor.max_packet_size & 0x07FF;
+        let interval = Interval::Raw(descriptor.interval);
+        let mut builder = EndpointBuilder {
+            endpoint_address: AddressAssignment::Fixed(descriptor.endpoint_address),
+            attributes: descriptor.attributes.clone(),
+            max_packet_size,
+            interval,
+            high_speed_max_packet_size: None,
+            high_speed_interval: None,
+            high_speed_transactions: None,
+            super_speed_max_packet_size: None,
+            super_speed_interval: None,
+            max_burst: None,
+            max_streams: None,
+            mult: None,
+            bytes_per_interval: None,
+            super_speed_plus_bytes_per_interval: None,
+        };
+        match speed {
+            Speed::Low | Speed::Full => return builder,
+            Speed::High => {
+                let transactions = (descriptor.max_packet_size >> 11 & 0x03) as u8 + 1;
+                builder.high_speed_max_packet_size = Some(max_packet_size);
+                builder.high_speed_transactions = (transactions > 1).then_some(transactions);
+            }
+            Speed::Super | Speed::SuperPlus => {
+                // periodic endpoints encode bInterval alike at high speed and SuperSpeed
+                builder.high_speed_max_packet_size = Some(match transfer_type {
+                    TransferType::Control => 64,
+                    TransferType::Bulk => 512,
+                    _ => max_packet_size,
+                });
+                builder.super_speed_max_packet_size = Some(max_packet_size);
+                builder.super_speed_interval = Some(interval);
+            }
+        }
+        builder.high_speed_interval = Some(interval);
+
+        builder.max_packet_size = match transfer_type {
+            TransferType::Control | TransferType::Bulk => 64,
+            TransferType::Interrupt => max_packet_size.min(64),
+            TransferType::Isochronous => max_packet_size.min(1023),
+        };
+        // a period of 2^(bInterval-1) microframes in whole frames
+        let micros = 125u32 << descriptor.interval.clamp(1, 16).saturating_sub(1);
+        builder.interval = match transfer_type {
+            TransferType::Control | TransferType::Bulk => Interval::Raw(0),
+            TransferType::Interrupt => Interval::millis(micros.div_ceil(1000).min(255)),
+            TransferType::Isochronous => Interval::millis((micros / 1000).max(1)),
+        };
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        descriptor_type::DescriptorType,
+        endpoint::{direction::Direction, sync_type::SyncType, usage_type::UsageType},
+    };
+
+    use super::*;
+
+    const ADDRESS: EndpointAddress = EndpointAddress {
+        endpoint_number: 0x01,
+        direction: Direction::In,
+    };
+
+    fn builder(transfer_type: TransferType) -> EndpointBuilder {
+        EndpointBuilder {
+            endpoint_address: AddressAssignment::fixed(0x01, Direction::In),
+            attributes: EndpointAttributes {
+                transfer_type,
+                sync_type: SyncType::NoSync,
+                usage_type: UsageType::Data,
+            },
+            max_packet_size: 0x40,
+            interval: Interval::Raw(0x01),
+            high_speed_max_packet_size: None,
+            high_speed_interval: None,
+            high_speed_transactions: None,
+            super_speed_max_packet_size: None,
+            super_speed_interval: None,
+            max_burst: None,
+            max_streams: None,
+            mult: None,
+            bytes_per_interval: None,
+            super_speed_plus_bytes_per_interval: None,
+        }
+    }
+
+    #[test]
+    fn test_build_companion() {
+        let mut bulk = builder(TransferType::Bulk);
+        assert_eq!(bulk.build_companion(Speed::High), Ok(None));
+        bulk.max_burst = Some(15);
+        bulk.max_streams = Some(4);
+        assert_eq!(
+            bulk.build_companion(Speed::Super),
+            Ok(Some(SuperSpeedEndpointCompanionDescriptor {
+                max_burst: 15,
+                attributes: 4,
+                bytes_per_interval: 0
+            }))
+        );
+
+        bulk.max_burst = Some(16);
+        let error = |field: &'static str, value: u32| {
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::SuperSpeedEndpointCompanion,
+                field,
+                value,
+            })
+        };
+        assert_eq!(bulk.build_companion(Speed::Super), error("bMaxBurst", 16));
+
+        let mut interrupt = builder(TransferType::Interrupt);
+        interrupt.max_streams = Some(1);
+        assert_eq!(
+            interrupt.build_companion(Speed::Super),
+            error("bmAttributes", 1)
+        );
+        interrupt.max_streams = None;
+        interrupt.max_burst = Some(2);
+        assert_eq!(
+            interrupt.build_companion(Speed::Super),
+            Ok(Some(SuperSpeedEndpointCompanionDescriptor {
+                max_burst: 2,
+                attributes: 0,
+                bytes_per_interval: 0x40 * 3
+            }))
+        );
+        interrupt.max_burst = Some(3);
+        assert_eq!(
+            interrupt.build_companion(Speed::Super),
+            error("bMaxBurst", 3)
+        );
+
+        let mut isochronous = builder(TransferType::Isochronous);
+        isochronous.mult = Some(2);
+        assert_eq!(
+            isochronous.build_companion(Speed::Super),
+            error("bmAttributes", 2)
+        );
+        isochronous.max_burst = Some(3);
+        assert_eq!(
+            isochronous.build_companion(Speed::Super),
+            Ok(Some(SuperSpeedEndpointCompanionDescriptor {
+                max_burst: 3,
+                attributes: 2,
+                bytes_per_interval: 0x40 * 4 * 3
+            }))
+        );
+        isochronous.bytes_per_interval = Some(0x40 * 4 * 3 + 1);
+        assert_eq!(
+            isochronous.build_companion(Speed::Super),
+            error("wBytesPerInterval", 0x40 * 4 * 3 + 1)
+        );
+    }
+
+    #[test]
+    fn test_build_super_speed_plus_companion() {
+        let mut isochronous = builder(TransferType::Isochronous);
+        isochronous.max_burst = Some(15);
+        isochronous.mult = Some(2);
+        isochronous.super_speed_plus_bytes_per_interval = Some(0x0001_8000);
+        assert_eq!(
+            isochronous.build_super_speed_plus_companion(Speed::SuperPlus),
+            Ok(Some(SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+                bytes_per_interval: 0x0001_8000
+            }))
+        );
+        assert_eq!(
+            isochronous.build_companion(Speed::SuperPlus),
+            Ok(Some(SuperSpeedEndpointCompanionDescriptor {
+                max_burst: 15,
+                attributes: 0x80,
+                bytes_per_interval: 1
+            }))
+        );
+
+        // at SuperSpeed the plain companion is used
+        assert_eq!(
+            isochronous.build_super_speed_plus_companion(Speed::Super),
+            Ok(None)
+        );
+        assert_eq!(
+            isochronous
+                .build_companion(Speed::Super)
+                .unwrap()
+                .unwrap()
+                .attributes,
+            2
+        );
+
+        let mut companion = builder(TransferType::Isochronous);
+        companion.apply_companion(&SuperSpeedEndpointCompanionDescriptor {
+            max_burst: 15,
+            attributes: 0x80,
+            bytes_per_interval: 1,
+        });
+        assert_eq!(companion.mult, None);
+        assert_eq!(companion.bytes_per_interval, None);
+
+        let mut bulk = builder(TransferType::Bulk);
+        bulk.super_speed_plus_bytes_per_interval = Some(0x0001_8000);
+        assert_eq!(
+            bulk.build_super_speed_plus_companion(Speed::SuperPlus),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion,
+                field: "dwBytesPerInterval",
+                value: 0x0001_8000
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_descriptor() {
+        let descriptor =
+            |transfer_type: TransferType, max_packet_size: u16, interval: u8| EndpointDescriptor {
+                length: ENDPOINT_DESCRIPTOR_LENGTH,
+                endpoint_address: ADDRESS,
+                attributes: builder(transfer_type).attributes,
+                max_packet_size,
+                interval,
+            };
+
+        let bulk =
+            EndpointBuilder::from_descriptor(&descriptor(TransferType::Bulk, 512, 0), Speed::High);
+        assert_eq!(bulk.max_packet_size, 64);
+        assert_eq!(bulk.high_speed_max_packet_size, Some(512));
+        assert_eq!(
+            bulk.build(ADDRESS, Speed::High),
+            Ok(descriptor(TransferType::Bulk, 512, 0))
+        );
+        assert!(bulk.build(ADDRESS, Speed::Full).is_ok());
+
+        // every 2 ms at SuperSpeed
+        let interrupt = descriptor(TransferType::Interrupt, 1024, 5);
+        let decoded = EndpointBuilder::from_descriptor(&interrupt, Speed::Super);
+        assert_eq!(decoded.super_speed_max_packet_size, Some(1024));
+        assert_eq!(decoded.super_speed_interval, Some(Interval::Raw(5)));
+        assert_eq!(decoded.build(ADDRESS, Speed::Super), Ok(interrupt));
+        assert_eq!(
+            decoded.build(ADDRESS, Speed::High),
+            Ok(descriptor(TransferType::Interrupt, 1024, 5))
+        );
+        assert_eq!(
+            decoded.build(ADDRESS, Speed::Full),
+            Ok(descriptor(TransferType::Interrupt, 64, 2))
+        );
+    }
+
+    #[test]
+    fn test_max_packet_size() {
+        let size = |speed: Speed, builder: &EndpointBuilder| {
+            builder
+                .build(ADDRESS, speed)
+                .map(|descriptor| descriptor.max_packet_size)
+        };
+        let error = |value: u32| {
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "wMaxPacketSize",
+                value,
+            })
+        };
+
+        let mut bulk = builder(TransferType::Bulk);
+        assert_eq!(size(Speed::Full, &bulk), Ok(64));
+        assert_eq!(size(Speed::High, &bulk), error(64));
+        assert_eq!(size(Speed::Low, &bulk), error(64));
+        bulk.high_speed_max_packet_size = Some(512);
+        assert_eq!(size(Speed::High, &bulk), Ok(512));
+        assert_eq!(size(Speed::Super, &bulk), error(512));
+        bulk.high_speed_transactions = Some(2);
+        assert_eq!(size(Speed::High, &bulk), error(2));
+
+        // 3 transactions of 1024 bytes per microframe
+        let mut isochronous = builder(TransferType::Isochronous);
+        isochronous.high_speed_max_packet_size = Some(1024);
+        isochronous.high_speed_transactions = Some(3);
+        assert_eq!(size(Speed::High, &isochronous), Ok(0x1400));
+        assert_eq!(size(Speed::Full, &isochronous), Ok(64));
+        let decoded = EndpointBuilder::from_descriptor(
+            &isochronous.build(ADDRESS, Speed::High).unwrap(),
+            Speed::High,
+        );
+        assert_eq!(decoded.high_speed_max_packet_size, Some(1024));
+        assert_eq!(decoded.high_speed_transactions, Some(3));
+        assert_eq!(size(Speed::High, &decoded), Ok(0x1400));
+        assert_eq!(size(Speed::Full, &decoded), Ok(1023));
+
+        isochronous.high_speed_max_packet_size = Some(600);
+        assert_eq!(size(Speed::High, &isochronous), error(600));
+        isochronous.max_packet_size = 1024;
+        assert_eq!(size(Speed::Full, &isochronous), error(1024));
+
+        let mut interrupt = builder(TransferType::Interrupt);
+        interrupt.max_burst = Some(1);
+        interrupt.super_speed_max_packet_size = Some(512);
+        assert_eq!(size(Speed::Super, &interrupt), error(512));
+        interrupt.super_speed_max_packet_size = Some(1024);
+        assert_eq!(size(Speed::Super, &interrupt), Ok(1024));
+    }
 }
@@ -1,8 +1,10 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use crate::{
-    binary::EncodeByte,
-    descriptor::Descriptor, descriptor_type::DescriptorType,
+    binary::{expect_descriptor, DecodeByte, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
 };
 
 use super::{endpoint_address::EndpointAddress, endpoint_attributes::EndpointAttributes};
@@ -25,17 +27,19 @@ pub struct EndpointDescriptor {
 }
 
 impl Descriptor for EndpointDescriptor {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
-        let mut bytes = Vec::<u8>::new();
-        bytes.push(self.length);
-        bytes.push(ENDPOINT_DESCRIPTOR_TYPE.encode()?);
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![self.length, ENDPOINT_DESCRIPTOR_TYPE.encode()?];
         bytes.push(self.endpoint_address.encode()?);
         bytes.push(self.attributes.encode()?);
         bytes.extend_from_slice(&self.max_packet_size.to_le_bytes());
         bytes.push(self.interval);
 
         if bytes.len() != self.length as usize {
-            return Err("endpoint bLength does not match the actual length");
+            return Err(Error::LengthMismatch {
+                descriptor: ENDPOINT_DESCRIPTOR_TYPE,
+                expected: self.length as usize,
+                actual: bytes.len(),
+            });
         }
 
         Ok(bytes)
@@ -46,36 +50,91 @@ impl Descriptor for EndpointDescriptor {
     }
 }
 
+impl DecodeDescriptor for EndpointDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            ENDPOINT_DESCRIPTOR_TYPE,
+            Some(ENDPOINT_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(EndpointDescriptor {
+            length: bytes[0],
+            endpoint_address: EndpointAddress::decode(bytes[2])?,
+            attributes: EndpointAttributes::decode(bytes[3])?,
+            max_packet_size: u16::from_le_bytes([bytes[4], bytes[5]]),
+            interval: bytes[6],
+        })
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
-    use crate::endpoint::{
-        direction::Direction, endpoint_builder::EndpointBuilder, sync_type::SyncType,
-        transfer_type::TransferType, usage_type::UsageType,
+    use crate::{
+        endpoint::{
+            address_assignment::AddressAssignment, direction::Direction,
+            endpoint_builder::EndpointBuilder, interval::Interval, sync_type::SyncType,
+            transfer_type::TransferType, usage_type::UsageType,
+        },
+        speed::Speed,
     };
 
     use super::*;
 
     #[test]
     fn test_encode() {
+        let endpoint_address = EndpointAddress {
+            endpoint_number: 1,
+            direction: Direction::In,
+        };
         let endpoint_descriptor = EndpointBuilder {
-            endpoint_address: EndpointAddress {
-                endpoint_number: 1,
-                direction: Direction::In,
-            },
+            endpoint_address: AddressAssignment::Fixed(endpoint_address),
             attributes: EndpointAttributes {
                 transfer_type: TransferType::Interrupt,
                 sync_type: SyncType::NoSync,
                 usage_type: UsageType::Data,
             },
             max_packet_size: 16,
-            interval: 10,
+            interval: Interval::millis(10),
+            high_speed_max_packet_size: None,
+            high_speed_interval: None,
+            high_speed_transactions: None,
+            super_speed_max_packet_size: None,
+            super_speed_interval: None,
+            max_burst: None,
+            max_streams: None,
+            mult: None,
+            bytes_per_interval: None,
+            super_speed_plus_bytes_per_interval: None,
         }
-        .build()
+        .build(endpoint_address, Speed::Full)
         .unwrap();
         let endpoint_descriptor_encoded = vec![7, 5, 129, 3, 16, 0, 10];
         assert_eq!(
             endpoint_descriptor.encode().unwrap(),
             endpoint_descriptor_encoded
         );
+        assert_eq!(
+            EndpointDescriptor::decode(&endpoint_descriptor_encoded),
+            Ok(endpoint_descriptor)
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            EndpointDescriptor::decode(&[7, 5, 129, 3, 16, 0]),
+            Err(DecodeError::Truncated {
+                expected: 7,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            EndpointDescriptor::decode(&[7, 4, 129, 3, 16, 0, 10]),
+            Err(DecodeError::UnexpectedDescriptorType {
+                expected: DescriptorType::Endpoint,
+                actual: DescriptorType::Interface
+            })
+        );
     }
 }
@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use crate::{binary::EncodeByte, descriptor_type::DescriptorType, error::Error};
+
+use super::{direction::Direction, endpoint_address::EndpointAddress};
+
+/// The endpoints the device controller provides besides the default control endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointPool {
+    /// The endpoint numbers the hardware provides, automatic addresses are taken in this order
+    pub endpoint_numbers: Vec<u8>,
+    /// `true` if every endpoint number has an IN and an OUT endpoint.
+    /// `false` if IN and OUT share a number, so each number serves only one direction.
+    pub bidirectional: bool,
+}
+
+impl Default for EndpointPool {
+    /// Endpoints 1 to 15 in both directions
+    fn default() -> Self {
+        EndpointPool {
+            endpoint_numbers: (1..=15).collect(),
+            bidirectional: true,
+        }
+    }
+}
+
+impl EndpointPool {
+    /// Checks that the hardware provides `address`. Returns the encoded `bEndpointAddress`.
+    pub fn validate(&self, address: EndpointAddress) -> Result<u8, Error> {
+        let encoded = address.encode()?;
+        if address.endpoint_number == 0 || !self.endpoint_numbers.contains(&address.endpoint_number)
+        {
+            return Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bEndpointAddress",
+                value: encoded as u32,
+            });
+        }
+        Ok(encoded)
+    }
+
+    /// Whether two encoded endpoint addresses use the same hardware endpoint
+    pub fn conflicts(&self, address: u8, other: u8) -> bool {
+        match self.bidirectional {
+            true => address == other,
+            false => address & 0x0F == other & 0x0F,
+        }
+    }
+
+    /// The first endpoint in `direction` that is not `taken`, `None` if the pool is exhausted
+    pub fn allocate(
+        &self,
+        direction: Direction,
+        taken: impl Fn(u8) -> bool,
+    ) -> Result<Option<EndpointAddress>, Error> {
+        for endpoint_number in self.endpoint_numbers.iter().copied() {
+            let address = EndpointAddress {
+                endpoint_number,
+                direction,
+            };
+            if !taken(self.validate(address)?) {
+                return Ok(Some(address));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate() {
+        let pool = EndpointPool {
+            endpoint_numbers: vec![1, 2, 3, 4, 5],
+            bidirectional: false,
+        };
+        let taken = [0x81, 0x02];
+        let taken = |address: u8| taken.iter().any(|other| pool.conflicts(address, *other));
+        assert_eq!(
+            pool.allocate(Direction::Out, taken),
+            Ok(Some(EndpointAddress {
+                endpoint_number: 3,
+                direction: Direction::Out,
+            }))
+        );
+        let exhausted = [0x81, 0x02, 0x03, 0x84, 0x05];
+        assert_eq!(
+            pool.allocate(Direction::In, |address| exhausted
+                .iter()
+                .any(|other| pool.conflicts(address, *other))),
+            Ok(None)
+        );
+
+        let pool = EndpointPool::default();
+        assert_eq!(
+            pool.allocate(Direction::Out, |address| address == 0x01),
+            Ok(Some(EndpointAddress {
+                endpoint_number: 2,
+                direction: Direction::Out,
+            }))
+        );
+        assert!(!pool.conflicts(0x81, 0x01));
+
+        fn error<T>(value: u32) -> Result<T, Error> {
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bEndpointAddress",
+                value,
+            })
+        }
+        let zero = EndpointAddress {
+            endpoint_number: 0,
+            direction: Direction::In,
+        };
+        assert_eq!(pool.validate(zero), error(0x80));
+        let pool = EndpointPool {
+            endpoint_numbers: vec![0],
+            bidirectional: true,
+        };
+        assert_eq!(pool.allocate(Direction::Out, |_| false), error(0x00));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{descriptor_type::DescriptorType, error::Error, speed::Speed};
+
+use super::transfer_type::TransferType;
+
+/// Turns into `bInterval`. A period is encoded for the speed and transfer type of the endpoint:
+/// frames for full-speed interrupt endpoints, 2^(n-1) frames or microframes for periodic
+/// endpoints otherwise, and the NAK rate in microframes for high-speed bulk and control endpoints.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Interval {
+    /// A period in µs
+    Micros(u32),
+    /// `bInterval` as is, e.g. from a decoded descriptor
+    Raw(u8),
+}
+
+impl Interval {
+    pub const fn millis(millis: u32) -> Interval {
+        Interval::Micros(millis.saturating_mul(1000))
+    }
+
+    pub const fn micros(micros: u32) -> Interval {
+        Interval::Micros(micros)
+    }
+
+    /// Errors if the period can not be represented exactly at `speed`
+    pub fn encode(&self, speed: Speed, transfer_type: TransferType) -> Result<u8, Error> {
+        let micros = match *self {
+            Interval::Micros(micros) => micros,
+            Interval::Raw(interval) => return Ok(interval),
+        };
+
+        let interval = match (speed, transfer_type) {
+            (Speed::High, TransferType::Control | TransferType::Bulk) => {
+                units(micros, 125).filter(|microframes| *microframes <= 255)
+            }
+            // the other speeds have no interval for these
+            (_, TransferType::Control | TransferType::Bulk) => (micros == 0).then_some(0),
+            (Speed::Low, TransferType::Interrupt) => {
+                units(micros, 1000).filter(|frames| (10..=255).contains(frames))
+            }
+            (Speed::Full, TransferType::Interrupt) => {
+                units(micros, 1000).filter(|frames| (1..=255).contains(frames))
+            }
+            (Speed::Low, TransferType::Isochronous) => None,
+            (Speed::Full, TransferType::Isochronous) => exponent(micros, 1000),
+            (_, TransferType::Interrupt | TransferType::Isochronous) => exponent(micros, 125),
+        };
+        match interval {
+            Some(interval) => Ok(interval as u8),
+            None => Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bInterval",
+                value: micros,
+            }),
+        }
+    }
+}
+
+/// The number of whole `unit`s in `micros`
+fn units(micros: u32, unit: u32) -> Option<u32> {
+    micros.is_multiple_of(unit).then_some(micros / unit)
+}
+
+/// `n` of a period of 2^(n-1) `unit`s, 1 to 16
+fn exponent(micros: u32, unit: u32) -> Option<u32> {
+    let units = units(micros, unit)?;
+    if !units.is_power_of_two() || units > 1 << 15 {
+        return None;
+    }
+    Some(units.trailing_zeros() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let interrupt =
+            |interval: Interval, speed: Speed| interval.encode(speed, TransferType::Interrupt);
+        assert_eq!(interrupt(Interval::millis(10), Speed::Full), Ok(10));
+        assert_eq!(interrupt(Interval::millis(8), Speed::High), Ok(7));
+        assert_eq!(interrupt(Interval::micros(125), Speed::High), Ok(1));
+        assert_eq!(interrupt(Interval::micros(125), Speed::Super), Ok(1));
+        assert_eq!(interrupt(Interval::millis(10), Speed::Low), Ok(10));
+        assert_eq!(interrupt(Interval::Raw(3), Speed::High), Ok(3));
+        assert_eq!(
+            interrupt(Interval::millis(10), Speed::High),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "bInterval",
+                value: 10_000
+            })
+        );
+        assert!(interrupt(Interval::millis(1), Speed::Low).is_err());
+        assert!(interrupt(Interval::micros(125), Speed::Full).is_err());
+
+        let isochronous =
+            |interval: Interval, speed: Speed| interval.encode(speed, TransferType::Isochronous);
+        assert_eq!(isochronous(Interval::millis(1), Speed::Full), Ok(1));
+        assert_eq!(isochronous(Interval::millis(4), Speed::Full), Ok(3));
+        assert_eq!(isochronous(Interval::millis(4096), Speed::High), Ok(16));
+        assert!(isochronous(Interval::millis(8192), Speed::High).is_err());
+        assert!(isochronous(Interval::millis(3), Speed::Full).is_err());
+
+        // NAK rate of high-speed bulk endpoints
+        let bulk = |interval: Interval, speed: Speed| interval.encode(speed, TransferType::Bulk);
+        assert_eq!(bulk(Interval::micros(500), Speed::High), Ok(4));
+        assert_eq!(bulk(Interval::micros(0), Speed::Full), Ok(0));
+        assert!(bulk(Interval::micros(500), Speed::Full).is_err());
+    }
+}
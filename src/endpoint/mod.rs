@@ -1,8 +1,13 @@
+pub mod address_assignment;
 pub mod direction;
-pub mod endpoint_builder;
 pub mod endpoint_address;
 pub mod endpoint_attributes;
+pub mod endpoint_builder;
 pub mod endpoint_descriptor;
+pub mod endpoint_pool;
+pub mod interval;
+pub mod super_speed_endpoint_companion_descriptor;
+pub mod super_speed_plus_isochronous_endpoint_companion_descriptor;
 pub mod sync_type;
 pub mod transfer_type;
 pub mod usage_type;
@@ -0,0 +1,85 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    binary::{expect_descriptor, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+pub const SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH: u8 = 6;
+pub const SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE: DescriptorType =
+    DescriptorType::SuperSpeedEndpointCompanion;
+
+/// Follows every endpoint descriptor at SuperSpeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuperSpeedEndpointCompanionDescriptor {
+    /// Turns into `bMaxBurst`
+    pub max_burst: u8,
+    /// Turns into `bmAttributes`, MaxStreams of bulk and Mult of isochronous endpoints
+    pub attributes: u8,
+    /// Turns into `wBytesPerInterval`
+    pub bytes_per_interval: u16,
+}
+
+impl Descriptor for SuperSpeedEndpointCompanionDescriptor {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![
+            SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH,
+            SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE.encode()?,
+        ];
+        bytes.push(self.max_burst);
+        bytes.push(self.attributes);
+        bytes.extend_from_slice(&self.bytes_per_interval.to_le_bytes());
+
+        if bytes.len() != SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH as usize {
+            return Err(Error::LengthMismatch {
+                descriptor: SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+                expected: SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH as usize,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE
+    }
+}
+
+impl DecodeDescriptor for SuperSpeedEndpointCompanionDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+            Some(SUPER_SPEED_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(SuperSpeedEndpointCompanionDescriptor {
+            max_burst: bytes[2],
+            attributes: bytes[3],
+            bytes_per_interval: u16::from_le_bytes([bytes[4], bytes[5]]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let descriptor = SuperSpeedEndpointCompanionDescriptor {
+            max_burst: 0x03,
+            attributes: 0x01,
+            bytes_per_interval: 0x2000,
+        };
+        let bytes = descriptor.encode().unwrap();
+        assert_eq!(bytes, vec![0x06, 0x30, 0x03, 0x01, 0x00, 0x20]);
+        assert_eq!(
+            SuperSpeedEndpointCompanionDescriptor::decode(&bytes),
+            Ok(descriptor)
+        );
+    }
+}
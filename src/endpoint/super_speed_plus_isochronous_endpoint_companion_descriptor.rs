@@ -0,0 +1,79 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    binary::{expect_descriptor, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+pub const SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH: u8 = 8;
+pub const SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE: DescriptorType =
+    DescriptorType::SuperSpeedPlusIsochronousEndpointCompanion;
+
+/// Follows the SuperSpeed endpoint companion of isochronous endpoints that move more bytes
+/// per service interval than `wBytesPerInterval` can hold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+    /// Turns into `dwBytesPerInterval`
+    pub bytes_per_interval: u32,
+}
+
+impl Descriptor for SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![
+            SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH,
+            SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE.encode()?,
+        ];
+        bytes.extend_from_slice(&[0, 0]); // wReserved
+        bytes.extend_from_slice(&self.bytes_per_interval.to_le_bytes());
+
+        if bytes.len() != SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH as usize
+        {
+            return Err(Error::LengthMismatch {
+                descriptor: SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+                expected: SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH
+                    as usize,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE
+    }
+}
+
+impl DecodeDescriptor for SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_TYPE,
+            Some(SUPER_SPEED_PLUS_ISOCHRONOUS_ENDPOINT_COMPANION_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+            bytes_per_interval: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let descriptor = SuperSpeedPlusIsochronousEndpointCompanionDescriptor {
+            bytes_per_interval: 0x0001_8000,
+        };
+        let bytes = descriptor.encode().unwrap();
+        assert_eq!(bytes, vec![0x08, 0x31, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00]);
+        assert_eq!(
+            SuperSpeedPlusIsochronousEndpointCompanionDescriptor::decode(&bytes),
+            Ok(descriptor)
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SyncType {
@@ -9,7 +12,7 @@ pub enum SyncType {
 }
 
 impl EncodeByte for SyncType {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         match *self {
             SyncType::NoSync => Ok(0x00),
             SyncType::Asynchronous => Ok(0x01),
@@ -18,3 +21,18 @@ impl EncodeByte for SyncType {
         }
     }
 }
+
+impl DecodeByte for SyncType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(SyncType::NoSync),
+            0x01 => Ok(SyncType::Asynchronous),
+            0x02 => Ok(SyncType::Adaptive),
+            0x03 => Ok(SyncType::Synchronous),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmAttributes",
+                value: byte as u16,
+            }),
+        }
+    }
+}
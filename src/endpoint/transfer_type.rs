@@ -1,4 +1,7 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TransferType {
@@ -9,7 +12,7 @@ pub enum TransferType {
 }
 
 impl EncodeByte for TransferType {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         match *self {
             TransferType::Control => Ok(0x00),
             TransferType::Isochronous => Ok(0x01),
@@ -18,3 +21,18 @@ impl EncodeByte for TransferType {
         }
     }
 }
+
+impl DecodeByte for TransferType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(TransferType::Control),
+            0x01 => Ok(TransferType::Isochronous),
+            0x02 => Ok(TransferType::Bulk),
+            0x03 => Ok(TransferType::Interrupt),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmAttributes",
+                value: byte as u16,
+            }),
+        }
+    }
+}
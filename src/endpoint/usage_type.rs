@@ -1,4 +1,7 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum UsageType {
@@ -8,7 +11,7 @@ pub enum UsageType {
 }
 
 impl EncodeByte for UsageType {
-    fn encode(&self) -> Result<u8, &str> {
+    fn encode(&self) -> Result<u8, Error> {
         match *self {
             UsageType::Data => Ok(0x00),
             UsageType::Feedback => Ok(0x01),
@@ -16,3 +19,17 @@ impl EncodeByte for UsageType {
         }
     }
 }
+
+impl DecodeByte for UsageType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(UsageType::Data),
+            0x01 => Ok(UsageType::Feedback),
+            0x02 => Ok(UsageType::ImplicitFeedbackData),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmAttributes",
+                value: byte as u16,
+            }),
+        }
+    }
+}
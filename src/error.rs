@@ -0,0 +1,255 @@
+use core::fmt::Display;
+
+use crate::descriptor_type::DescriptorType;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A field holds a value outside of the range the specification allows
+    FieldOutOfRange {
+        descriptor: DescriptorType,
+        field: &'static str,
+        value: u32,
+    },
+    /// The class, subclass and protocol triple is not a defined class code.
+    /// See https://www.usb.org/defined-class-codes
+    InvalidClass {
+        descriptor: DescriptorType,
+        class: u8,
+        subclass: u8,
+        protocol: u8,
+    },
+    /// The encoded data does not fit into its length field
+    LengthOverflow {
+        descriptor: DescriptorType,
+        field: &'static str,
+        length: usize,
+    },
+    /// `bLength` does not match the number of encoded bytes
+    LengthMismatch {
+        descriptor: DescriptorType,
+        expected: usize,
+        actual: usize,
+    },
+    /// A descriptor is missing something it requires, e.g. a configuration without interfaces
+    Missing {
+        descriptor: DescriptorType,
+        field: &'static str,
+    },
+    /// There are more strings than an 8 bit string index can address
+    TooManyStrings { count: usize },
+    /// Two interfaces of the same configuration use the same `bEndpointAddress`, or the same
+    /// endpoint number if IN and OUT share a number in the endpoint pool
+    DuplicateEndpoint {
+        configuration_value: u8,
+        address: u8,
+    },
+    /// The endpoint pool of the device has no free endpoint left for an automatic address
+    EndpointsExhausted { configuration_value: u8 },
+    /// Alternate settings of one interface have different HID descriptors. The host requests
+    /// the HID and report descriptor per interface, so there can only be one.
+    ConflictingHid {
+        configuration_value: u8,
+        interface_number: u8,
+    },
+    /// Decoding a descriptor failed
+    Decode(DecodeError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::FieldOutOfRange {
+                descriptor,
+                field,
+                value,
+            } => write!(
+                f,
+                "{} descriptor: {} value {} is out of range",
+                descriptor, field, value
+            ),
+            Error::InvalidClass {
+                descriptor,
+                class,
+                subclass,
+                protocol,
+            } => write!(
+                f,
+                "{} descriptor: class 0x{:02x} is not compatible with subclass 0x{:02x} and protocol 0x{:02x}. Please check https://www.usb.org/defined-class-codes",
+                descriptor, class, subclass, protocol
+            ),
+            Error::LengthOverflow {
+                descriptor,
+                field,
+                length,
+            } => write!(
+                f,
+                "{} descriptor: length {} does not fit into {}",
+                descriptor, length, field
+            ),
+            Error::LengthMismatch {
+                descriptor,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} descriptor: bLength {} does not match the actual length {}",
+                descriptor, expected, actual
+            ),
+            Error::Missing { descriptor, field } => {
+                write!(f, "{} descriptor: {} is missing", descriptor, field)
+            }
+            Error::TooManyStrings { count } => write!(
+                f,
+                "{} string descriptors exceed the 8 bit string index",
+                count
+            ),
+            Error::DuplicateEndpoint {
+                configuration_value,
+                address,
+            } => write!(
+                f,
+                "Endpoint 0x{:02x} is used more than once in configuration {}",
+                address, configuration_value
+            ),
+            Error::EndpointsExhausted {
+                configuration_value,
+            } => write!(
+                f,
+                "No free endpoint left in the endpoint pool for configuration {}",
+                configuration_value
+            ),
+            Error::ConflictingHid {
+                configuration_value,
+                interface_number,
+            } => write!(
+                f,
+                "Interface {} of configuration {} has alternate settings with different HID descriptors",
+                interface_number, configuration_value
+            ),
+            Error::Decode(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        Error::Decode(error)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended before the descriptor was complete
+    Truncated { expected: usize, actual: usize },
+    /// `bLength` is not valid for the descriptor type
+    InvalidLength { descriptor_type: u8, length: u8 },
+    /// `bDescriptorType` is not a descriptor type known to this crate
+    UnknownDescriptorType(u8),
+    /// `bDescriptorType` is known but not the one that was expected
+    UnexpectedDescriptorType {
+        expected: DescriptorType,
+        actual: DescriptorType,
+    },
+    /// A field holds a value that is reserved or not supported
+    InvalidValue { field: &'static str, value: u16 },
+    /// A string index refers to a string that is not in the supplied string table
+    MissingString(u8),
+    /// A HID interface has no report descriptor in the supplied report descriptors
+    MissingReport {
+        configuration_value: u8,
+        interface_number: u8,
+    },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::Truncated { expected, actual } => write!(
+                f,
+                "Descriptor is truncated: expected {} bytes but got {}",
+                expected, actual
+            ),
+            DecodeError::InvalidLength {
+                descriptor_type,
+                length,
+            } => write!(
+                f,
+                "Invalid bLength {} for descriptor type 0x{:02x}",
+                length, descriptor_type
+            ),
+            DecodeError::UnknownDescriptorType(descriptor_type) => {
+                write!(f, "Unknown bDescriptorType 0x{:02x}", descriptor_type)
+            }
+            DecodeError::UnexpectedDescriptorType { expected, actual } => write!(
+                f,
+                "Expected a {} descriptor but got a {} descriptor",
+                expected, actual
+            ),
+            DecodeError::InvalidValue { field, value } => {
+                write!(f, "Invalid value 0x{:x} in {}", value, field)
+            }
+            DecodeError::MissingString(index) => {
+                write!(f, "String descriptor {} is missing", index)
+            }
+            DecodeError::MissingReport {
+                configuration_value,
+                interface_number,
+            } => write!(
+                f,
+                "Report descriptor of interface {} in configuration {} is missing",
+                interface_number, configuration_value
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Error::FieldOutOfRange {
+                descriptor: DescriptorType::Endpoint,
+                field: "wMaxPacketSize",
+                value: 2048,
+            }
+            .to_string(),
+            "Endpoint descriptor: wMaxPacketSize value 2048 is out of range"
+        );
+        assert_eq!(
+            Error::DuplicateEndpoint {
+                configuration_value: 1,
+                address: 0x81,
+            }
+            .to_string(),
+            "Endpoint 0x81 is used more than once in configuration 1"
+        );
+        assert_eq!(
+            Error::EndpointsExhausted {
+                configuration_value: 2
+            }
+            .to_string(),
+            "No free endpoint left in the endpoint pool for configuration 2"
+        );
+        assert_eq!(
+            Error::ConflictingHid {
+                configuration_value: 1,
+                interface_number: 0,
+            }
+            .to_string(),
+            "Interface 0 of configuration 1 has alternate settings with different HID descriptors"
+        );
+        assert_eq!(
+            Error::from(DecodeError::UnknownDescriptorType(0x42)).to_string(),
+            "Unknown bDescriptorType 0x42"
+        );
+    }
+}
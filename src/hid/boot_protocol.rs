@@ -0,0 +1,250 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    endpoint::{
+        address_assignment::AddressAssignment, direction::Direction,
+        endpoint_attributes::EndpointAttributes, endpoint_builder::EndpointBuilder,
+        interval::Interval, sync_type::SyncType, transfer_type::TransferType,
+        usage_type::UsageType,
+    },
+    interface::{interface_builder::InterfaceBuilder, interface_class::InterfaceClass},
+    report::{
+        collection_type::CollectionType,
+        data_flags::DataFlags,
+        global_item::GlobalItem,
+        item::Item,
+        local_item::LocalItem,
+        main_item::MainItem::{Collection, EndCollection, Input, Output},
+        report_builder::ReportBuilder,
+        usage_tables::{Button, GenericDesktop, Keyboard, Led},
+    },
+    string::string_builder::StringBuidler,
+};
+
+use super::{hid_builder::HidBuilder, hid_descriptor::HID1_11};
+
+/// `bInterfaceSubClass` of interfaces supporting the boot protocol
+pub const BOOT_INTERFACE_SUBCLASS: u8 = 0x01;
+
+const DATA_VARIABLE_ABSOLUTE: DataFlags = DataFlags {
+    constant: false,
+    variable: true,
+    relative: false,
+    wrap: false,
+    non_linear: false,
+    no_preferred: false,
+    null_state: false,
+    volatile: false,
+    buffered_bytes: false,
+};
+
+/// The boot devices of HID 1.11 Appendix B
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BootProtocol {
+    /// 8 byte input report with modifiers and six key codes, 1 byte LED output report
+    Keyboard,
+    /// 3 byte input report with three buttons and relative X and Y
+    Mouse,
+}
+
+impl BootProtocol {
+    /// Turns into `bInterfaceProtocol`
+    pub fn interface_protocol(&self) -> u8 {
+        match self {
+            BootProtocol::Keyboard => 0x01,
+            BootProtocol::Mouse => 0x02,
+        }
+    }
+
+    /// The report descriptor of HID 1.11 Appendix B.1 or B.2.
+    /// Items use their shortest encoding like every `ReportBuilder`, so zero values are
+    /// 0 byte items, e.g. Logical Minimum (0) is `0x14` where the appendix lists `0x15 0x00`.
+    /// Hosts parse both the same way.
+    pub fn report(&self) -> ReportBuilder {
+        let items = match self {
+            BootProtocol::Keyboard => keyboard_items(),
+            BootProtocol::Mouse => mouse_items(),
+        };
+        ReportBuilder { items }
+    }
+
+    pub fn hid(&self) -> HidBuilder {
+        HidBuilder {
+            bcd_hid: HID1_11,
+            country_code: 0x00,
+            report: self.report(),
+        }
+    }
+
+    /// A boot interface with one interrupt IN endpoint, polled every 10 ms at full speed
+    /// and every 8 ms at high speed
+    pub fn interface(&self, interface: StringBuidler, endpoint_number: u8) -> InterfaceBuilder {
+        InterfaceBuilder {
+            interface_class: InterfaceClass::HumanInterfaceDevice,
+            interface_suclass: BOOT_INTERFACE_SUBCLASS,
+            interface_protocol: self.interface_protocol(),
+            interface: Some(interface),
+            hid: Some(self.hid()),
+            endpoints: vec![EndpointBuilder {
+                endpoint_address: AddressAssignment::fixed(endpoint_number, Direction::In),
+                attributes: EndpointAttributes {
+                    transfer_type: TransferType::Interrupt,
+                    sync_type: SyncType::NoSync,
+                    usage_type: UsageType::Data,
+                },
+                max_packet_size: 8,
+                interval: Interval::millis(10),
+                high_speed_max_packet_size: None,
+                high_speed_interval: Some(Interval::millis(8)),
+                high_speed_transactions: None,
+                super_speed_max_packet_size: None,
+                super_speed_interval: None,
+                max_burst: None,
+                max_streams: None,
+                mult: None,
+                bytes_per_interval: None,
+                super_speed_plus_bytes_per_interval: None,
+            }],
+        }
+    }
+}
+
+fn keyboard_items() -> Vec<Item> {
+    vec![
+        GenericDesktop::KEYBOARD.page_item(),
+        GenericDesktop::KEYBOARD.usage_item(),
+        Item::Main(Collection(CollectionType::Application)),
+        // Modifier byte
+        Item::Global(GlobalItem::UsagePage(Keyboard::PAGE)),
+        Item::Local(LocalItem::UsageMinimum(Keyboard::LEFT_CONTROL.id as u32)),
+        Item::Local(LocalItem::UsageMaximum(Keyboard::RIGHT_GUI.id as u32)),
+        Item::Global(GlobalItem::LogicalMinimum(0)),
+        Item::Global(GlobalItem::LogicalMaximum(1)),
+        Item::Global(GlobalItem::ReportSize(1)),
+        Item::Global(GlobalItem::ReportCount(8)),
+        Item::Main(Input(DATA_VARIABLE_ABSOLUTE)),
+        // Reserved byte
+        Item::Global(GlobalItem::ReportCount(1)),
+        Item::Global(GlobalItem::ReportSize(8)),
+        Item::Main(Input(DataFlags {
+            constant: true,
+            ..Default::default()
+        })),
+        // LED report
+        Item::Global(GlobalItem::ReportCount(5)),
+        Item::Global(GlobalItem::ReportSize(1)),
+        Item::Global(GlobalItem::UsagePage(Led::PAGE)),
+        Item::Local(LocalItem::UsageMinimum(Led::NUM_LOCK.id as u32)),
+        Item::Local(LocalItem::UsageMaximum(Led::KANA.id as u32)),
+        Item::Main(Output(DATA_VARIABLE_ABSOLUTE)),
+        // LED report padding
+        Item::Global(GlobalItem::ReportCount(1)),
+        Item::Global(GlobalItem::ReportSize(3)),
+        Item::Main(Output(DataFlags {
+            constant: true,
+            ..Default::default()
+        })),
+        // Key codes
+        Item::Global(GlobalItem::ReportCount(6)),
+        Item::Global(GlobalItem::ReportSize(8)),
+        Item::Global(GlobalItem::LogicalMinimum(0)),
+        Item::Global(GlobalItem::LogicalMaximum(Keyboard::APPLICATION.id as i32)),
+        Item::Global(GlobalItem::UsagePage(Keyboard::PAGE)),
+        Item::Local(LocalItem::UsageMinimum(Keyboard::NO_EVENT.id as u32)),
+        Item::Local(LocalItem::UsageMaximum(Keyboard::APPLICATION.id as u32)),
+        Item::Main(Input(DataFlags::default())),
+        Item::Main(EndCollection),
+    ]
+}
+
+fn mouse_items() -> Vec<Item> {
+    vec![
+        GenericDesktop::MOUSE.page_item(),
+        GenericDesktop::MOUSE.usage_item(),
+        Item::Main(Collection(CollectionType::Application)),
+        GenericDesktop::POINTER.usage_item(),
+        Item::Main(Collection(CollectionType::Physical)),
+        // Buttons
+        Item::Global(GlobalItem::UsagePage(Button::PAGE)),
+        Item::Local(LocalItem::UsageMinimum(Button::button(1).id as u32)),
+        Item::Local(LocalItem::UsageMaximum(Button::button(3).id as u32)),
+        Item::Global(GlobalItem::LogicalMinimum(0)),
+        Item::Global(GlobalItem::LogicalMaximum(1)),
+        Item::Global(GlobalItem::ReportCount(3)),
+        Item::Global(GlobalItem::ReportSize(1)),
+        Item::Main(Input(DATA_VARIABLE_ABSOLUTE)),
+        // Padding
+        Item::Global(GlobalItem::ReportCount(1)),
+        Item::Global(GlobalItem::ReportSize(5)),
+        Item::Main(Input(DataFlags {
+            constant: true,
+            ..Default::default()
+        })),
+        // X and Y
+        GenericDesktop::X.page_item(),
+        GenericDesktop::X.usage_item(),
+        GenericDesktop::Y.usage_item(),
+        Item::Global(GlobalItem::LogicalMinimum(-127)),
+        Item::Global(GlobalItem::LogicalMaximum(127)),
+        Item::Global(GlobalItem::ReportSize(8)),
+        Item::Global(GlobalItem::ReportCount(2)),
+        Item::Main(Input(DataFlags {
+            relative: true,
+            ..DATA_VARIABLE_ABSOLUTE
+        })),
+        Item::Main(EndCollection),
+        Item::Main(EndCollection),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{report_kind::ReportKind, report_layout::ReportLayout};
+
+    use super::*;
+
+    #[test]
+    fn test_keyboard() {
+        let report = BootProtocol::Keyboard.report().build().unwrap();
+        assert_eq!(
+            report.items,
+            vec![
+                0x05, 0x01, 0x09, 0x06, 0xA1, 0x01, 0x05, 0x07, 0x19, 0xE0, 0x29, 0xE7, 0x14, 0x25,
+                0x01, 0x75, 0x01, 0x95, 0x08, 0x81, 0x02, 0x95, 0x01, 0x75, 0x08, 0x81, 0x01, 0x95,
+                0x05, 0x75, 0x01, 0x05, 0x08, 0x19, 0x01, 0x29, 0x05, 0x91, 0x02, 0x95, 0x01, 0x75,
+                0x03, 0x91, 0x01, 0x95, 0x06, 0x75, 0x08, 0x14, 0x25, 0x65, 0x05, 0x07, 0x18, 0x29,
+                0x65, 0x80, 0xC0,
+            ]
+        );
+
+        let layout = ReportLayout::decode(&report.items).unwrap();
+        assert_eq!(layout.report_length(ReportKind::Input, 0), 8);
+        assert_eq!(layout.report_length(ReportKind::Output, 0), 1);
+
+        let interface = BootProtocol::Keyboard.interface(StringBuidler::text("Keyboard"), 1);
+        assert_eq!(interface.interface_suclass, 0x01);
+        assert_eq!(interface.interface_protocol, 0x01);
+        assert!(interface.build(0, 0, 0, 1).is_ok());
+    }
+
+    #[test]
+    fn test_mouse() {
+        let report = BootProtocol::Mouse.report().build().unwrap();
+        assert_eq!(
+            report.items,
+            vec![
+                0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x09, 0x01, 0xA0, 0x05, 0x09, 0x19, 0x01, 0x29,
+                0x03, 0x14, 0x25, 0x01, 0x95, 0x03, 0x75, 0x01, 0x81, 0x02, 0x95, 0x01, 0x75, 0x05,
+                0x81, 0x01, 0x05, 0x01, 0x09, 0x30, 0x09, 0x31, 0x15, 0x81, 0x25, 0x7F, 0x75, 0x08,
+                0x95, 0x02, 0x81, 0x06, 0xC0, 0xC0,
+            ]
+        );
+
+        let layout = ReportLayout::decode(&report.items).unwrap();
+        assert_eq!(layout.report_length(ReportKind::Input, 0), 3);
+
+        let interface = BootProtocol::Mouse.interface(StringBuidler::text("Mouse"), 1);
+        assert_eq!(interface.interface_suclass, 0x01);
+        assert_eq!(interface.interface_protocol, 0x02);
+    }
+}
@@ -1,8 +1,9 @@
-use alloc::vec::Vec;
+use crate::{
+    descriptor_type::DescriptorType, error::DecodeError, report::report_builder::ReportBuilder,
+    version::Version,
+};
 
-use crate::{descriptor_type::DescriptorType, report::report_builder::ReportBuilder, version::Version};
-
-use super::hid_descriptor::HidDescriptor;
+use super::hid_descriptor::{HidDescriptor, HID_DESCRIPTOR_LENGTH, HID_DESCRIPTOR_TYPE};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HidBuilder {
@@ -10,37 +11,45 @@ pub struct HidBuilder {
     pub bcd_hid: Version,
     /// Turns into `bCountryCode`
     pub country_code: u8,
-    /// Turns into `bNumDescriptors`
-    pub descriptors: Vec<ReportBuilder>
+    /// Turns into the report descriptor and its `wDescriptorLength`
+    pub report: ReportBuilder,
 }
 
 impl HidBuilder {
-    pub fn build(&self, num_descriptors: u8, report_descriptor_length: u16) -> HidDescriptor {
+    pub fn build(&self, report_descriptor_length: u16) -> HidDescriptor {
         HidDescriptor {
-            length: 9,
-            descriptor_type: DescriptorType::Hid,
+            length: HID_DESCRIPTOR_LENGTH,
+            descriptor_type: HID_DESCRIPTOR_TYPE,
             bcd_hid: self.bcd_hid,
             country_code: self.country_code,
-            num_descriptors,
+            num_descriptors: 1,
             report_descriptor_type: DescriptorType::Report,
             report_descriptor_length,
         }
     }
-}
 
-// pub struct HidDescriptor {
-//     /// Turns into `bLength`
-//     pub length: u8,
-//     /// Turns into `bDescriptorType`
-//     pub descriptor_type: DescriptorType,
-//     /// Turns into `bcdHID`
-//     pub bcd_hid: Version,
-//     /// Turns into `bCountryCode`
-//     pub country_code: u8,
-//     /// Turns into `bNumDescriptors`
-//     pub num_descriptors: u8,
-//     /// Turns into `bDescriptorType` for the report descriptor
-//     pub report_descriptor_type: DescriptorType,
-//     /// Turns into `wDescriptorLength`
-//     pub report_descriptor_length: u16,
-// }
\ No newline at end of file
+    /// Rebuilds the builder from a HID descriptor and the report descriptor it announces
+    pub fn from_descriptor(
+        descriptor: &HidDescriptor,
+        report: &[u8],
+    ) -> Result<HidBuilder, DecodeError> {
+        if descriptor.report_descriptor_type != DescriptorType::Report {
+            return Err(DecodeError::UnexpectedDescriptorType {
+                expected: DescriptorType::Report,
+                actual: descriptor.report_descriptor_type,
+            });
+        }
+        if report.len() != descriptor.report_descriptor_length as usize {
+            return Err(DecodeError::InvalidValue {
+                field: "wDescriptorLength",
+                value: descriptor.report_descriptor_length,
+            });
+        }
+
+        Ok(HidBuilder {
+            bcd_hid: descriptor.bcd_hid,
+            country_code: descriptor.country_code,
+            report: ReportBuilder::decode(report)?,
+        })
+    }
+}
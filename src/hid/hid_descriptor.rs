@@ -1,5 +1,19 @@
+use alloc::{vec, vec::Vec};
+
 use crate::{
-    binary::EncodeBytes, descriptor::Descriptor, descriptor_type::DescriptorType, version::Version,
+    binary::{expect_descriptor, DecodeByte, DecodeBytes, EncodeByte, EncodeBytes},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+    version::Version,
+};
+
+pub const HID_DESCRIPTOR_LENGTH: u8 = 9;
+pub const HID_DESCRIPTOR_TYPE: DescriptorType = DescriptorType::Hid;
+
+pub const HID1_11: Version = Version {
+    major: 0x01,
+    minor: 0x11,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,19 +35,78 @@ pub struct HidDescriptor {
 }
 
 impl Descriptor for HidDescriptor {
-    fn encode(&self) -> Result<alloc::vec::Vec<u8>, &str> {
-        let mut encoded = alloc::vec::Vec::new();
-        encoded.push(self.length);
-        encoded.push(self.descriptor_type as u8);
-        encoded.extend_from_slice(&self.bcd_hid.encode()?);
-        encoded.push(self.country_code);
-        encoded.push(self.num_descriptors);
-        encoded.push(self.report_descriptor_type as u8);
-        encoded.extend_from_slice(&self.report_descriptor_length.to_le_bytes());
-        Ok(encoded)
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![self.length, self.descriptor_type.encode()?];
+        bytes.append(&mut self.bcd_hid.encode()?);
+        bytes.push(self.country_code);
+        bytes.push(self.num_descriptors);
+        bytes.push(self.report_descriptor_type.encode()?);
+        bytes.extend_from_slice(&self.report_descriptor_length.to_le_bytes());
+
+        if bytes.len() != self.length as usize {
+            return Err(Error::LengthMismatch {
+                descriptor: HID_DESCRIPTOR_TYPE,
+                expected: self.length as usize,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(bytes)
     }
 
     fn get_descriptor_type(&self) -> DescriptorType {
         self.descriptor_type
     }
 }
+
+impl DecodeDescriptor for HidDescriptor {
+    /// Only the first class descriptor (the report descriptor) is decoded.
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(bytes, HID_DESCRIPTOR_TYPE, None)?;
+        if bytes.len() < HID_DESCRIPTOR_LENGTH as usize {
+            return Err(DecodeError::InvalidLength {
+                descriptor_type: bytes[1],
+                length: bytes[0],
+            });
+        }
+
+        Ok(HidDescriptor {
+            length: HID_DESCRIPTOR_LENGTH,
+            descriptor_type: HID_DESCRIPTOR_TYPE,
+            bcd_hid: Version::decode(&bytes[2..4])?,
+            country_code: bytes[4],
+            num_descriptors: bytes[5],
+            report_descriptor_type: DescriptorType::decode(bytes[6])?,
+            report_descriptor_length: u16::from_le_bytes([bytes[7], bytes[8]]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let descriptor = HidDescriptor {
+            length: 9,
+            descriptor_type: DescriptorType::Hid,
+            bcd_hid: HID1_11,
+            country_code: 0,
+            num_descriptors: 1,
+            report_descriptor_type: DescriptorType::Report,
+            report_descriptor_length: 0x34,
+        };
+        let encoded = vec![
+            0x09, // bLength
+            0x21, // bDescriptorType
+            0x11, 0x01, // bcdHID
+            0x00, // bCountryCode
+            0x01, // bNumDescriptors
+            0x22, // bDescriptorType
+            0x34, 0x00, // wDescriptorLength
+        ];
+        assert_eq!(descriptor.encode().unwrap(), encoded);
+        assert_eq!(HidDescriptor::decode(&encoded), Ok(descriptor));
+    }
+}
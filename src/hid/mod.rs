@@ -1,2 +1,3 @@
-// pub mod hid_descriptor;
-// pub mod hid_builder;
+pub mod boot_protocol;
+pub mod hid_builder;
+pub mod hid_descriptor;
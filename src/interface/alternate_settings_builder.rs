@@ -0,0 +1,32 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{descriptor_type::DescriptorType, error::Error};
+
+use super::interface_builder::InterfaceBuilder;
+
+/// One interface of a configuration. All alternate settings share the same `bInterfaceNumber`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlternateSettingsBuilder {
+    /// Turns into one interface descriptor per `bAlternateSetting`, numbered in order
+    pub alternate_settings: Vec<InterfaceBuilder>,
+}
+
+impl AlternateSettingsBuilder {
+    /// An interface without alternate settings
+    pub fn single(interface: InterfaceBuilder) -> AlternateSettingsBuilder {
+        AlternateSettingsBuilder {
+            alternate_settings: vec![interface],
+        }
+    }
+
+    /// Checks that there is a default setting.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.alternate_settings.is_empty() {
+            return Err(Error::Missing {
+                descriptor: DescriptorType::Interface,
+                field: "alternate_settings",
+            });
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,17 @@
 use alloc::vec::Vec;
 
-use crate::{endpoint::endpoint_builder::EndpointBuilder, string::string_builder::StringBuidler};
+use crate::{
+    descriptor_type::DescriptorType,
+    endpoint::endpoint_builder::EndpointBuilder,
+    error::{DecodeError, Error},
+    hid::hid_builder::HidBuilder,
+    string::{string_builder::StringBuidler, string_descriptor::StringDescriptor},
+};
 
 use super::{interface_class::InterfaceClass, interface_descriptor::InterfaceDescriptor};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InterfaceBuilder {
-    /// Turns into `bAlternateSetting`
-    pub alternate_setting: u8,
     /// Turns into `bInterfaceClass`
     pub interface_class: InterfaceClass,
     /// Turns into `bInterfaceSubClass`
@@ -15,7 +19,9 @@ pub struct InterfaceBuilder {
     /// Turns into `bInterfaceProtocol`
     pub interface_protocol: u8,
     /// Turns into `iInterface`
-    pub interface: StringBuidler,
+    pub interface: Option<StringBuidler>,
+    /// Turns into the HID descriptor and the report descriptor of a HID interface
+    pub hid: Option<HidBuilder>,
     /// Turns into `bNumEndpoints`
     pub endpoints: Vec<EndpointBuilder>,
 }
@@ -24,19 +30,29 @@ impl InterfaceBuilder {
     pub fn build(
         &self,
         interface_number: u8,
+        alternate_setting: u8,
         interface: u8,
         num_endpoints: u8,
-    ) -> Result<InterfaceDescriptor, &str> {
-        if num_endpoints == 0 {
-            return Err("InterfaceDescript need at least 1 endpoint.");
+    ) -> Result<InterfaceDescriptor, Error> {
+        if num_endpoints == 0 && self.interface_class.requires_endpoints() {
+            return Err(Error::Missing {
+                descriptor: DescriptorType::Interface,
+                field: "endpoints",
+            });
         }
 
         self.interface_class
             .validate(self.interface_suclass, self.interface_protocol)?;
+        if self.interface_class == InterfaceClass::HumanInterfaceDevice && self.hid.is_none() {
+            return Err(Error::Missing {
+                descriptor: DescriptorType::Interface,
+                field: "hid",
+            });
+        }
 
         Ok(InterfaceDescriptor {
             interface_number,
-            alternate_setting: self.alternate_setting,
+            alternate_setting,
             num_endpoints,
             interface_class: self.interface_class,
             interface_suclass: self.interface_suclass,
@@ -44,4 +60,56 @@ impl InterfaceBuilder {
             interface,
         })
     }
+
+    /// Endpoints and the HID descriptor are not part of the interface descriptor and have to
+    /// be added afterwards. `bInterfaceNumber` and `bAlternateSetting` are given by the
+    /// position in the configuration.
+    pub fn from_descriptor(
+        descriptor: &InterfaceDescriptor,
+        strings: &[StringDescriptor],
+    ) -> Result<InterfaceBuilder, DecodeError> {
+        Ok(InterfaceBuilder {
+            interface_class: descriptor.interface_class,
+            interface_suclass: descriptor.interface_suclass,
+            interface_protocol: descriptor.interface_protocol,
+            interface: StringBuidler::resolve(descriptor.interface, strings)?,
+            hid: None,
+            endpoints: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interface_builder(interface_class: InterfaceClass) -> InterfaceBuilder {
+        InterfaceBuilder {
+            interface_class,
+            interface_suclass: 0x00,
+            interface_protocol: 0x00,
+            interface: None,
+            hid: None,
+            endpoints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_without_endpoints() {
+        let audio_control = interface_builder(InterfaceClass::Audio)
+            .build(0, 0, 0, 0)
+            .unwrap();
+        assert_eq!(audio_control.num_endpoints, 0);
+        assert!(interface_builder(InterfaceClass::CdcData)
+            .build(1, 0, 0, 0)
+            .is_ok());
+
+        assert_eq!(
+            interface_builder(InterfaceClass::HumanInterfaceDevice).build(0, 0, 0, 0),
+            Err(Error::Missing {
+                descriptor: DescriptorType::Interface,
+                field: "endpoints",
+            })
+        );
+    }
 }
@@ -1,4 +1,8 @@
-use crate::binary::EncodeByte;
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum InterfaceClass {
@@ -9,7 +13,7 @@ pub enum InterfaceClass {
     Image,
     Printer,
     MassStorage,
-    CdcData ,
+    CdcData,
     SmartCard,
     ContentSecurity,
     Video,
@@ -24,11 +28,13 @@ pub enum InterfaceClass {
     Miscellaneous,
     ApplicationSpecific,
     VendorSpecific,
+    /// A class code without a variant, e.g. decoded from a device. It is not validated.
+    Other(u8),
 }
 
 impl EncodeByte for InterfaceClass {
-    fn encode(&self) -> Result<u8, &str> {
-        match self {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
             InterfaceClass::Audio => Ok(0x01),
             InterfaceClass::CommunicationAndCDCControl => Ok(0x02),
             InterfaceClass::HumanInterfaceDevice => Ok(0x03),
@@ -51,13 +57,49 @@ impl EncodeByte for InterfaceClass {
             InterfaceClass::Miscellaneous => Ok(0xEF),
             InterfaceClass::ApplicationSpecific => Ok(0xFE),
             InterfaceClass::VendorSpecific => Ok(0xFF),
+            InterfaceClass::Other(class) => Ok(class),
+        }
+    }
+}
+
+impl DecodeByte for InterfaceClass {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x01 => Ok(InterfaceClass::Audio),
+            0x02 => Ok(InterfaceClass::CommunicationAndCDCControl),
+            0x03 => Ok(InterfaceClass::HumanInterfaceDevice),
+            0x05 => Ok(InterfaceClass::Physical),
+            0x06 => Ok(InterfaceClass::Image),
+            0x07 => Ok(InterfaceClass::Printer),
+            0x08 => Ok(InterfaceClass::MassStorage),
+            0x0A => Ok(InterfaceClass::CdcData),
+            0x0B => Ok(InterfaceClass::SmartCard),
+            0x0D => Ok(InterfaceClass::ContentSecurity),
+            0x0E => Ok(InterfaceClass::Video),
+            0x0F => Ok(InterfaceClass::PersonalHealthcare),
+            0x10 => Ok(InterfaceClass::AudioVideo),
+            0x12 => Ok(InterfaceClass::UsbTypeCBridge),
+            0x13 => Ok(InterfaceClass::UsbBulkDisplay),
+            0x14 => Ok(InterfaceClass::MctpOverUSB),
+            0x15 => Ok(InterfaceClass::I3c),
+            0xDC => Ok(InterfaceClass::Diagnostic),
+            0xE0 => Ok(InterfaceClass::WirelessController),
+            0xEF => Ok(InterfaceClass::Miscellaneous),
+            0xFE => Ok(InterfaceClass::ApplicationSpecific),
+            0xFF => Ok(InterfaceClass::VendorSpecific),
+            _ => Ok(InterfaceClass::Other(byte)),
         }
     }
 }
 
 impl InterfaceClass {
-    pub fn validate(&self, suclass: u8, protocol: u8) -> Result<(), &str> {
-        let error = Err("The interface base class is not compatible with the interface subclass and protocol. Pease check https://www.usb.org/defined-class-codes");
+    pub fn validate(&self, suclass: u8, protocol: u8) -> Result<(), Error> {
+        let error = Err(Error::InvalidClass {
+            descriptor: DescriptorType::Interface,
+            class: self.encode()?,
+            subclass: suclass,
+            protocol,
+        });
         match self {
             InterfaceClass::Audio => Ok(()),
             InterfaceClass::CommunicationAndCDCControl => Ok(()),
@@ -85,7 +127,7 @@ impl InterfaceClass {
             InterfaceClass::Video => Ok(()),
             InterfaceClass::PersonalHealthcare => Ok(()),
             InterfaceClass::AudioVideo => match suclass {
-                0x01 | 0x02 | 0x03 => match protocol {
+                0x01..=0x03 => match protocol {
                     0x00 => Ok(()),
                     _ => error,
                 },
@@ -124,7 +166,7 @@ impl InterfaceClass {
                     0x01 => Ok(()),
                     _ => error,
                 },
-                0x02 | 0x03 | 0x04 | 0x05 | 0x06 | 0x07 => match protocol {
+                0x02..=0x07 => match protocol {
                     0x00 | 0x01 => Ok(()),
                     _ => error,
                 },
@@ -136,11 +178,11 @@ impl InterfaceClass {
             },
             InterfaceClass::WirelessController => match suclass {
                 0x01 => match protocol {
-                    0x01 | 0x02 | 0x03 | 0x04 => Ok(()),
+                    0x01..=0x04 => Ok(()),
                     _ => error,
                 },
                 0x02 => match protocol {
-                    0x01 | 0x02 | 0x03 => Ok(()),
+                    0x01..=0x03 => Ok(()),
                     _ => error,
                 },
                 _ => error,
@@ -155,11 +197,11 @@ impl InterfaceClass {
                     _ => error,
                 },
                 0x04 => match protocol {
-                    0x01 | 0x02 | 0x03 | 0x04 | 0x05 | 0x06 | 0x07 => Ok(()),
+                    0x01..=0x07 => Ok(()),
                     _ => error,
                 },
                 0x05 | 0x07 => match protocol {
-                    0x00 | 0x01 | 0x02 => Ok(()),
+                    0x00..=0x02 => Ok(()),
                     _ => error,
                 },
                 _ => error,
@@ -180,8 +222,23 @@ impl InterfaceClass {
                 _ => error,
             },
             InterfaceClass::VendorSpecific => Ok(()),
+            InterfaceClass::Other(_) => Ok(()),
         }
     }
+
+    /// Whether the class specification requires at least one endpoint besides endpoint zero.
+    /// Audio control, DFU, and idle alternate settings of streaming and CDC data interfaces
+    /// work without endpoints.
+    pub fn requires_endpoints(&self) -> bool {
+        matches!(
+            self,
+            InterfaceClass::HumanInterfaceDevice
+                | InterfaceClass::Image
+                | InterfaceClass::Printer
+                | InterfaceClass::MassStorage
+                | InterfaceClass::SmartCard
+        )
+    }
 }
 
 #[cfg(test)]
@@ -287,4 +344,26 @@ mod tests {
             INTERFACE_VENDOR_SPECIFIC
         );
     }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            InterfaceClass::decode(INTERFACE_HUMAN_INTERFACE_DEVICE),
+            Ok(InterfaceClass::HumanInterfaceDevice)
+        );
+        assert_eq!(
+            InterfaceClass::decode(INTERFACE_CDC_DATA),
+            Ok(InterfaceClass::CdcData)
+        );
+        assert_eq!(
+            InterfaceClass::decode(INTERFACE_VENDOR_SPECIFIC),
+            Ok(InterfaceClass::VendorSpecific)
+        );
+        assert_eq!(
+            InterfaceClass::decode(0x04),
+            Ok(InterfaceClass::Other(0x04))
+        );
+        assert_eq!(InterfaceClass::Other(0x04).encode(), Ok(0x04));
+        assert_eq!(InterfaceClass::Other(0x04).validate(0x01, 0x02), Ok(()));
+    }
 }
@@ -1,6 +1,11 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
-use crate::{binary::EncodeByte, descriptor::Descriptor, descriptor_type::DescriptorType};
+use crate::{
+    binary::{expect_descriptor, DecodeByte, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
 
 use super::interface_class::InterfaceClass;
 
@@ -26,10 +31,11 @@ pub struct InterfaceDescriptor {
 }
 
 impl Descriptor for InterfaceDescriptor {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
-        let mut bytes = Vec::<u8>::new();
-        bytes.push(9); // bLength
-        bytes.push(DescriptorType::Interface.encode()?); // bDescriptorType
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![
+            INTERFACE_DESCRIPTOR_LENGTH,         // bLength
+            INTERFACE_DESCRIPTOR_TYPE.encode()?, // bDescriptorType
+        ];
         bytes.push(self.interface_number);
         bytes.push(self.alternate_setting);
         bytes.push(self.num_endpoints);
@@ -46,6 +52,26 @@ impl Descriptor for InterfaceDescriptor {
     }
 }
 
+impl DecodeDescriptor for InterfaceDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            INTERFACE_DESCRIPTOR_TYPE,
+            Some(INTERFACE_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(InterfaceDescriptor {
+            interface_number: bytes[2],
+            alternate_setting: bytes[3],
+            num_endpoints: bytes[4],
+            interface_class: InterfaceClass::decode(bytes[5])?,
+            interface_suclass: bytes[6],
+            interface_protocol: bytes[7],
+            interface: bytes[8],
+        })
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
 
@@ -77,5 +103,34 @@ pub mod tests {
             interface_descriptor.encode().unwrap(),
             interface_descriptor_encoded
         );
+        assert_eq!(
+            InterfaceDescriptor::decode(&interface_descriptor_encoded),
+            Ok(interface_descriptor)
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            InterfaceDescriptor::decode(&[9, 4, 0, 0, 1, 3, 0]),
+            Err(DecodeError::Truncated {
+                expected: 9,
+                actual: 7
+            })
+        );
+        assert_eq!(
+            InterfaceDescriptor::decode(&[7, 4, 0, 0, 1, 3, 0]),
+            Err(DecodeError::InvalidLength {
+                descriptor_type: 4,
+                length: 7
+            })
+        );
+        // unlisted class codes decode instead of failing
+        assert_eq!(
+            InterfaceDescriptor::decode(&[9, 4, 0, 0, 1, 0x04, 0, 0, 0])
+                .unwrap()
+                .interface_class,
+            InterfaceClass::Other(0x04)
+        );
     }
 }
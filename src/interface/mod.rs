@@ -1,3 +1,4 @@
+pub mod alternate_settings_builder;
+pub mod interface_builder;
 pub mod interface_class;
 pub mod interface_descriptor;
-pub mod interface_builder;
@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+
+use crate::{
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+    interface::{
+        alternate_settings_builder::AlternateSettingsBuilder, interface_class::InterfaceClass,
+    },
+    string::{string_builder::StringBuidler, string_descriptor::StringDescriptor},
+};
+
+use super::interface_association_descriptor::InterfaceAssociationDescriptor;
+
+/// Groups consecutive interfaces into one function of a composite device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceAssociationBuilder {
+    /// Turns into `bFunctionClass`
+    pub function_class: InterfaceClass,
+    /// Turns into `bFunctionSubClass`
+    pub function_suclass: u8,
+    /// Turns into `bFunctionProtocol`
+    pub function_protocol: u8,
+    /// Turns into `iFunction`
+    pub function: Option<StringBuidler>,
+    /// Turns into `bFirstInterface` and `bInterfaceCount`
+    pub interfaces: Vec<AlternateSettingsBuilder>,
+}
+
+impl InterfaceAssociationBuilder {
+    pub fn build(
+        &self,
+        first_interface: u8,
+        interface_count: u8,
+        function: u8,
+    ) -> Result<InterfaceAssociationDescriptor, Error> {
+        if interface_count == 0 {
+            return Err(Error::Missing {
+                descriptor: DescriptorType::InterfaceAssociation,
+                field: "interfaces",
+            });
+        }
+
+        self.function_class
+            .validate(self.function_suclass, self.function_protocol)
+            .map_err(|error| match error {
+                Error::InvalidClass {
+                    class,
+                    subclass,
+                    protocol,
+                    ..
+                } => Error::InvalidClass {
+                    descriptor: DescriptorType::InterfaceAssociation,
+                    class,
+                    subclass,
+                    protocol,
+                },
+                error => error,
+            })?;
+
+        Ok(InterfaceAssociationDescriptor {
+            first_interface,
+            interface_count,
+            function_class: self.function_class,
+            function_suclass: self.function_suclass,
+            function_protocol: self.function_protocol,
+            function,
+        })
+    }
+
+    /// The associated interfaces are not part of the descriptor and have to be added afterwards.
+    pub fn from_descriptor(
+        descriptor: &InterfaceAssociationDescriptor,
+        strings: &[StringDescriptor],
+    ) -> Result<InterfaceAssociationBuilder, DecodeError> {
+        Ok(InterfaceAssociationBuilder {
+            function_class: descriptor.function_class,
+            function_suclass: descriptor.function_suclass,
+            function_protocol: descriptor.function_protocol,
+            function: StringBuidler::resolve(descriptor.function, strings)?,
+            interfaces: Vec::new(),
+        })
+    }
+}
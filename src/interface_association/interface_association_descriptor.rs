@@ -0,0 +1,101 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    binary::{expect_descriptor, DecodeByte, EncodeByte},
+    descriptor::{DecodeDescriptor, Descriptor},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+    interface::interface_class::InterfaceClass,
+};
+
+pub const INTERFACE_ASSOCIATION_DESCRIPTOR_LENGTH: u8 = 8;
+pub const INTERFACE_ASSOCIATION_DESCRIPTOR_TYPE: DescriptorType =
+    DescriptorType::InterfaceAssociation;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceAssociationDescriptor {
+    /// Turns into `bFirstInterface`
+    pub first_interface: u8,
+    /// Turns into `bInterfaceCount`
+    pub interface_count: u8,
+    /// Turns into `bFunctionClass`
+    pub function_class: InterfaceClass,
+    /// Turns into `bFunctionSubClass`
+    pub function_suclass: u8,
+    /// Turns into `bFunctionProtocol`
+    pub function_protocol: u8,
+    /// Turns into `iFunction`
+    pub function: u8,
+}
+
+impl Descriptor for InterfaceAssociationDescriptor {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![
+            INTERFACE_ASSOCIATION_DESCRIPTOR_LENGTH,         // bLength
+            INTERFACE_ASSOCIATION_DESCRIPTOR_TYPE.encode()?, // bDescriptorType
+        ];
+        bytes.push(self.first_interface);
+        bytes.push(self.interface_count);
+        bytes.push(self.function_class.encode()?);
+        bytes.push(self.function_suclass);
+        bytes.push(self.function_protocol);
+        bytes.push(self.function);
+
+        Ok(bytes)
+    }
+
+    fn get_descriptor_type(&self) -> DescriptorType {
+        DescriptorType::InterfaceAssociation
+    }
+}
+
+impl DecodeDescriptor for InterfaceAssociationDescriptor {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(
+            bytes,
+            INTERFACE_ASSOCIATION_DESCRIPTOR_TYPE,
+            Some(INTERFACE_ASSOCIATION_DESCRIPTOR_LENGTH),
+        )?;
+
+        Ok(InterfaceAssociationDescriptor {
+            first_interface: bytes[2],
+            interface_count: bytes[3],
+            function_class: InterfaceClass::decode(bytes[4])?,
+            function_suclass: bytes[5],
+            function_protocol: bytes[6],
+            function: bytes[7],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let descriptor = InterfaceAssociationDescriptor {
+            first_interface: 0,
+            interface_count: 2,
+            function_class: InterfaceClass::CommunicationAndCDCControl,
+            function_suclass: 0x02,
+            function_protocol: 0x01,
+            function: 4,
+        };
+        let encoded = vec![
+            8,    // bLength
+            0x0B, // bDescriptorType
+            0,    // bFirstInterface
+            2,    // bInterfaceCount
+            0x02, // bFunctionClass
+            0x02, // bFunctionSubClass
+            0x01, // bFunctionProtocol
+            4,    // iFunction
+        ];
+        assert_eq!(descriptor.encode().unwrap(), encoded);
+        assert_eq!(
+            InterfaceAssociationDescriptor::decode(&encoded),
+            Ok(descriptor)
+        );
+    }
+}
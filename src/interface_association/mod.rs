@@ -0,0 +1,2 @@
+pub mod interface_association_builder;
+pub mod interface_association_descriptor;
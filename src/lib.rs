@@ -6,10 +6,16 @@ extern crate alloc;
 #[macro_use]
 extern crate std;
 
+pub mod bos;
 pub mod configuration;
 pub mod device;
 pub mod endpoint;
+pub mod hid;
 pub mod interface;
+pub mod interface_association;
+pub mod report;
+pub mod request;
+pub mod speed;
 pub mod string;
 
 pub mod binary;
@@ -17,4 +23,5 @@ pub mod descriptor;
 pub mod descriptors;
 pub mod descriptors_builder;
 pub mod descriptor_type;
+pub mod error;
 pub mod version;
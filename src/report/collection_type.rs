@@ -0,0 +1,57 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CollectionType {
+    Physical,
+    Application,
+    Logical,
+    Report,
+    NamedArray,
+    UsageSwitch,
+    UsageModifier,
+    /// 0x80 to 0xFF
+    VendorDefined(u8),
+}
+
+impl EncodeByte for CollectionType {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            CollectionType::Physical => Ok(0x00),
+            CollectionType::Application => Ok(0x01),
+            CollectionType::Logical => Ok(0x02),
+            CollectionType::Report => Ok(0x03),
+            CollectionType::NamedArray => Ok(0x04),
+            CollectionType::UsageSwitch => Ok(0x05),
+            CollectionType::UsageModifier => Ok(0x06),
+            CollectionType::VendorDefined(value @ 0x80..=0xFF) => Ok(value),
+            CollectionType::VendorDefined(value) => Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Report,
+                field: "Collection",
+                value: value as u32,
+            }),
+        }
+    }
+}
+
+impl DecodeByte for CollectionType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(CollectionType::Physical),
+            0x01 => Ok(CollectionType::Application),
+            0x02 => Ok(CollectionType::Logical),
+            0x03 => Ok(CollectionType::Report),
+            0x04 => Ok(CollectionType::NamedArray),
+            0x05 => Ok(CollectionType::UsageSwitch),
+            0x06 => Ok(CollectionType::UsageModifier),
+            0x80..=0xFF => Ok(CollectionType::VendorDefined(byte)),
+            _ => Err(DecodeError::InvalidValue {
+                field: "Collection",
+                value: byte as u16,
+            }),
+        }
+    }
+}
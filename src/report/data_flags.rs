@@ -0,0 +1,56 @@
+/// Flags of Input, Output and Feature items. The default is Data, Array, Absolute.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct DataFlags {
+    /// Bit 0, Data or Constant
+    pub constant: bool,
+    /// Bit 1, Array or Variable
+    pub variable: bool,
+    /// Bit 2, Absolute or Relative
+    pub relative: bool,
+    /// Bit 3, No Wrap or Wrap
+    pub wrap: bool,
+    /// Bit 4, Linear or Non Linear
+    pub non_linear: bool,
+    /// Bit 5, Preferred State or No Preferred
+    pub no_preferred: bool,
+    /// Bit 6, No Null Position or Null State
+    pub null_state: bool,
+    /// Bit 7, Non Volatile or Volatile. Reserved for Input items.
+    pub volatile: bool,
+    /// Bit 8, Bit Field or Buffered Bytes
+    pub buffered_bytes: bool,
+}
+
+impl DataFlags {
+    pub fn bits(&self) -> u32 {
+        [
+            self.constant,
+            self.variable,
+            self.relative,
+            self.wrap,
+            self.non_linear,
+            self.no_preferred,
+            self.null_state,
+            self.volatile,
+            self.buffered_bytes,
+        ]
+        .iter()
+        .enumerate()
+        .fold(0, |bits, (bit, set)| bits | (*set as u32) << bit)
+    }
+
+    pub fn from_bits(bits: u32) -> DataFlags {
+        let bit = |bit: u32| bits & (1 << bit) != 0;
+        DataFlags {
+            constant: bit(0),
+            variable: bit(1),
+            relative: bit(2),
+            wrap: bit(3),
+            non_linear: bit(4),
+            no_preferred: bit(5),
+            null_state: bit(6),
+            volatile: bit(7),
+            buffered_bytes: bit(8),
+        }
+    }
+}
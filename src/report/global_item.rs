@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+
+use crate::{
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+use super::item::{decode_signed, decode_unsigned, signed_data, unsigned_data};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GlobalItem {
+    UsagePage(u16),
+    LogicalMinimum(i32),
+    LogicalMaximum(i32),
+    PhysicalMinimum(i32),
+    PhysicalMaximum(i32),
+    /// -8 to 7, encoded as a 4 bit two's complement
+    UnitExponent(i8),
+    Unit(u32),
+    ReportSize(u32),
+    /// 1 to 255, 0 is reserved
+    ReportId(u8),
+    ReportCount(u32),
+    Push,
+    Pop,
+}
+
+impl GlobalItem {
+    pub fn tag(&self) -> u8 {
+        match self {
+            GlobalItem::UsagePage(_) => 0x00,
+            GlobalItem::LogicalMinimum(_) => 0x01,
+            GlobalItem::LogicalMaximum(_) => 0x02,
+            GlobalItem::PhysicalMinimum(_) => 0x03,
+            GlobalItem::PhysicalMaximum(_) => 0x04,
+            GlobalItem::UnitExponent(_) => 0x05,
+            GlobalItem::Unit(_) => 0x06,
+            GlobalItem::ReportSize(_) => 0x07,
+            GlobalItem::ReportId(_) => 0x08,
+            GlobalItem::ReportCount(_) => 0x09,
+            GlobalItem::Push => 0x0A,
+            GlobalItem::Pop => 0x0B,
+        }
+    }
+
+    pub fn data(&self) -> Result<Vec<u8>, Error> {
+        match *self {
+            GlobalItem::UsagePage(page) => Ok(unsigned_data(page as u32)),
+            GlobalItem::LogicalMinimum(value)
+            | GlobalItem::LogicalMaximum(value)
+            | GlobalItem::PhysicalMinimum(value)
+            | GlobalItem::PhysicalMaximum(value) => Ok(signed_data(value)),
+            GlobalItem::UnitExponent(exponent @ -8..=7) => {
+                Ok(unsigned_data(exponent as u32 & 0x0F))
+            }
+            GlobalItem::UnitExponent(exponent) => Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Report,
+                field: "Unit Exponent",
+                value: exponent as u32,
+            }),
+            GlobalItem::ReportId(0) => Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Report,
+                field: "Report ID",
+                value: 0,
+            }),
+            GlobalItem::ReportId(id) => Ok(unsigned_data(id as u32)),
+            GlobalItem::Unit(value)
+            | GlobalItem::ReportSize(value)
+            | GlobalItem::ReportCount(value) => Ok(unsigned_data(value)),
+            GlobalItem::Push | GlobalItem::Pop => Ok(Vec::new()),
+        }
+    }
+
+    pub fn decode(tag: u8, data: &[u8]) -> Result<GlobalItem, DecodeError> {
+        let value = decode_unsigned(data);
+        match tag {
+            0x00 => Ok(GlobalItem::UsagePage(value as u16)),
+            0x01 => Ok(GlobalItem::LogicalMinimum(decode_signed(data))),
+            0x02 => Ok(GlobalItem::LogicalMaximum(decode_signed(data))),
+            0x03 => Ok(GlobalItem::PhysicalMinimum(decode_signed(data))),
+            0x04 => Ok(GlobalItem::PhysicalMaximum(decode_signed(data))),
+            // A 4 bit two's complement, some devices use a signed byte instead
+            0x05 => match value {
+                0x00..=0x07 => Ok(GlobalItem::UnitExponent(value as i8)),
+                0x08..=0x0F => Ok(GlobalItem::UnitExponent(value as i8 - 0x10)),
+                _ => match decode_signed(data) {
+                    exponent @ -8..=7 => Ok(GlobalItem::UnitExponent(exponent as i8)),
+                    _ => Err(DecodeError::InvalidValue {
+                        field: "Unit Exponent",
+                        value: value as u16,
+                    }),
+                },
+            },
+            0x06 => Ok(GlobalItem::Unit(value)),
+            0x07 => Ok(GlobalItem::ReportSize(value)),
+            0x08 => match value {
+                0x01..=0xFF => Ok(GlobalItem::ReportId(value as u8)),
+                _ => Err(DecodeError::InvalidValue {
+                    field: "Report ID",
+                    value: value as u16,
+                }),
+            },
+            0x09 => Ok(GlobalItem::ReportCount(value)),
+            0x0A => Ok(GlobalItem::Push),
+            0x0B => Ok(GlobalItem::Pop),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bTag",
+                value: tag as u16,
+            }),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::{vec, vec::Vec};
+
+use crate::error::DecodeError;
+
+use super::{report_builder::ReportBuilder, report_struct::ReportStruct};
+
+/// A struct that is one report. Implemented by `#[derive(HidReport)]` from the
+/// `usb-descriptors-derive` crate, so the report descriptor and the packed report
+/// are generated from the same field list.
+pub trait HidReport: Sized {
+    const REPORT: ReportStruct;
+
+    /// Writes the fields to `report`, which starts after the report ID byte
+    fn write_fields(&self, report: &mut [u8]);
+
+    /// Reads the fields from `report`, which starts after the report ID byte
+    /// and is at least as long as the fields
+    fn read_fields(report: &[u8]) -> Self;
+
+    /// The report descriptor items of this report
+    fn report() -> ReportBuilder {
+        Self::REPORT.report()
+    }
+
+    /// The report as sent over the wire, starting with the report ID if there is one
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::REPORT.report_length()];
+        match Self::REPORT.report_id {
+            0 => self.write_fields(&mut bytes),
+            report_id => {
+                bytes[0] = report_id;
+                self.write_fields(&mut bytes[1..]);
+            }
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let length = Self::REPORT.report_length();
+        if bytes.len() < length {
+            return Err(DecodeError::Truncated {
+                expected: length,
+                actual: bytes.len(),
+            });
+        }
+        match Self::REPORT.report_id {
+            0 => Ok(Self::read_fields(bytes)),
+            report_id if bytes[0] == report_id => Ok(Self::read_fields(&bytes[1..])),
+            _ => Err(DecodeError::InvalidValue {
+                field: "Report ID",
+                value: bytes[0] as u16,
+            }),
+        }
+    }
+}
+
+/// Writes the lowest `size` bits of `value` at `bit_offset`, least significant bit first
+pub fn write_bits(report: &mut [u8], bit_offset: u32, size: u32, value: u32) {
+    for bit in 0..size {
+        let position = (bit_offset + bit) as usize;
+        let mask = 1 << (position % 8);
+        if (value >> bit) & 1 != 0 {
+            report[position / 8] |= mask;
+        } else {
+            report[position / 8] &= !mask;
+        }
+    }
+}
+
+/// Reads `size` bits at `bit_offset`, sign extended if `signed`
+pub fn read_bits(report: &[u8], bit_offset: u32, size: u32, signed: bool) -> u32 {
+    let mut value = 0u32;
+    for bit in 0..size {
+        let position = (bit_offset + bit) as usize;
+        value |= ((report[position / 8] as u32 >> (position % 8)) & 1) << bit;
+    }
+    if signed && size > 0 && size < 32 && value & (1 << (size - 1)) != 0 {
+        value |= u32::MAX << size;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{
+        data_flags::DataFlags,
+        report_kind::ReportKind,
+        report_layout::ReportLayout,
+        report_struct_field::ReportStructField,
+        struct_usages::StructUsages,
+        usage_tables::{Button, GenericDesktop},
+    };
+
+    use super::*;
+
+    const VARIABLE: DataFlags = DataFlags {
+        constant: false,
+        variable: true,
+        relative: false,
+        wrap: false,
+        non_linear: false,
+        no_preferred: false,
+        null_state: false,
+        volatile: false,
+        buffered_bytes: false,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Mouse {
+        buttons: [bool; 3],
+        x: i8,
+        y: i8,
+    }
+
+    impl HidReport for Mouse {
+        const REPORT: ReportStruct = ReportStruct {
+            kind: ReportKind::Input,
+            application: Some(GenericDesktop::MOUSE),
+            report_id: 2,
+            fields: &[
+                ReportStructField {
+                    usages: StructUsages::Range {
+                        minimum: Button::button(1),
+                        maximum: Button::button(3),
+                    },
+                    size: 1,
+                    count: 3,
+                    logical_minimum: 0,
+                    logical_maximum: 1,
+                    flags: VARIABLE,
+                },
+                ReportStructField {
+                    usages: StructUsages::List(&[GenericDesktop::X, GenericDesktop::Y]),
+                    size: 8,
+                    count: 2,
+                    logical_minimum: -127,
+                    logical_maximum: 127,
+                    flags: DataFlags {
+                        relative: true,
+                        ..VARIABLE
+                    },
+                },
+            ],
+        };
+
+        fn write_fields(&self, report: &mut [u8]) {
+            for (index, button) in self.buttons.iter().enumerate() {
+                write_bits(report, index as u32, 1, *button as u32);
+            }
+            write_bits(report, 3, 8, self.x as u32);
+            write_bits(report, 11, 8, self.y as u32);
+        }
+
+        fn read_fields(report: &[u8]) -> Self {
+            Mouse {
+                buttons: core::array::from_fn(|index| {
+                    read_bits(report, index as u32, 1, false) != 0
+                }),
+                x: read_bits(report, 3, 8, true) as i8,
+                y: read_bits(report, 11, 8, true) as i8,
+            }
+        }
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut report = [0xFF, 0x00];
+        write_bits(&mut report, 4, 8, 0x5A);
+        assert_eq!(report, [0xAF, 0x05]);
+        assert_eq!(read_bits(&report, 4, 8, false), 0x5A);
+        assert_eq!(read_bits(&report, 0, 4, true), 0xFFFF_FFFF);
+        assert_eq!(read_bits(&report, 0, 4, false), 0x0F);
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Mouse::report().build().unwrap();
+        assert_eq!(
+            report.items,
+            vec![
+                0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x85, 0x02, 0x05, 0x09, 0x19, 0x01, 0x29, 0x03,
+                0x14, 0x25, 0x01, 0x75, 0x01, 0x95, 0x03, 0x81, 0x02, 0x05, 0x01, 0x09, 0x30, 0x09,
+                0x31, 0x15, 0x81, 0x25, 0x7F, 0x75, 0x08, 0x95, 0x02, 0x81, 0x06, 0xC0,
+            ]
+        );
+
+        let mouse = Mouse {
+            buttons: [true, false, true],
+            x: -1,
+            y: 2,
+        };
+        let bytes = mouse.to_bytes();
+        assert_eq!(bytes, vec![0x02, 0xFD, 0x17, 0x00]);
+        assert_eq!(Mouse::from_bytes(&bytes), Ok(mouse));
+
+        // The layout parsed from the descriptor reads the same values
+        let layout = ReportLayout::decode(&report.items).unwrap();
+        assert_eq!(layout.report_length(ReportKind::Input, 2), bytes.len());
+        let fields = layout.report_fields(ReportKind::Input, 2);
+        assert_eq!(fields[1].value(&bytes, 0), Some(-1));
+        assert_eq!(fields[1].value(&bytes, 1), Some(2));
+
+        assert_eq!(
+            Mouse::from_bytes(&[0x02, 0x00]),
+            Err(DecodeError::Truncated {
+                expected: 4,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Mouse::from_bytes(&[0x01, 0x00, 0x00, 0x00]),
+            Err(DecodeError::InvalidValue {
+                field: "Report ID",
+                value: 1
+            })
+        );
+    }
+}
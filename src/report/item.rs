@@ -0,0 +1,293 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    binary::{DecodeByte, DecodeBytes, EncodeByte, EncodeBytes},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+use super::{
+    global_item::GlobalItem, item_type::ItemType, local_item::LocalItem, main_item::MainItem,
+};
+
+/// `bTag` and `bType` of a long item prefix
+pub const LONG_ITEM_PREFIX: u8 = 0xFE;
+
+/// One item of a report descriptor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Main(MainItem),
+    Global(GlobalItem),
+    Local(LocalItem),
+    /// Up to 255 bytes of data with a `bLongItemTag` from 0xF0 to 0xFF
+    Long {
+        tag: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl EncodeBytes for Item {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            Item::Main(item) => short_item(ItemType::Main, item.tag(), &item.data()?),
+            Item::Global(item) => short_item(ItemType::Global, item.tag(), &item.data()?),
+            Item::Local(item) => short_item(ItemType::Local, item.tag(), &item.data()?),
+            Item::Long { tag, data } => {
+                let data_size = u8::try_from(data.len()).map_err(|_| Error::LengthOverflow {
+                    descriptor: DescriptorType::Report,
+                    field: "bDataSize",
+                    length: data.len(),
+                })?;
+                let mut bytes = vec![LONG_ITEM_PREFIX, data_size, *tag];
+                bytes.extend_from_slice(data);
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+impl DecodeBytes for Item {
+    /// Decodes exactly one item, see `split_item`
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (item, _) = split_item(bytes)?;
+        if item[0] == LONG_ITEM_PREFIX {
+            return Ok(Item::Long {
+                tag: item[2],
+                data: item[3..].to_vec(),
+            });
+        }
+
+        let tag = item[0] >> 4;
+        let data = &item[1..];
+        match ItemType::decode((item[0] >> 2) & 0x03)? {
+            ItemType::Main => Ok(Item::Main(MainItem::decode(tag, data)?)),
+            ItemType::Global => Ok(Item::Global(GlobalItem::decode(tag, data)?)),
+            ItemType::Local => Ok(Item::Local(LocalItem::decode(tag, data)?)),
+        }
+    }
+}
+
+/// Splits the first item off `bytes` according to its size.
+/// Returns the item including its prefix and the remaining bytes.
+pub fn split_item(bytes: &[u8]) -> Result<(&[u8], &[u8]), DecodeError> {
+    let length = match bytes {
+        [] => {
+            return Err(DecodeError::Truncated {
+                expected: 1,
+                actual: 0,
+            })
+        }
+        [LONG_ITEM_PREFIX, data_size, ..] => 3 + *data_size as usize,
+        [LONG_ITEM_PREFIX] => 3,
+        [prefix, ..] => match prefix & 0x03 {
+            3 => 5,
+            size => 1 + size as usize,
+        },
+    };
+    if bytes.len() < length {
+        return Err(DecodeError::Truncated {
+            expected: length,
+            actual: bytes.len(),
+        });
+    }
+    Ok(bytes.split_at(length))
+}
+
+/// Encodes the prefix byte followed by 0, 1, 2 or 4 data bytes
+pub fn short_item(item_type: ItemType, tag: u8, data: &[u8]) -> Result<Vec<u8>, Error> {
+    let size = match data.len() {
+        0 => 0,
+        1 => 1,
+        2 => 2,
+        4 => 3,
+        length => {
+            return Err(Error::LengthOverflow {
+                descriptor: DescriptorType::Report,
+                field: "bSize",
+                length,
+            })
+        }
+    };
+    let mut bytes = vec![tag << 4 | item_type.encode()? << 2 | size];
+    bytes.extend_from_slice(data);
+    Ok(bytes)
+}
+
+/// The shortest little endian encoding of an unsigned item value
+pub fn unsigned_data(value: u32) -> Vec<u8> {
+    let length = match value {
+        0 => 0,
+        0x01..=0xFF => 1,
+        0x0100..=0xFFFF => 2,
+        _ => 4,
+    };
+    value.to_le_bytes()[..length].to_vec()
+}
+
+/// The shortest little endian two's complement encoding of a signed item value
+pub fn signed_data(value: i32) -> Vec<u8> {
+    let length = if value == 0 {
+        0
+    } else if i8::try_from(value).is_ok() {
+        1
+    } else if i16::try_from(value).is_ok() {
+        2
+    } else {
+        4
+    };
+    value.to_le_bytes()[..length].to_vec()
+}
+
+/// Zero extends the little endian data of a short item
+pub fn decode_unsigned(data: &[u8]) -> u32 {
+    let mut bytes = [0; 4];
+    bytes[..data.len()].copy_from_slice(data);
+    u32::from_le_bytes(bytes)
+}
+
+/// Sign extends the little endian two's complement data of a short item
+pub fn decode_signed(data: &[u8]) -> i32 {
+    match data.len() {
+        0 => 0,
+        1 => data[0] as i8 as i32,
+        2 => i16::from_le_bytes([data[0], data[1]]) as i32,
+        _ => decode_unsigned(data) as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{collection_type::CollectionType, data_flags::DataFlags};
+
+    use super::*;
+
+    #[test]
+    fn test_data_size() {
+        assert_eq!(unsigned_data(0), vec![]);
+        assert_eq!(unsigned_data(0x7F), vec![0x7F]);
+        assert_eq!(unsigned_data(0x0100), vec![0x00, 0x01]);
+        assert_eq!(unsigned_data(0x0001_0000), vec![0x00, 0x00, 0x01, 0x00]);
+        assert_eq!(signed_data(-127), vec![0x81]);
+        assert_eq!(signed_data(255), vec![0xFF, 0x00]);
+        assert_eq!(signed_data(-32769), vec![0xFF, 0x7F, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_encode() {
+        let variable = DataFlags {
+            variable: true,
+            ..Default::default()
+        };
+        let items = [
+            (Item::Global(GlobalItem::UsagePage(0x01)), vec![0x05, 0x01]),
+            (Item::Local(LocalItem::Usage(0x02)), vec![0x09, 0x02]),
+            (
+                Item::Main(MainItem::Collection(CollectionType::Application)),
+                vec![0xA1, 0x01],
+            ),
+            (Item::Global(GlobalItem::LogicalMinimum(0)), vec![0x14]),
+            (
+                Item::Global(GlobalItem::LogicalMaximum(255)),
+                vec![0x26, 0xFF, 0x00],
+            ),
+            (Item::Global(GlobalItem::UnitExponent(-2)), vec![0x55, 0x0E]),
+            (Item::Main(MainItem::Input(variable)), vec![0x81, 0x02]),
+            (
+                Item::Main(MainItem::Feature(DataFlags {
+                    buffered_bytes: true,
+                    ..variable
+                })),
+                vec![0xB2, 0x02, 0x01],
+            ),
+            (
+                Item::Local(LocalItem::Usage(0x000C_00E9)),
+                vec![0x0B, 0xE9, 0x00, 0x0C, 0x00],
+            ),
+            (
+                Item::Local(LocalItem::Delimiter { open: true }),
+                vec![0xA9, 0x01],
+            ),
+            (Item::Global(GlobalItem::Push), vec![0xA4]),
+            (Item::Main(MainItem::EndCollection), vec![0xC0]),
+            (
+                Item::Long {
+                    tag: 0xF0,
+                    data: vec![0x01, 0x02],
+                },
+                vec![0xFE, 0x02, 0xF0, 0x01, 0x02],
+            ),
+        ];
+        for (item, bytes) in items {
+            assert_eq!(item.encode().unwrap(), bytes);
+            assert_eq!(Item::decode(&bytes), Ok(item));
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            Item::decode(&[0x26, 0xFF]),
+            Err(DecodeError::Truncated {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Item::decode(&[0x0C]),
+            Err(DecodeError::InvalidValue {
+                field: "bType",
+                value: 0x03
+            })
+        );
+        assert_eq!(
+            Item::decode(&[0x85, 0x00]),
+            Err(DecodeError::InvalidValue {
+                field: "Report ID",
+                value: 0
+            })
+        );
+        assert_eq!(decode_signed(&[0x81]), -127);
+        assert_eq!(decode_unsigned(&[0x81]), 0x81);
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        assert_eq!(
+            Item::Global(GlobalItem::ReportId(0)).encode(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Report,
+                field: "Report ID",
+                value: 0
+            })
+        );
+        assert_eq!(
+            Item::Global(GlobalItem::UnitExponent(8)).encode(),
+            Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Report,
+                field: "Unit Exponent",
+                value: 8
+            })
+        );
+        let volatile = DataFlags {
+            volatile: true,
+            ..Default::default()
+        };
+        assert!(Item::Main(MainItem::Input(volatile)).encode().is_err());
+        assert_eq!(
+            Item::Main(MainItem::Output(volatile)).encode().unwrap(),
+            vec![0x91, 0x80]
+        );
+        assert_eq!(
+            Item::Long {
+                tag: 0xF0,
+                data: vec![0; 256]
+            }
+            .encode(),
+            Err(Error::LengthOverflow {
+                descriptor: DescriptorType::Report,
+                field: "bDataSize",
+                length: 256
+            })
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+/// `bType` of a short item
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ItemType {
+    Main,
+    Global,
+    Local,
+}
+
+impl EncodeByte for ItemType {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            ItemType::Main => Ok(0x00),
+            ItemType::Global => Ok(0x01),
+            ItemType::Local => Ok(0x02),
+        }
+    }
+}
+
+impl DecodeByte for ItemType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(ItemType::Main),
+            0x01 => Ok(ItemType::Global),
+            0x02 => Ok(ItemType::Local),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bType",
+                value: byte as u16,
+            }),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use crate::error::{DecodeError, Error};
+
+use super::item::{decode_unsigned, unsigned_data};
+
+/// Usages above 0xFFFF are extended usages with the usage page in the upper 16 bits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LocalItem {
+    Usage(u32),
+    UsageMinimum(u32),
+    UsageMaximum(u32),
+    DesignatorIndex(u32),
+    DesignatorMinimum(u32),
+    DesignatorMaximum(u32),
+    StringIndex(u32),
+    StringMinimum(u32),
+    StringMaximum(u32),
+    /// Opens a set of alternative usages if `open`, closes it otherwise
+    Delimiter {
+        open: bool,
+    },
+}
+
+impl LocalItem {
+    pub fn tag(&self) -> u8 {
+        match self {
+            LocalItem::Usage(_) => 0x00,
+            LocalItem::UsageMinimum(_) => 0x01,
+            LocalItem::UsageMaximum(_) => 0x02,
+            LocalItem::DesignatorIndex(_) => 0x03,
+            LocalItem::DesignatorMinimum(_) => 0x04,
+            LocalItem::DesignatorMaximum(_) => 0x05,
+            LocalItem::StringIndex(_) => 0x07,
+            LocalItem::StringMinimum(_) => 0x08,
+            LocalItem::StringMaximum(_) => 0x09,
+            LocalItem::Delimiter { .. } => 0x0A,
+        }
+    }
+
+    pub fn data(&self) -> Result<Vec<u8>, Error> {
+        match *self {
+            LocalItem::Usage(value)
+            | LocalItem::UsageMinimum(value)
+            | LocalItem::UsageMaximum(value)
+            | LocalItem::DesignatorIndex(value)
+            | LocalItem::DesignatorMinimum(value)
+            | LocalItem::DesignatorMaximum(value)
+            | LocalItem::StringIndex(value)
+            | LocalItem::StringMinimum(value)
+            | LocalItem::StringMaximum(value) => Ok(unsigned_data(value)),
+            LocalItem::Delimiter { open } => Ok(unsigned_data(open as u32)),
+        }
+    }
+
+    pub fn decode(tag: u8, data: &[u8]) -> Result<LocalItem, DecodeError> {
+        let value = decode_unsigned(data);
+        match tag {
+            0x00 => Ok(LocalItem::Usage(value)),
+            0x01 => Ok(LocalItem::UsageMinimum(value)),
+            0x02 => Ok(LocalItem::UsageMaximum(value)),
+            0x03 => Ok(LocalItem::DesignatorIndex(value)),
+            0x04 => Ok(LocalItem::DesignatorMinimum(value)),
+            0x05 => Ok(LocalItem::DesignatorMaximum(value)),
+            0x07 => Ok(LocalItem::StringIndex(value)),
+            0x08 => Ok(LocalItem::StringMinimum(value)),
+            0x09 => Ok(LocalItem::StringMaximum(value)),
+            0x0A => match value {
+                0 | 1 => Ok(LocalItem::Delimiter { open: value == 1 }),
+                _ => Err(DecodeError::InvalidValue {
+                    field: "Delimiter",
+                    value: value as u16,
+                }),
+            },
+            _ => Err(DecodeError::InvalidValue {
+                field: "bTag",
+                value: tag as u16,
+            }),
+        }
+    }
+}
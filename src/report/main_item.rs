@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+use super::{
+    collection_type::CollectionType,
+    data_flags::DataFlags,
+    item::{decode_unsigned, unsigned_data},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MainItem {
+    Input(DataFlags),
+    Output(DataFlags),
+    Feature(DataFlags),
+    Collection(CollectionType),
+    EndCollection,
+}
+
+impl MainItem {
+    pub fn tag(&self) -> u8 {
+        match self {
+            MainItem::Input(_) => 0x08,
+            MainItem::Output(_) => 0x09,
+            MainItem::Collection(_) => 0x0A,
+            MainItem::Feature(_) => 0x0B,
+            MainItem::EndCollection => 0x0C,
+        }
+    }
+
+    pub fn data(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            MainItem::Input(flags) if flags.volatile => Err(Error::FieldOutOfRange {
+                descriptor: DescriptorType::Report,
+                field: "Input",
+                value: flags.bits(),
+            }),
+            MainItem::Input(flags) | MainItem::Output(flags) | MainItem::Feature(flags) => {
+                Ok(unsigned_data(flags.bits()))
+            }
+            MainItem::Collection(collection_type) => {
+                Ok(unsigned_data(collection_type.encode()? as u32))
+            }
+            MainItem::EndCollection => Ok(Vec::new()),
+        }
+    }
+
+    pub fn decode(tag: u8, data: &[u8]) -> Result<MainItem, DecodeError> {
+        let value = decode_unsigned(data);
+        match tag {
+            0x08 => Ok(MainItem::Input(DataFlags::from_bits(value))),
+            0x09 => Ok(MainItem::Output(DataFlags::from_bits(value))),
+            0x0A => match u8::try_from(value) {
+                Ok(byte) => Ok(MainItem::Collection(CollectionType::decode(byte)?)),
+                Err(_) => Err(DecodeError::InvalidValue {
+                    field: "Collection",
+                    value: value as u16,
+                }),
+            },
+            0x0B => Ok(MainItem::Feature(DataFlags::from_bits(value))),
+            0x0C => Ok(MainItem::EndCollection),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bTag",
+                value: tag as u16,
+            }),
+        }
+    }
+}
@@ -1,2 +1,20 @@
-// pub mod report_builder;
-// pub mod report_descriptor;
\ No newline at end of file
+pub mod collection_type;
+pub mod data_flags;
+pub mod global_item;
+pub mod hid_report;
+pub mod item;
+pub mod item_type;
+pub mod local_item;
+pub mod main_item;
+pub mod report_builder;
+pub mod report_collection;
+pub mod report_descriptor;
+pub mod report_field;
+pub mod report_kind;
+pub mod report_layout;
+pub mod report_struct;
+pub mod report_struct_field;
+pub mod struct_usages;
+pub mod usage;
+pub mod usage_page;
+pub mod usage_tables;
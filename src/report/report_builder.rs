@@ -1,2 +1,107 @@
+use alloc::vec::Vec;
+
+use crate::{
+    binary::{DecodeBytes, EncodeBytes},
+    descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
+};
+
+use super::{
+    item::{split_item, Item},
+    main_item::MainItem,
+    report_descriptor::ReportDescriptor,
+};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ReportBuilder {}
\ No newline at end of file
+pub struct ReportBuilder {
+    /// Turns into the report items
+    pub items: Vec<Item>,
+}
+
+impl ReportBuilder {
+    pub fn build(&self) -> Result<ReportDescriptor, Error> {
+        let mut depth = 0usize;
+        let mut items = Vec::<u8>::new();
+        for item in self.items.iter() {
+            match item {
+                Item::Main(MainItem::Collection(_)) => depth += 1,
+                Item::Main(MainItem::EndCollection) => {
+                    depth = depth.checked_sub(1).ok_or(Error::Missing {
+                        descriptor: DescriptorType::Report,
+                        field: "Collection",
+                    })?;
+                }
+                _ => {}
+            }
+            items.append(&mut item.encode()?);
+        }
+        if depth != 0 {
+            return Err(Error::Missing {
+                descriptor: DescriptorType::Report,
+                field: "End Collection",
+            });
+        }
+
+        Ok(ReportDescriptor { items })
+    }
+
+    /// Decodes the items of an encoded report descriptor
+    pub fn decode(bytes: &[u8]) -> Result<ReportBuilder, DecodeError> {
+        let mut items = Vec::<Item>::new();
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let (item, next) = split_item(rest)?;
+            items.push(Item::decode(item)?);
+            rest = next;
+        }
+        Ok(ReportBuilder { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{
+        collection_type::CollectionType, data_flags::DataFlags, global_item::GlobalItem,
+        local_item::LocalItem,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let builder = ReportBuilder {
+            items: vec![
+                Item::Global(GlobalItem::UsagePage(0x01)),
+                Item::Local(LocalItem::Usage(0x02)),
+                Item::Main(MainItem::Collection(CollectionType::Application)),
+                Item::Global(GlobalItem::ReportSize(8)),
+                Item::Global(GlobalItem::ReportCount(1)),
+                Item::Main(MainItem::Input(DataFlags::default())),
+                Item::Main(MainItem::EndCollection),
+            ],
+        };
+        assert_eq!(
+            builder.build().unwrap().items,
+            vec![0x05, 0x01, 0x09, 0x02, 0xA1, 0x01, 0x75, 0x08, 0x95, 0x01, 0x80, 0xC0]
+        );
+
+        let mut unbalanced = builder.clone();
+        unbalanced.items.pop();
+        assert_eq!(
+            unbalanced.build(),
+            Err(Error::Missing {
+                descriptor: DescriptorType::Report,
+                field: "End Collection"
+            })
+        );
+        unbalanced.items.remove(2);
+        unbalanced.items.push(Item::Main(MainItem::EndCollection));
+        assert_eq!(
+            unbalanced.build(),
+            Err(Error::Missing {
+                descriptor: DescriptorType::Report,
+                field: "Collection"
+            })
+        );
+    }
+}
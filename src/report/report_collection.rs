@@ -0,0 +1,12 @@
+use alloc::vec::Vec;
+
+use super::{collection_type::CollectionType, report_field::ReportField};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportCollection {
+    pub collection_type: CollectionType,
+    /// Extended usage of the collection
+    pub usage: Option<u32>,
+    pub collections: Vec<ReportCollection>,
+    pub fields: Vec<ReportField>,
+}
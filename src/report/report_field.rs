@@ -0,0 +1,89 @@
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+
+use super::{data_flags::DataFlags, report_kind::ReportKind, usage::Usage};
+
+/// The bits one Input, Output or Feature item occupies in its report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportField {
+    pub kind: ReportKind,
+    /// 0 if the report descriptor does not use report IDs
+    pub report_id: u8,
+    /// Offset of the first element in bits, counted after the report ID byte
+    pub bit_offset: u32,
+    /// `Report Size` of one element in bits
+    pub size: u32,
+    /// `Report Count`, the number of elements
+    pub count: u32,
+    pub flags: DataFlags,
+    /// Extended usages (usage page in the upper 16 bits). One per element of a variable field,
+    /// the last one repeats. The usages an array field selects from.
+    pub usages: Vec<u32>,
+    pub logical_minimum: i32,
+    pub logical_maximum: i32,
+}
+
+impl ReportField {
+    /// The usage of element `index` of a variable field
+    pub fn usage(&self, index: u32) -> Option<u32> {
+        self.usages
+            .get(index as usize)
+            .or(self.usages.last())
+            .copied()
+    }
+
+    /// Reads element `index` from a report. `report` starts with the report ID byte if the
+    /// descriptor uses report IDs. Values are sign extended if the logical minimum is negative.
+    pub fn value(&self, report: &[u8], index: u32) -> Option<i32> {
+        if index >= self.count || self.size == 0 || self.size > 32 {
+            return None;
+        }
+        let data = if self.report_id == 0 {
+            report
+        } else {
+            report.get(1..)?
+        };
+
+        let offset = self.bit_offset as u64 + index as u64 * self.size as u64;
+        let mut value = 0u64;
+        for bit in 0..self.size as u64 {
+            let position = offset + bit;
+            let byte = data.get((position / 8) as usize)?;
+            value |= ((*byte as u64 >> (position % 8)) & 1) << bit;
+        }
+
+        if self.logical_minimum < 0 && value & (1 << (self.size - 1)) != 0 {
+            value |= u64::MAX << self.size;
+        }
+        Some(value as i64 as i32)
+    }
+}
+
+impl Display for ReportField {
+    /// One line like `Input bits 8..24 (2 x 8) [-127, 127]: Generic Desktop: X, Generic Desktop: Y`
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.kind)?;
+        if self.report_id != 0 {
+            write!(f, " {}", self.report_id)?;
+        }
+        write!(
+            f,
+            " bits {}..{} ({} x {}) [{}, {}]",
+            self.bit_offset,
+            self.bit_offset as u64 + self.count as u64 * self.size as u64,
+            self.count,
+            self.size,
+            self.logical_minimum,
+            self.logical_maximum
+        )?;
+        if self.flags.constant {
+            return write!(f, ": Constant");
+        }
+        for (index, usage) in self.usages.iter().enumerate() {
+            let separator = if index == 0 { ": " } else { ", " };
+            write!(f, "{}{}", separator, Usage::from_extended(*usage))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+/// The report a data field is part of
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReportKind {
+    Input,
+    Output,
+    Feature,
+}
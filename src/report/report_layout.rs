@@ -0,0 +1,519 @@
+use alloc::vec::Vec;
+
+use crate::{binary::DecodeBytes, error::DecodeError};
+
+use super::{
+    data_flags::DataFlags,
+    global_item::GlobalItem,
+    item::{split_item, Item},
+    local_item::LocalItem,
+    main_item::MainItem,
+    report_collection::ReportCollection,
+    report_field::ReportField,
+    report_kind::ReportKind,
+};
+
+/// Usage ranges larger than this are rejected instead of being expanded
+const MAX_USAGE_RANGE: u32 = 0x10000;
+
+/// The collection tree of a report descriptor with the position of every field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportLayout {
+    pub collections: Vec<ReportCollection>,
+    /// Fields outside of any collection
+    pub fields: Vec<ReportField>,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+struct GlobalState {
+    usage_page: u16,
+    logical_minimum: i32,
+    logical_maximum: i32,
+    report_size: u32,
+    report_id: u8,
+    report_count: u32,
+}
+
+/// A usage as written in the Usage item, `true` if it is a 32 bit extended usage
+type LocalUsage = (u32, bool);
+
+#[derive(Debug, Default)]
+struct LocalState {
+    usages: Vec<LocalUsage>,
+    usage_minimum: Option<LocalUsage>,
+    /// Inside a delimiter only the first usage is used, `true` once it is taken
+    delimiter: Option<bool>,
+}
+
+impl LocalState {
+    fn add_usage(&mut self, usage: LocalUsage) {
+        match self.delimiter {
+            Some(true) => {}
+            Some(false) => {
+                self.usages.push(usage);
+                self.delimiter = Some(true);
+            }
+            None => self.usages.push(usage),
+        }
+    }
+
+    /// Short usages are on the usage page in effect at the main item
+    fn resolve(self, usage_page: u16) -> Vec<u32> {
+        self.usages
+            .into_iter()
+            .map(|(usage, extended)| match extended {
+                true => usage,
+                false => (usage_page as u32) << 16 | usage,
+            })
+            .collect()
+    }
+}
+
+/// State while walking the items of a report descriptor
+#[derive(Debug, Default)]
+struct Parser {
+    collections: Vec<ReportCollection>,
+    fields: Vec<ReportField>,
+    /// Open collections, innermost last
+    open: Vec<ReportCollection>,
+    global: GlobalState,
+    global_stack: Vec<GlobalState>,
+    local: LocalState,
+    /// Next free bit per report
+    offsets: Vec<(ReportKind, u8, u32)>,
+}
+
+impl Parser {
+    fn global_item(&mut self, item: GlobalItem) -> Result<(), DecodeError> {
+        let global = &mut self.global;
+        match item {
+            GlobalItem::UsagePage(page) => global.usage_page = page,
+            GlobalItem::LogicalMinimum(value) => global.logical_minimum = value,
+            GlobalItem::LogicalMaximum(value) => global.logical_maximum = value,
+            GlobalItem::ReportSize(size) => global.report_size = size,
+            GlobalItem::ReportId(id) => global.report_id = id,
+            GlobalItem::ReportCount(count) => global.report_count = count,
+            GlobalItem::Push => self.global_stack.push(*global),
+            GlobalItem::Pop => {
+                *global = self.global_stack.pop().ok_or(DecodeError::InvalidValue {
+                    field: "Pop",
+                    value: 0,
+                })?;
+            }
+            GlobalItem::PhysicalMinimum(_)
+            | GlobalItem::PhysicalMaximum(_)
+            | GlobalItem::UnitExponent(_)
+            | GlobalItem::Unit(_) => {}
+        }
+        Ok(())
+    }
+
+    /// `extended` is `true` for items with 4 data bytes (`bSize` 3)
+    fn local_item(&mut self, item: LocalItem, extended: bool) -> Result<(), DecodeError> {
+        let local = &mut self.local;
+        match item {
+            LocalItem::Usage(usage) => local.add_usage((usage, extended)),
+            LocalItem::UsageMinimum(usage) => local.usage_minimum = Some((usage, extended)),
+            LocalItem::UsageMaximum(maximum) => {
+                let error = DecodeError::InvalidValue {
+                    field: "Usage Maximum",
+                    value: maximum as u16,
+                };
+                // both ends are either short or extended usages
+                let minimum = match local.usage_minimum.take() {
+                    Some((minimum, minimum_extended)) if minimum_extended == extended => minimum,
+                    _ => return Err(error),
+                };
+                if maximum < minimum || maximum - minimum >= MAX_USAGE_RANGE {
+                    return Err(error);
+                }
+                for usage in minimum..=maximum {
+                    local.add_usage((usage, extended));
+                }
+            }
+            LocalItem::Delimiter { open } => {
+                local.delimiter = match (open, local.delimiter) {
+                    (true, None) => Some(false),
+                    (false, Some(_)) => None,
+                    _ => {
+                        return Err(DecodeError::InvalidValue {
+                            field: "Delimiter",
+                            value: open as u16,
+                        })
+                    }
+                };
+            }
+            LocalItem::DesignatorIndex(_)
+            | LocalItem::DesignatorMinimum(_)
+            | LocalItem::DesignatorMaximum(_)
+            | LocalItem::StringIndex(_)
+            | LocalItem::StringMinimum(_)
+            | LocalItem::StringMaximum(_) => {}
+        }
+        Ok(())
+    }
+
+    fn main_item(&mut self, item: MainItem) -> Result<(), DecodeError> {
+        let local = core::mem::take(&mut self.local);
+        if let Some((minimum, _)) = local.usage_minimum {
+            return Err(DecodeError::InvalidValue {
+                field: "Usage Minimum",
+                value: minimum as u16,
+            });
+        }
+        let usages = local.resolve(self.global.usage_page);
+        match item {
+            MainItem::Input(flags) => self.add_field(ReportKind::Input, flags, usages),
+            MainItem::Output(flags) => self.add_field(ReportKind::Output, flags, usages),
+            MainItem::Feature(flags) => self.add_field(ReportKind::Feature, flags, usages),
+            MainItem::Collection(collection_type) => {
+                self.open.push(ReportCollection {
+                    collection_type,
+                    usage: usages.first().copied(),
+                    collections: Vec::new(),
+                    fields: Vec::new(),
+                });
+                Ok(())
+            }
+            MainItem::EndCollection => {
+                let collection = self.open.pop().ok_or(DecodeError::InvalidValue {
+                    field: "End Collection",
+                    value: 0,
+                })?;
+                match self.open.last_mut() {
+                    Some(parent) => parent.collections.push(collection),
+                    None => self.collections.push(collection),
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn add_field(
+        &mut self,
+        kind: ReportKind,
+        flags: DataFlags,
+        usages: Vec<u32>,
+    ) -> Result<(), DecodeError> {
+        let global = self.global;
+        let length = global.report_size.checked_mul(global.report_count).ok_or(
+            DecodeError::InvalidValue {
+                field: "Report Count",
+                value: global.report_count as u16,
+            },
+        )?;
+        let index = match self
+            .offsets
+            .iter()
+            .position(|(k, id, _)| *k == kind && *id == global.report_id)
+        {
+            Some(index) => index,
+            None => {
+                self.offsets.push((kind, global.report_id, 0));
+                self.offsets.len() - 1
+            }
+        };
+        let (_, _, offset) = &mut self.offsets[index];
+        let bit_offset = *offset;
+        *offset = offset
+            .checked_add(length)
+            .ok_or(DecodeError::InvalidValue {
+                field: "Report Count",
+                value: global.report_count as u16,
+            })?;
+
+        let field = ReportField {
+            kind,
+            report_id: global.report_id,
+            bit_offset,
+            size: global.report_size,
+            count: global.report_count,
+            flags,
+            usages,
+            logical_minimum: global.logical_minimum,
+            logical_maximum: global.logical_maximum,
+        };
+        match self.open.last_mut() {
+            Some(collection) => collection.fields.push(field),
+            None => self.fields.push(field),
+        }
+        Ok(())
+    }
+
+    fn item(&mut self, item: &Item, extended: bool) -> Result<(), DecodeError> {
+        match item {
+            Item::Main(item) => self.main_item(*item),
+            Item::Global(item) => self.global_item(*item),
+            Item::Local(item) => self.local_item(*item, extended),
+            Item::Long { .. } => Ok(()),
+        }
+    }
+
+    fn finish(self) -> Result<ReportLayout, DecodeError> {
+        if !self.open.is_empty() {
+            return Err(DecodeError::InvalidValue {
+                field: "Collection",
+                value: self.open.len() as u16,
+            });
+        }
+        Ok(ReportLayout {
+            collections: self.collections,
+            fields: self.fields,
+        })
+    }
+}
+
+impl ReportLayout {
+    /// Decodes and parses an encoded report descriptor
+    pub fn decode(bytes: &[u8]) -> Result<ReportLayout, DecodeError> {
+        let mut parser = Parser::default();
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let (item, next) = split_item(rest)?;
+            parser.item(&Item::decode(item)?, item[0] & 0x03 == 3)?;
+            rest = next;
+        }
+        parser.finish()
+    }
+
+    /// Parses items as they would be encoded, so only usages above 0xFFFF are extended
+    pub fn parse(items: &[Item]) -> Result<ReportLayout, DecodeError> {
+        let mut parser = Parser::default();
+        for item in items {
+            let extended = match item {
+                Item::Local(local) => local.data().is_ok_and(|data| data.len() == 4),
+                _ => false,
+            };
+            parser.item(item, extended)?;
+        }
+        parser.finish()
+    }
+
+    /// All fields of one report ordered by bit offset
+    pub fn report_fields(&self, kind: ReportKind, report_id: u8) -> Vec<&ReportField> {
+        let mut fields = Vec::<&ReportField>::new();
+        let mut pending = self.collections.iter().collect::<Vec<&ReportCollection>>();
+        fields.extend(self.fields.iter());
+        while let Some(collection) = pending.pop() {
+            fields.extend(collection.fields.iter());
+            pending.extend(collection.collections.iter());
+        }
+        fields.retain(|field| field.kind == kind && field.report_id == report_id);
+        fields.sort_by_key(|field| field.bit_offset);
+        fields
+    }
+
+    /// Length of a report in bytes, including the report ID byte if report IDs are used
+    pub fn report_length(&self, kind: ReportKind, report_id: u8) -> usize {
+        let bits = self
+            .report_fields(kind, report_id)
+            .iter()
+            .map(|field| field.bit_offset as usize + (field.size * field.count) as usize)
+            .max()
+            .unwrap_or(0);
+        bits.div_ceil(8) + (report_id != 0) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use crate::report::{collection_type::CollectionType, report_builder::ReportBuilder};
+
+    use super::*;
+
+    const MOUSE: [u8; 50] = [
+        0x05, 0x01, // Usage Page (Generic Desktop)
+        0x09, 0x02, // Usage (Mouse)
+        0xA1, 0x01, // Collection (Application)
+        0x09, 0x01, //   Usage (Pointer)
+        0xA1, 0x00, //   Collection (Physical)
+        0x05, 0x09, //     Usage Page (Button)
+        0x19, 0x01, //     Usage Minimum (1)
+        0x29, 0x03, //     Usage Maximum (3)
+        0x15, 0x00, //     Logical Minimum (0)
+        0x25, 0x01, //     Logical Maximum (1)
+        0x95, 0x03, //     Report Count (3)
+        0x75, 0x01, //     Report Size (1)
+        0x81, 0x02, //     Input (Data, Variable, Absolute)
+        0x95, 0x01, //     Report Count (1)
+        0x75, 0x05, //     Report Size (5)
+        0x81, 0x01, //     Input (Constant)
+        0x05, 0x01, //     Usage Page (Generic Desktop)
+        0x09, 0x30, //     Usage (X)
+        0x09, 0x31, //     Usage (Y)
+        0x15, 0x81, //     Logical Minimum (-127)
+        0x25, 0x7F, //     Logical Maximum (127)
+        0x75, 0x08, //     Report Size (8)
+        0x95, 0x02, //     Report Count (2)
+        0x81, 0x06, //     Input (Data, Variable, Relative)
+        0xC0, //   End Collection
+        0xC0, // End Collection
+    ];
+
+    #[test]
+    fn test_mouse_layout() {
+        let layout = ReportLayout::decode(&MOUSE).unwrap();
+        assert!(layout.fields.is_empty());
+        assert_eq!(layout.collections.len(), 1);
+        let application = &layout.collections[0];
+        assert_eq!(application.collection_type, CollectionType::Application);
+        assert_eq!(application.usage, Some(0x0001_0002));
+        assert_eq!(application.collections[0].usage, Some(0x0001_0001));
+
+        let fields = layout.report_fields(ReportKind::Input, 0);
+        let positions = fields
+            .iter()
+            .map(|field| (field.bit_offset, field.size, field.count))
+            .collect::<Vec<(u32, u32, u32)>>();
+        assert_eq!(positions, vec![(0, 1, 3), (3, 5, 1), (8, 8, 2)]);
+        assert_eq!(
+            fields[0].usages,
+            vec![0x0009_0001, 0x0009_0002, 0x0009_0003]
+        );
+        assert!(fields[1].flags.constant);
+        assert_eq!(fields[2].usage(1), Some(0x0001_0031));
+        assert_eq!(
+            fields[2].to_string(),
+            "Input bits 8..24 (2 x 8) [-127, 127]: Generic Desktop: X, Generic Desktop: Y"
+        );
+        assert_eq!(
+            fields[1].to_string(),
+            "Input bits 3..8 (1 x 5) [0, 1]: Constant"
+        );
+        assert_eq!(
+            (fields[2].logical_minimum, fields[2].logical_maximum),
+            (-127, 127)
+        );
+        assert_eq!(layout.report_length(ReportKind::Input, 0), 3);
+        assert_eq!(layout.report_length(ReportKind::Output, 0), 0);
+
+        let report = [0x05, 0xFF, 0x02];
+        assert_eq!(fields[0].value(&report, 0), Some(1));
+        assert_eq!(fields[0].value(&report, 1), Some(0));
+        assert_eq!(fields[0].value(&report, 2), Some(1));
+        assert_eq!(fields[2].value(&report, 0), Some(-1));
+        assert_eq!(fields[2].value(&report, 1), Some(2));
+        assert_eq!(fields[2].value(&report, 2), None);
+    }
+
+    #[test]
+    fn test_report_ids() {
+        let layout = ReportLayout::decode(&[
+            0x05, 0x0C, // Usage Page (Consumer)
+            0x09, 0x01, // Usage (Consumer Control)
+            0xA1, 0x01, // Collection (Application)
+            0x85, 0x01, //   Report ID (1)
+            0xA4, //   Push
+            0x15, 0x00, //   Logical Minimum (0)
+            0x26, 0xFF, 0x03, //   Logical Maximum (1023)
+            0x19, 0x00, //   Usage Minimum (0)
+            0x2A, 0xFF, 0x03, //   Usage Maximum (1023)
+            0x75, 0x10, //   Report Size (16)
+            0x95, 0x01, //   Report Count (1)
+            0x80, //   Input (Data, Array, Absolute)
+            0xB4, //   Pop
+            0x85, 0x02, //   Report ID (2)
+            0x0B, 0x30, 0x00, 0x01, 0x00, //   Usage (Generic Desktop X)
+            0x75, 0x08, //   Report Size (8)
+            0x95, 0x01, //   Report Count (1)
+            0xB1, 0x02, //   Feature (Data, Variable, Absolute)
+            0xC0, // End Collection
+        ])
+        .unwrap();
+
+        let consumer = layout.report_fields(ReportKind::Input, 1);
+        assert_eq!(consumer.len(), 1);
+        assert_eq!(consumer[0].usages.len(), 1024);
+        assert_eq!(consumer[0].value(&[0x01, 0xE9, 0x00], 0), Some(0xE9));
+        assert_eq!(layout.report_length(ReportKind::Input, 1), 3);
+
+        let feature = layout.report_fields(ReportKind::Feature, 2);
+        assert_eq!(feature[0].bit_offset, 0);
+        assert_eq!(feature[0].usages, vec![0x0001_0030]);
+        // Logical range was restored by Pop
+        assert_eq!(feature[0].logical_maximum, 0);
+        assert_eq!(layout.report_length(ReportKind::Feature, 2), 2);
+    }
+
+    #[test]
+    fn test_usage_page_at_main_item() {
+        let bytes = [
+            0x09, 0x30, // Usage (X)
+            0x05, 0x01, // Usage Page (Generic Desktop)
+            0x0B, 0x38, 0x00, 0x00, 0x00, // Usage (0x0000_0038)
+            0x75, 0x08, // Report Size (8)
+            0x95, 0x02, // Report Count (2)
+            0x81, 0x02, // Input (Data, Variable, Absolute)
+        ];
+        let layout = ReportLayout::decode(&bytes).unwrap();
+        // the short usage takes the later page, the extended one keeps its own
+        assert_eq!(layout.fields[0].usages, vec![0x0001_0030, 0x0000_0038]);
+
+        // parsed items are extended only if they encode with 4 data bytes
+        let items = ReportBuilder::decode(&bytes).unwrap().items;
+        let layout = ReportLayout::parse(&items).unwrap();
+        assert_eq!(layout.fields[0].usages, vec![0x0001_0030, 0x0001_0038]);
+
+        assert_eq!(
+            ReportLayout::decode(&[0x19, 0x01, 0x2B, 0x03, 0x00, 0x09, 0x00]),
+            Err(DecodeError::InvalidValue {
+                field: "Usage Maximum",
+                value: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_unpaired_usage_range() {
+        // Usage Minimum (1) without Usage Maximum, Input
+        assert_eq!(
+            ReportLayout::decode(&[0x19, 0x01, 0x81, 0x02]),
+            Err(DecodeError::InvalidValue {
+                field: "Usage Minimum",
+                value: 1
+            })
+        );
+        // Usage Maximum (3) without Usage Minimum
+        assert_eq!(
+            ReportLayout::decode(&[0x29, 0x03, 0x81, 0x02]),
+            Err(DecodeError::InvalidValue {
+                field: "Usage Maximum",
+                value: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            ReportLayout::decode(&[0xA1, 0x01]),
+            Err(DecodeError::InvalidValue {
+                field: "Collection",
+                value: 1
+            })
+        );
+        assert_eq!(
+            ReportLayout::decode(&[0xC0]),
+            Err(DecodeError::InvalidValue {
+                field: "End Collection",
+                value: 0
+            })
+        );
+        assert_eq!(
+            ReportLayout::decode(&[0xB4]),
+            Err(DecodeError::InvalidValue {
+                field: "Pop",
+                value: 0
+            })
+        );
+        assert_eq!(
+            ReportLayout::decode(&[0x29, 0x03]),
+            Err(DecodeError::InvalidValue {
+                field: "Usage Maximum",
+                value: 3
+            })
+        );
+    }
+}
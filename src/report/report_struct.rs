@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+
+use super::{
+    collection_type::CollectionType, global_item::GlobalItem, item::Item, local_item::LocalItem,
+    main_item::MainItem, report_builder::ReportBuilder, report_kind::ReportKind,
+    report_struct_field::ReportStructField, struct_usages::StructUsages, usage::Usage,
+};
+
+/// One report laid out like the fields of a struct, without gaps between fields.
+/// Usually generated by `#[derive(HidReport)]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReportStruct {
+    pub kind: ReportKind,
+    /// Wraps the fields in an Application collection
+    pub application: Option<Usage>,
+    /// 0 if the report has no report ID
+    pub report_id: u8,
+    pub fields: &'static [ReportStructField],
+}
+
+impl ReportStruct {
+    /// Offset of field `index` in bits, counted after the report ID byte
+    pub fn bit_offset(&self, index: usize) -> u32 {
+        self.fields[..index].iter().map(|field| field.bits()).sum()
+    }
+
+    /// Length of the report in bytes, including the report ID byte
+    pub fn report_length(&self) -> usize {
+        let bits = self.bit_offset(self.fields.len()) as usize;
+        let id_length = if self.report_id == 0 { 0 } else { 1 };
+        bits.div_ceil(8) + id_length
+    }
+
+    /// The report descriptor items describing this report.
+    /// Global items are only repeated when their value changes, constant fields without
+    /// usages keep the logical range of the field before them.
+    pub fn report(&self) -> ReportBuilder {
+        let mut items = Vec::<Item>::new();
+        let mut usage_page = None::<u16>;
+        if let Some(application) = self.application {
+            items.push(application.page_item());
+            items.push(application.usage_item());
+            items.push(Item::Main(MainItem::Collection(
+                CollectionType::Application,
+            )));
+            usage_page = Some(application.page);
+        }
+        if self.report_id != 0 {
+            items.push(Item::Global(GlobalItem::ReportId(self.report_id)));
+        }
+
+        let mut globals = [None::<GlobalItem>; 4];
+        for field in self.fields.iter() {
+            let mut page_item = |usage: &Usage, items: &mut Vec<Item>| {
+                if usage_page != Some(usage.page) {
+                    items.push(usage.page_item());
+                    usage_page = Some(usage.page);
+                }
+            };
+            match field.usages {
+                StructUsages::None => {}
+                StructUsages::List(usages)
+                    if usages.iter().all(|usage| usage.page == usages[0].page) =>
+                {
+                    for usage in usages.iter() {
+                        page_item(usage, &mut items);
+                        items.push(usage.usage_item());
+                    }
+                }
+                // a Usage Page item between the usages would change the page of the ones before it
+                StructUsages::List(usages) => {
+                    for usage in usages.iter() {
+                        items.push(Item::Local(LocalItem::Usage(usage.extended())));
+                    }
+                }
+                StructUsages::Range { minimum, maximum } if minimum.page == maximum.page => {
+                    page_item(&minimum, &mut items);
+                    items.push(Item::Local(LocalItem::UsageMinimum(minimum.id as u32)));
+                    items.push(Item::Local(LocalItem::UsageMaximum(maximum.id as u32)));
+                }
+                StructUsages::Range { minimum, maximum } => {
+                    items.push(Item::Local(LocalItem::UsageMinimum(minimum.extended())));
+                    items.push(Item::Local(LocalItem::UsageMaximum(maximum.extended())));
+                }
+            }
+
+            let values = [
+                GlobalItem::LogicalMinimum(field.logical_minimum),
+                GlobalItem::LogicalMaximum(field.logical_maximum),
+                GlobalItem::ReportSize(field.size),
+                GlobalItem::ReportCount(field.count),
+            ];
+            let padding = field.flags.constant && field.usages == StructUsages::None;
+            for (index, (global, value)) in globals.iter_mut().zip(values).enumerate() {
+                if padding && index < 2 {
+                    continue;
+                }
+                if *global != Some(value) {
+                    items.push(Item::Global(value));
+                    *global = Some(value);
+                }
+            }
+
+            items.push(Item::Main(match self.kind {
+                ReportKind::Input => MainItem::Input(field.flags),
+                ReportKind::Output => MainItem::Output(field.flags),
+                ReportKind::Feature => MainItem::Feature(field.flags),
+            }));
+        }
+
+        if self.application.is_some() {
+            items.push(Item::Main(MainItem::EndCollection));
+        }
+        ReportBuilder { items }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{
+        data_flags::DataFlags,
+        global_item::GlobalItem,
+        usage_tables::{Consumer, GenericDesktop},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_usages_on_different_pages() {
+        let report = ReportStruct {
+            kind: ReportKind::Input,
+            application: Some(GenericDesktop::MOUSE),
+            report_id: 0,
+            fields: &[ReportStructField {
+                usages: StructUsages::List(&[GenericDesktop::WHEEL, Consumer::AC_PAN]),
+                size: 8,
+                count: 2,
+                logical_minimum: -127,
+                logical_maximum: 127,
+                flags: DataFlags {
+                    constant: false,
+                    variable: true,
+                    relative: true,
+                    wrap: false,
+                    non_linear: false,
+                    no_preferred: false,
+                    null_state: false,
+                    volatile: false,
+                    buffered_bytes: false,
+                },
+            }],
+        };
+        assert_eq!(
+            report.report().items[3..6],
+            [
+                Item::Local(LocalItem::Usage(0x0001_0038)),
+                Item::Local(LocalItem::Usage(0x000C_0238)),
+                Item::Global(GlobalItem::LogicalMinimum(-127)),
+            ]
+        );
+    }
+}
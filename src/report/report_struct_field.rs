@@ -0,0 +1,27 @@
+use super::{data_flags::DataFlags, struct_usages::StructUsages};
+
+/// One field of a `ReportStruct`, an array field has a `count` above 1
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReportStructField {
+    pub usages: StructUsages,
+    /// Turns into `Report Size`
+    pub size: u32,
+    /// Turns into `Report Count`
+    pub count: u32,
+    /// Turns into `Logical Minimum`. Values are sign extended if it is negative.
+    pub logical_minimum: i32,
+    /// Turns into `Logical Maximum`
+    pub logical_maximum: i32,
+    pub flags: DataFlags,
+}
+
+impl ReportStructField {
+    /// Size of all elements in bits
+    pub const fn bits(&self) -> u32 {
+        self.size * self.count
+    }
+
+    pub const fn signed(&self) -> bool {
+        self.logical_minimum < 0
+    }
+}
@@ -0,0 +1,12 @@
+use super::usage::Usage;
+
+/// The usages of a `ReportStructField`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StructUsages {
+    /// Padding and other constant fields
+    None,
+    /// One usage per element, the last one repeats
+    List(&'static [Usage]),
+    /// Turns into `Usage Minimum` and `Usage Maximum`
+    Range { minimum: Usage, maximum: Usage },
+}
@@ -0,0 +1,108 @@
+use core::fmt::Display;
+
+use super::{
+    global_item::GlobalItem,
+    item::Item,
+    local_item::LocalItem,
+    usage_page::UsagePage,
+    usage_tables::{
+        BatterySystem, Button, Consumer, Digitizer, GameControls, GenericDesktop, Haptics,
+        Keyboard, Led, PowerDevice, Sensor, Telephony,
+    },
+};
+
+/// A usage ID on a usage page
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Usage {
+    pub page: u16,
+    pub id: u16,
+}
+
+impl Usage {
+    /// The 32 bit form with the usage page in the upper 16 bits
+    pub const fn extended(&self) -> u32 {
+        (self.page as u32) << 16 | self.id as u32
+    }
+
+    pub const fn from_extended(usage: u32) -> Usage {
+        Usage {
+            page: (usage >> 16) as u16,
+            id: usage as u16,
+        }
+    }
+
+    /// Turns into `Usage Page`
+    pub fn page_item(&self) -> Item {
+        Item::Global(GlobalItem::UsagePage(self.page))
+    }
+
+    /// Turns into `Usage` on the current usage page
+    pub fn usage_item(&self) -> Item {
+        Item::Local(LocalItem::Usage(self.id as u32))
+    }
+
+    /// The name from the HID Usage Tables, if the usage is known
+    pub fn name(&self) -> Option<&'static str> {
+        match self.page {
+            GenericDesktop::PAGE => GenericDesktop::name(self.id),
+            GameControls::PAGE => GameControls::name(self.id),
+            Keyboard::PAGE => Keyboard::name(self.id),
+            Led::PAGE => Led::name(self.id),
+            Button::PAGE => Button::name(self.id),
+            Telephony::PAGE => Telephony::name(self.id),
+            Consumer::PAGE => Consumer::name(self.id),
+            Digitizer::PAGE => Digitizer::name(self.id),
+            Haptics::PAGE => Haptics::name(self.id),
+            Sensor::PAGE => Sensor::name(self.id),
+            PowerDevice::PAGE => PowerDevice::name(self.id),
+            BatterySystem::PAGE => BatterySystem::name(self.id),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match UsagePage::from_value(self.page).name() {
+            Some(page) => write!(f, "{}", page)?,
+            None => write!(f, "0x{:04X}", self.page)?,
+        }
+        match self.name() {
+            Some(name) => write!(f, ": {}", name),
+            None if self.page == Button::PAGE => write!(f, ": Button {}", self.id),
+            None => write!(f, ": 0x{:04X}", self.id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_usage() {
+        assert_eq!(GenericDesktop::X.extended(), 0x0001_0030);
+        assert_eq!(Usage::from_extended(0x0001_0030), GenericDesktop::X);
+        assert_eq!(GenericDesktop::X.to_string(), "Generic Desktop: X");
+        assert_eq!(Keyboard::A.to_string(), "Keyboard/Keypad: Keyboard a and A");
+        assert_eq!(Button::button(4).to_string(), "Button: Button 4");
+        assert_eq!(
+            Usage::from_extended(0xFF00_0001).to_string(),
+            "Vendor Defined: 0x0001"
+        );
+        assert_eq!(
+            Usage::from_extended(0x1234_0001).to_string(),
+            "0x1234: 0x0001"
+        );
+        assert_eq!(
+            Consumer::VOLUME_INCREMENT.page_item(),
+            Item::Global(GlobalItem::UsagePage(0x0C))
+        );
+        assert_eq!(
+            Consumer::VOLUME_INCREMENT.usage_item(),
+            Item::Local(LocalItem::Usage(0xE9))
+        );
+    }
+}
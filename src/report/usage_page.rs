@@ -0,0 +1,165 @@
+/// Usage pages of the HID Usage Tables
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UsagePage {
+    GenericDesktop,
+    Simulation,
+    Vr,
+    Sport,
+    Game,
+    GenericDevice,
+    Keyboard,
+    Led,
+    Button,
+    Ordinal,
+    Telephony,
+    Consumer,
+    Digitizer,
+    Haptics,
+    Pid,
+    Unicode,
+    EyeAndHeadTrackers,
+    AlphanumericDisplay,
+    Sensors,
+    MedicalInstruments,
+    BrailleDisplay,
+    LightingAndIllumination,
+    Monitor,
+    MonitorEnumerated,
+    VesaVirtualControls,
+    Power,
+    BatterySystem,
+    BarCodeScanner,
+    Scale,
+    MagneticStripeReader,
+    CameraControl,
+    Arcade,
+    GamingDevice,
+    FidoAlliance,
+    /// 0xFF00 to 0xFFFF
+    VendorDefined(u16),
+    Reserved(u16),
+}
+
+impl UsagePage {
+    pub fn value(&self) -> u16 {
+        match *self {
+            UsagePage::GenericDesktop => 0x01,
+            UsagePage::Simulation => 0x02,
+            UsagePage::Vr => 0x03,
+            UsagePage::Sport => 0x04,
+            UsagePage::Game => 0x05,
+            UsagePage::GenericDevice => 0x06,
+            UsagePage::Keyboard => 0x07,
+            UsagePage::Led => 0x08,
+            UsagePage::Button => 0x09,
+            UsagePage::Ordinal => 0x0A,
+            UsagePage::Telephony => 0x0B,
+            UsagePage::Consumer => 0x0C,
+            UsagePage::Digitizer => 0x0D,
+            UsagePage::Haptics => 0x0E,
+            UsagePage::Pid => 0x0F,
+            UsagePage::Unicode => 0x10,
+            UsagePage::EyeAndHeadTrackers => 0x12,
+            UsagePage::AlphanumericDisplay => 0x14,
+            UsagePage::Sensors => 0x20,
+            UsagePage::MedicalInstruments => 0x40,
+            UsagePage::BrailleDisplay => 0x41,
+            UsagePage::LightingAndIllumination => 0x59,
+            UsagePage::Monitor => 0x80,
+            UsagePage::MonitorEnumerated => 0x81,
+            UsagePage::VesaVirtualControls => 0x82,
+            UsagePage::Power => 0x84,
+            UsagePage::BatterySystem => 0x85,
+            UsagePage::BarCodeScanner => 0x8C,
+            UsagePage::Scale => 0x8D,
+            UsagePage::MagneticStripeReader => 0x8E,
+            UsagePage::CameraControl => 0x90,
+            UsagePage::Arcade => 0x91,
+            UsagePage::GamingDevice => 0x92,
+            UsagePage::FidoAlliance => 0xF1D0,
+            UsagePage::VendorDefined(page) | UsagePage::Reserved(page) => page,
+        }
+    }
+
+    pub fn from_value(page: u16) -> UsagePage {
+        match page {
+            0x01 => UsagePage::GenericDesktop,
+            0x02 => UsagePage::Simulation,
+            0x03 => UsagePage::Vr,
+            0x04 => UsagePage::Sport,
+            0x05 => UsagePage::Game,
+            0x06 => UsagePage::GenericDevice,
+            0x07 => UsagePage::Keyboard,
+            0x08 => UsagePage::Led,
+            0x09 => UsagePage::Button,
+            0x0A => UsagePage::Ordinal,
+            0x0B => UsagePage::Telephony,
+            0x0C => UsagePage::Consumer,
+            0x0D => UsagePage::Digitizer,
+            0x0E => UsagePage::Haptics,
+            0x0F => UsagePage::Pid,
+            0x10 => UsagePage::Unicode,
+            0x12 => UsagePage::EyeAndHeadTrackers,
+            0x14 => UsagePage::AlphanumericDisplay,
+            0x20 => UsagePage::Sensors,
+            0x40 => UsagePage::MedicalInstruments,
+            0x41 => UsagePage::BrailleDisplay,
+            0x59 => UsagePage::LightingAndIllumination,
+            0x80 => UsagePage::Monitor,
+            0x81 => UsagePage::MonitorEnumerated,
+            0x82 => UsagePage::VesaVirtualControls,
+            0x84 => UsagePage::Power,
+            0x85 => UsagePage::BatterySystem,
+            0x8C => UsagePage::BarCodeScanner,
+            0x8D => UsagePage::Scale,
+            0x8E => UsagePage::MagneticStripeReader,
+            0x90 => UsagePage::CameraControl,
+            0x91 => UsagePage::Arcade,
+            0x92 => UsagePage::GamingDevice,
+            0xF1D0 => UsagePage::FidoAlliance,
+            0xFF00..=0xFFFF => UsagePage::VendorDefined(page),
+            _ => UsagePage::Reserved(page),
+        }
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            UsagePage::GenericDesktop => Some("Generic Desktop"),
+            UsagePage::Simulation => Some("Simulation Controls"),
+            UsagePage::Vr => Some("VR Controls"),
+            UsagePage::Sport => Some("Sport Controls"),
+            UsagePage::Game => Some("Game Controls"),
+            UsagePage::GenericDevice => Some("Generic Device Controls"),
+            UsagePage::Keyboard => Some("Keyboard/Keypad"),
+            UsagePage::Led => Some("LED"),
+            UsagePage::Button => Some("Button"),
+            UsagePage::Ordinal => Some("Ordinal"),
+            UsagePage::Telephony => Some("Telephony Device"),
+            UsagePage::Consumer => Some("Consumer"),
+            UsagePage::Digitizer => Some("Digitizers"),
+            UsagePage::Haptics => Some("Haptics"),
+            UsagePage::Pid => Some("Physical Input Device"),
+            UsagePage::Unicode => Some("Unicode"),
+            UsagePage::EyeAndHeadTrackers => Some("Eye and Head Trackers"),
+            UsagePage::AlphanumericDisplay => Some("Auxiliary Display"),
+            UsagePage::Sensors => Some("Sensors"),
+            UsagePage::MedicalInstruments => Some("Medical Instrument"),
+            UsagePage::BrailleDisplay => Some("Braille Display"),
+            UsagePage::LightingAndIllumination => Some("Lighting And Illumination"),
+            UsagePage::Monitor => Some("Monitor"),
+            UsagePage::MonitorEnumerated => Some("Monitor Enumerated"),
+            UsagePage::VesaVirtualControls => Some("VESA Virtual Controls"),
+            UsagePage::Power => Some("Power"),
+            UsagePage::BatterySystem => Some("Battery System"),
+            UsagePage::BarCodeScanner => Some("Barcode Scanner"),
+            UsagePage::Scale => Some("Scales"),
+            UsagePage::MagneticStripeReader => Some("Magnetic Stripe Reader"),
+            UsagePage::CameraControl => Some("Camera Control"),
+            UsagePage::Arcade => Some("Arcade"),
+            UsagePage::GamingDevice => Some("Gaming Device"),
+            UsagePage::FidoAlliance => Some("FIDO Alliance"),
+            UsagePage::VendorDefined(_) => Some("Vendor Defined"),
+            UsagePage::Reserved(_) => None,
+        }
+    }
+}
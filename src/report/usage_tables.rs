@@ -0,0 +1,745 @@
+use super::usage::Usage;
+
+/// Declares a usage page with one `Usage` constant and one name per usage ID
+macro_rules! usage_table {
+    (
+        $(#[$meta:meta])*
+        $page:ident = $page_id:expr;
+        $($(#[$usage_meta:meta])* $usage:ident = $id:expr, $name:expr;)*
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub struct $page;
+
+        impl $page {
+            pub const PAGE: u16 = $page_id;
+            $($(#[$usage_meta])* pub const $usage: Usage = Usage { page: $page_id, id: $id };)*
+
+            /// The name of a usage ID on this page
+            pub fn name(id: u16) -> Option<&'static str> {
+                match id {
+                    $($id => Some($name),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+usage_table! {
+    /// Generic Desktop Page (0x01)
+    GenericDesktop = 0x01;
+    POINTER = 0x01, "Pointer";
+    MOUSE = 0x02, "Mouse";
+    JOYSTICK = 0x04, "Joystick";
+    GAMEPAD = 0x05, "Gamepad";
+    KEYBOARD = 0x06, "Keyboard";
+    KEYPAD = 0x07, "Keypad";
+    MULTI_AXIS_CONTROLLER = 0x08, "Multi-axis Controller";
+    TABLET_PC_SYSTEM_CONTROLS = 0x09, "Tablet PC System Controls";
+    WATER_COOLING_DEVICE = 0x0A, "Water Cooling Device";
+    COMPUTER_CHASSIS_DEVICE = 0x0B, "Computer Chassis Device";
+    WIRELESS_RADIO_CONTROLS = 0x0C, "Wireless Radio Controls";
+    PORTABLE_DEVICE_CONTROL = 0x0D, "Portable Device Control";
+    SYSTEM_MULTI_AXIS_CONTROLLER = 0x0E, "System Multi-Axis Controller";
+    SPATIAL_CONTROLLER = 0x0F, "Spatial Controller";
+    ASSISTIVE_CONTROL = 0x10, "Assistive Control";
+    DEVICE_DOCK = 0x11, "Device Dock";
+    DOCKABLE_DEVICE = 0x12, "Dockable Device";
+    CALL_STATE_MANAGEMENT_CONTROL = 0x13, "Call State Management Control";
+    X = 0x30, "X";
+    Y = 0x31, "Y";
+    Z = 0x32, "Z";
+    RX = 0x33, "Rx";
+    RY = 0x34, "Ry";
+    RZ = 0x35, "Rz";
+    SLIDER = 0x36, "Slider";
+    DIAL = 0x37, "Dial";
+    WHEEL = 0x38, "Wheel";
+    HAT_SWITCH = 0x39, "Hat Switch";
+    COUNTED_BUFFER = 0x3A, "Counted Buffer";
+    BYTE_COUNT = 0x3B, "Byte Count";
+    MOTION_WAKEUP = 0x3C, "Motion Wakeup";
+    START = 0x3D, "Start";
+    SELECT = 0x3E, "Select";
+    VX = 0x40, "Vx";
+    VY = 0x41, "Vy";
+    VZ = 0x42, "Vz";
+    VBRX = 0x43, "Vbrx";
+    VBRY = 0x44, "Vbry";
+    VBRZ = 0x45, "Vbrz";
+    VNO = 0x46, "Vno";
+    FEATURE_NOTIFICATION = 0x47, "Feature Notification";
+    RESOLUTION_MULTIPLIER = 0x48, "Resolution Multiplier";
+    QX = 0x49, "Qx";
+    QY = 0x4A, "Qy";
+    QZ = 0x4B, "Qz";
+    QW = 0x4C, "Qw";
+    SYSTEM_CONTROL = 0x80, "System Control";
+    SYSTEM_POWER_DOWN = 0x81, "System Power Down";
+    SYSTEM_SLEEP = 0x82, "System Sleep";
+    SYSTEM_WAKE_UP = 0x83, "System Wake Up";
+    SYSTEM_CONTEXT_MENU = 0x84, "System Context Menu";
+    SYSTEM_MAIN_MENU = 0x85, "System Main Menu";
+    SYSTEM_APP_MENU = 0x86, "System App Menu";
+    SYSTEM_MENU_HELP = 0x87, "System Menu Help";
+    SYSTEM_MENU_EXIT = 0x88, "System Menu Exit";
+    SYSTEM_MENU_SELECT = 0x89, "System Menu Select";
+    SYSTEM_MENU_RIGHT = 0x8A, "System Menu Right";
+    SYSTEM_MENU_LEFT = 0x8B, "System Menu Left";
+    SYSTEM_MENU_UP = 0x8C, "System Menu Up";
+    SYSTEM_MENU_DOWN = 0x8D, "System Menu Down";
+    SYSTEM_COLD_RESTART = 0x8E, "System Cold Restart";
+    SYSTEM_WARM_RESTART = 0x8F, "System Warm Restart";
+    D_PAD_UP = 0x90, "D-pad Up";
+    D_PAD_DOWN = 0x91, "D-pad Down";
+    D_PAD_RIGHT = 0x92, "D-pad Right";
+    D_PAD_LEFT = 0x93, "D-pad Left";
+    SYSTEM_DOCK = 0xA0, "System Dock";
+    SYSTEM_UNDOCK = 0xA1, "System Undock";
+    SYSTEM_SETUP = 0xA2, "System Setup";
+    SYSTEM_SPEAKER_MUTE = 0xA7, "System Speaker Mute";
+    SYSTEM_HIBERNATE = 0xA8, "System Hibernate";
+    SYSTEM_DISPLAY_INVERT = 0xB0, "System Display Invert";
+    SYSTEM_DISPLAY_INTERNAL = 0xB1, "System Display Internal";
+    SYSTEM_DISPLAY_EXTERNAL = 0xB2, "System Display External";
+    SYSTEM_DISPLAY_BOTH = 0xB3, "System Display Both";
+    SYSTEM_DISPLAY_DUAL = 0xB4, "System Display Dual";
+    SYSTEM_DISPLAY_TOGGLE = 0xB5, "System Display Toggle Int/Ext Mode";
+    WIRELESS_RADIO_BUTTON = 0xC6, "Wireless Radio Button";
+    WIRELESS_RADIO_LED = 0xC7, "Wireless Radio LED";
+    WIRELESS_RADIO_SLIDER_SWITCH = 0xC8, "Wireless Radio Slider Switch";
+}
+
+usage_table! {
+    /// Game Controls Page (0x05)
+    GameControls = 0x05;
+    GAME_CONTROLLER_3D = 0x01, "3D Game Controller";
+    PINBALL_DEVICE = 0x02, "Pinball Device";
+    GUN_DEVICE = 0x03, "Gun Device";
+    POINT_OF_VIEW = 0x20, "Point of View";
+    TURN_RIGHT_LEFT = 0x21, "Turn Right/Left";
+    PITCH_FORWARD_BACKWARD = 0x22, "Pitch Forward/Backward";
+    ROLL_RIGHT_LEFT = 0x23, "Roll Right/Left";
+    MOVE_RIGHT_LEFT = 0x24, "Move Right/Left";
+    MOVE_FORWARD_BACKWARD = 0x25, "Move Forward/Backward";
+    MOVE_UP_DOWN = 0x26, "Move Up/Down";
+    LEAN_RIGHT_LEFT = 0x27, "Lean Right/Left";
+    LEAN_FORWARD_BACKWARD = 0x28, "Lean Forward/Backward";
+    HEIGHT_OF_POV = 0x29, "Height of POV";
+    FLIPPER = 0x2A, "Flipper";
+    SECONDARY_FLIPPER = 0x2B, "Secondary Flipper";
+    BUMP = 0x2C, "Bump";
+    NEW_GAME = 0x2D, "New Game";
+    SHOOT_BALL = 0x2E, "Shoot Ball";
+    PLAYER = 0x2F, "Player";
+    GUN_BOLT = 0x30, "Gun Bolt";
+    GUN_CLIP = 0x31, "Gun Clip";
+    GUN_SELECTOR = 0x32, "Gun Selector";
+    GUN_SINGLE_SHOT = 0x33, "Gun Single Shot";
+    GUN_BURST = 0x34, "Gun Burst";
+    GUN_AUTOMATIC = 0x35, "Gun Automatic";
+    GUN_SAFETY = 0x36, "Gun Safety";
+    GAMEPAD_FIRE_JUMP = 0x37, "Gamepad Fire/Jump";
+    GAMEPAD_TRIGGER = 0x39, "Gamepad Trigger";
+}
+
+usage_table! {
+    /// Keyboard/Keypad Page (0x07)
+    Keyboard = 0x07;
+    NO_EVENT = 0x00, "No Event Indicated";
+    ERROR_ROLL_OVER = 0x01, "Keyboard ErrorRollOver";
+    POST_FAIL = 0x02, "Keyboard POSTFail";
+    ERROR_UNDEFINED = 0x03, "Keyboard ErrorUndefined";
+    A = 0x04, "Keyboard a and A";
+    B = 0x05, "Keyboard b and B";
+    C = 0x06, "Keyboard c and C";
+    D = 0x07, "Keyboard d and D";
+    E = 0x08, "Keyboard e and E";
+    F = 0x09, "Keyboard f and F";
+    G = 0x0A, "Keyboard g and G";
+    H = 0x0B, "Keyboard h and H";
+    I = 0x0C, "Keyboard i and I";
+    J = 0x0D, "Keyboard j and J";
+    K = 0x0E, "Keyboard k and K";
+    L = 0x0F, "Keyboard l and L";
+    M = 0x10, "Keyboard m and M";
+    N = 0x11, "Keyboard n and N";
+    O = 0x12, "Keyboard o and O";
+    P = 0x13, "Keyboard p and P";
+    Q = 0x14, "Keyboard q and Q";
+    R = 0x15, "Keyboard r and R";
+    S = 0x16, "Keyboard s and S";
+    T = 0x17, "Keyboard t and T";
+    U = 0x18, "Keyboard u and U";
+    V = 0x19, "Keyboard v and V";
+    W = 0x1A, "Keyboard w and W";
+    X = 0x1B, "Keyboard x and X";
+    Y = 0x1C, "Keyboard y and Y";
+    Z = 0x1D, "Keyboard z and Z";
+    DIGIT_1 = 0x1E, "Keyboard 1 and !";
+    DIGIT_2 = 0x1F, "Keyboard 2 and @";
+    DIGIT_3 = 0x20, "Keyboard 3 and #";
+    DIGIT_4 = 0x21, "Keyboard 4 and $";
+    DIGIT_5 = 0x22, "Keyboard 5 and %";
+    DIGIT_6 = 0x23, "Keyboard 6 and ^";
+    DIGIT_7 = 0x24, "Keyboard 7 and &";
+    DIGIT_8 = 0x25, "Keyboard 8 and *";
+    DIGIT_9 = 0x26, "Keyboard 9 and (";
+    DIGIT_0 = 0x27, "Keyboard 0 and )";
+    RETURN = 0x28, "Keyboard Return (ENTER)";
+    ESCAPE = 0x29, "Keyboard ESCAPE";
+    BACKSPACE = 0x2A, "Keyboard DELETE (Backspace)";
+    TAB = 0x2B, "Keyboard Tab";
+    SPACEBAR = 0x2C, "Keyboard Spacebar";
+    MINUS = 0x2D, "Keyboard - and _";
+    EQUAL = 0x2E, "Keyboard = and +";
+    LEFT_BRACKET = 0x2F, "Keyboard [ and {";
+    RIGHT_BRACKET = 0x30, "Keyboard ] and }";
+    BACKSLASH = 0x31, "Keyboard \\ and |";
+    NON_US_HASH = 0x32, "Keyboard Non-US # and ~";
+    SEMICOLON = 0x33, "Keyboard ; and :";
+    APOSTROPHE = 0x34, "Keyboard ' and \"";
+    GRAVE_ACCENT = 0x35, "Keyboard Grave Accent and Tilde";
+    COMMA = 0x36, "Keyboard , and <";
+    PERIOD = 0x37, "Keyboard . and >";
+    SLASH = 0x38, "Keyboard / and ?";
+    CAPS_LOCK = 0x39, "Keyboard Caps Lock";
+    F1 = 0x3A, "Keyboard F1";
+    F2 = 0x3B, "Keyboard F2";
+    F3 = 0x3C, "Keyboard F3";
+    F4 = 0x3D, "Keyboard F4";
+    F5 = 0x3E, "Keyboard F5";
+    F6 = 0x3F, "Keyboard F6";
+    F7 = 0x40, "Keyboard F7";
+    F8 = 0x41, "Keyboard F8";
+    F9 = 0x42, "Keyboard F9";
+    F10 = 0x43, "Keyboard F10";
+    F11 = 0x44, "Keyboard F11";
+    F12 = 0x45, "Keyboard F12";
+    PRINT_SCREEN = 0x46, "Keyboard PrintScreen";
+    SCROLL_LOCK = 0x47, "Keyboard Scroll Lock";
+    PAUSE = 0x48, "Keyboard Pause";
+    INSERT = 0x49, "Keyboard Insert";
+    HOME = 0x4A, "Keyboard Home";
+    PAGE_UP = 0x4B, "Keyboard PageUp";
+    DELETE_FORWARD = 0x4C, "Keyboard Delete Forward";
+    END = 0x4D, "Keyboard End";
+    PAGE_DOWN = 0x4E, "Keyboard PageDown";
+    RIGHT_ARROW = 0x4F, "Keyboard RightArrow";
+    LEFT_ARROW = 0x50, "Keyboard LeftArrow";
+    DOWN_ARROW = 0x51, "Keyboard DownArrow";
+    UP_ARROW = 0x52, "Keyboard UpArrow";
+    NUM_LOCK = 0x53, "Keypad Num Lock and Clear";
+    KEYPAD_DIVIDE = 0x54, "Keypad /";
+    KEYPAD_MULTIPLY = 0x55, "Keypad *";
+    KEYPAD_SUBTRACT = 0x56, "Keypad -";
+    KEYPAD_ADD = 0x57, "Keypad +";
+    KEYPAD_ENTER = 0x58, "Keypad ENTER";
+    KEYPAD_1 = 0x59, "Keypad 1 and End";
+    KEYPAD_2 = 0x5A, "Keypad 2 and Down Arrow";
+    KEYPAD_3 = 0x5B, "Keypad 3 and PageDn";
+    KEYPAD_4 = 0x5C, "Keypad 4 and Left Arrow";
+    KEYPAD_5 = 0x5D, "Keypad 5";
+    KEYPAD_6 = 0x5E, "Keypad 6 and Right Arrow";
+    KEYPAD_7 = 0x5F, "Keypad 7 and Home";
+    KEYPAD_8 = 0x60, "Keypad 8 and Up Arrow";
+    KEYPAD_9 = 0x61, "Keypad 9 and PageUp";
+    KEYPAD_0 = 0x62, "Keypad 0 and Insert";
+    KEYPAD_DECIMAL = 0x63, "Keypad . and Delete";
+    NON_US_BACKSLASH = 0x64, "Keyboard Non-US \\ and |";
+    APPLICATION = 0x65, "Keyboard Application";
+    POWER = 0x66, "Keyboard Power";
+    KEYPAD_EQUAL = 0x67, "Keypad =";
+    F13 = 0x68, "Keyboard F13";
+    F14 = 0x69, "Keyboard F14";
+    F15 = 0x6A, "Keyboard F15";
+    F16 = 0x6B, "Keyboard F16";
+    F17 = 0x6C, "Keyboard F17";
+    F18 = 0x6D, "Keyboard F18";
+    F19 = 0x6E, "Keyboard F19";
+    F20 = 0x6F, "Keyboard F20";
+    F21 = 0x70, "Keyboard F21";
+    F22 = 0x71, "Keyboard F22";
+    F23 = 0x72, "Keyboard F23";
+    F24 = 0x73, "Keyboard F24";
+    EXECUTE = 0x74, "Keyboard Execute";
+    HELP = 0x75, "Keyboard Help";
+    MENU = 0x76, "Keyboard Menu";
+    SELECT = 0x77, "Keyboard Select";
+    STOP = 0x78, "Keyboard Stop";
+    AGAIN = 0x79, "Keyboard Again";
+    UNDO = 0x7A, "Keyboard Undo";
+    CUT = 0x7B, "Keyboard Cut";
+    COPY = 0x7C, "Keyboard Copy";
+    PASTE = 0x7D, "Keyboard Paste";
+    FIND = 0x7E, "Keyboard Find";
+    MUTE = 0x7F, "Keyboard Mute";
+    VOLUME_UP = 0x80, "Keyboard Volume Up";
+    VOLUME_DOWN = 0x81, "Keyboard Volume Down";
+    LEFT_CONTROL = 0xE0, "Keyboard LeftControl";
+    LEFT_SHIFT = 0xE1, "Keyboard LeftShift";
+    LEFT_ALT = 0xE2, "Keyboard LeftAlt";
+    LEFT_GUI = 0xE3, "Keyboard Left GUI";
+    RIGHT_CONTROL = 0xE4, "Keyboard RightControl";
+    RIGHT_SHIFT = 0xE5, "Keyboard RightShift";
+    RIGHT_ALT = 0xE6, "Keyboard RightAlt";
+    RIGHT_GUI = 0xE7, "Keyboard Right GUI";
+}
+
+usage_table! {
+    /// LED Page (0x08)
+    Led = 0x08;
+    NUM_LOCK = 0x01, "Num Lock";
+    CAPS_LOCK = 0x02, "Caps Lock";
+    SCROLL_LOCK = 0x03, "Scroll Lock";
+    COMPOSE = 0x04, "Compose";
+    KANA = 0x05, "Kana";
+    POWER = 0x06, "Power";
+    SHIFT = 0x07, "Shift";
+    DO_NOT_DISTURB = 0x08, "Do Not Disturb";
+    MUTE = 0x09, "Mute";
+    TONE_ENABLE = 0x0A, "Tone Enable";
+    HIGH_CUT_FILTER = 0x0B, "High Cut Filter";
+    LOW_CUT_FILTER = 0x0C, "Low Cut Filter";
+    EQUALIZER_ENABLE = 0x0D, "Equalizer Enable";
+    SOUND_FIELD_ON = 0x0E, "Sound Field On";
+    SURROUND_ON = 0x0F, "Surround On";
+    REPEAT = 0x10, "Repeat";
+    STEREO = 0x11, "Stereo";
+    SAMPLING_RATE_DETECT = 0x12, "Sampling Rate Detect";
+    SPINNING = 0x13, "Spinning";
+    CAV = 0x14, "CAV";
+    CLV = 0x15, "CLV";
+    RECORDING_FORMAT_DETECT = 0x16, "Recording Format Detect";
+    OFF_HOOK = 0x17, "Off-Hook";
+    RING = 0x18, "Ring";
+    MESSAGE_WAITING = 0x19, "Message Waiting";
+    DATA_MODE = 0x1A, "Data Mode";
+    BATTERY_OPERATION = 0x1B, "Battery Operation";
+    BATTERY_OK = 0x1C, "Battery OK";
+    BATTERY_LOW = 0x1D, "Battery Low";
+    SPEAKER = 0x1E, "Speaker";
+    HEADSET = 0x1F, "Headset";
+    HOLD = 0x20, "Hold";
+    MICROPHONE = 0x21, "Microphone";
+    COVERAGE = 0x22, "Coverage";
+    NIGHT_MODE = 0x23, "Night Mode";
+    SEND_CALLS = 0x24, "Send Calls";
+    CALL_PICKUP = 0x25, "Call Pickup";
+    CONFERENCE = 0x26, "Conference";
+    STAND_BY = 0x27, "Stand-by";
+    CAMERA_ON = 0x28, "Camera On";
+    CAMERA_OFF = 0x29, "Camera Off";
+    ON_LINE = 0x2A, "On-Line";
+    OFF_LINE = 0x2B, "Off-Line";
+    BUSY = 0x2C, "Busy";
+    READY = 0x2D, "Ready";
+    PAPER_OUT = 0x2E, "Paper-Out";
+    PAPER_JAM = 0x2F, "Paper-Jam";
+    REMOTE = 0x30, "Remote";
+    FORWARD = 0x31, "Forward";
+    REVERSE = 0x32, "Reverse";
+    STOP = 0x33, "Stop";
+    REWIND = 0x34, "Rewind";
+    FAST_FORWARD = 0x35, "Fast Forward";
+    PLAY = 0x36, "Play";
+    PAUSE = 0x37, "Pause";
+    RECORD = 0x38, "Record";
+    ERROR = 0x39, "Error";
+    GENERIC_INDICATOR = 0x4B, "Generic Indicator";
+    SYSTEM_SUSPEND = 0x4C, "System Suspend";
+    EXTERNAL_POWER_CONNECTED = 0x4D, "External Power Connected";
+}
+
+usage_table! {
+    /// Button Page (0x09), usage ID N is button N
+    Button = 0x09;
+    NO_BUTTON_PRESSED = 0x00, "No Button Pressed";
+}
+
+impl Button {
+    pub const fn button(number: u16) -> Usage {
+        Usage {
+            page: Button::PAGE,
+            id: number,
+        }
+    }
+}
+
+usage_table! {
+    /// Telephony Device Page (0x0B)
+    Telephony = 0x0B;
+    PHONE = 0x01, "Phone";
+    ANSWERING_MACHINE = 0x02, "Answering Machine";
+    MESSAGE_CONTROLS = 0x03, "Message Controls";
+    HANDSET = 0x04, "Handset";
+    HEADSET = 0x05, "Headset";
+    TELEPHONY_KEY_PAD = 0x06, "Telephony Key Pad";
+    PROGRAMMABLE_BUTTON = 0x07, "Programmable Button";
+    HOOK_SWITCH = 0x20, "Hook Switch";
+    FLASH = 0x21, "Flash";
+    FEATURE = 0x22, "Feature";
+    HOLD = 0x23, "Hold";
+    REDIAL = 0x24, "Redial";
+    TRANSFER = 0x25, "Transfer";
+    DROP = 0x26, "Drop";
+    PARK = 0x27, "Park";
+    FORWARD_CALLS = 0x28, "Forward Calls";
+    ALTERNATE_FUNCTION = 0x29, "Alternate Function";
+    LINE = 0x2A, "Line";
+    SPEAKER_PHONE = 0x2B, "Speaker Phone";
+    CONFERENCE = 0x2C, "Conference";
+    RING_ENABLE = 0x2D, "Ring Enable";
+    RING_SELECT = 0x2E, "Ring Select";
+    PHONE_MUTE = 0x2F, "Phone Mute";
+    CALLER_ID = 0x30, "Caller ID";
+    SEND = 0x31, "Send";
+    SPEED_DIAL = 0x50, "Speed Dial";
+    STORE_NUMBER = 0x51, "Store Number";
+    RECALL_NUMBER = 0x52, "Recall Number";
+    PHONE_DIRECTORY = 0x53, "Phone Directory";
+    VOICE_MAIL = 0x70, "Voice Mail";
+    SCREEN_CALLS = 0x71, "Screen Calls";
+    DO_NOT_DISTURB = 0x72, "Do Not Disturb";
+    MESSAGE = 0x73, "Message";
+    ANSWER_ON_OFF = 0x74, "Answer On/Off";
+    RINGER = 0x9E, "Ringer";
+    PHONE_KEY_0 = 0xB0, "Phone Key 0";
+    PHONE_KEY_1 = 0xB1, "Phone Key 1";
+    PHONE_KEY_2 = 0xB2, "Phone Key 2";
+    PHONE_KEY_3 = 0xB3, "Phone Key 3";
+    PHONE_KEY_4 = 0xB4, "Phone Key 4";
+    PHONE_KEY_5 = 0xB5, "Phone Key 5";
+    PHONE_KEY_6 = 0xB6, "Phone Key 6";
+    PHONE_KEY_7 = 0xB7, "Phone Key 7";
+    PHONE_KEY_8 = 0xB8, "Phone Key 8";
+    PHONE_KEY_9 = 0xB9, "Phone Key 9";
+    PHONE_KEY_STAR = 0xBA, "Phone Key Star";
+    PHONE_KEY_POUND = 0xBB, "Phone Key Pound";
+    PHONE_KEY_A = 0xBC, "Phone Key A";
+    PHONE_KEY_B = 0xBD, "Phone Key B";
+    PHONE_KEY_C = 0xBE, "Phone Key C";
+    PHONE_KEY_D = 0xBF, "Phone Key D";
+}
+
+usage_table! {
+    /// Consumer Page (0x0C)
+    Consumer = 0x0C;
+    CONSUMER_CONTROL = 0x01, "Consumer Control";
+    NUMERIC_KEY_PAD = 0x02, "Numeric Key Pad";
+    PROGRAMMABLE_BUTTONS = 0x03, "Programmable Buttons";
+    MICROPHONE = 0x04, "Microphone";
+    HEADPHONE = 0x05, "Headphone";
+    GRAPHIC_EQUALIZER = 0x06, "Graphic Equalizer";
+    POWER = 0x30, "Power";
+    RESET = 0x31, "Reset";
+    SLEEP = 0x32, "Sleep";
+    SLEEP_AFTER = 0x33, "Sleep After";
+    SLEEP_MODE = 0x34, "Sleep Mode";
+    ILLUMINATION = 0x35, "Illumination";
+    FUNCTION_BUTTONS = 0x36, "Function Buttons";
+    MENU = 0x40, "Menu";
+    MENU_PICK = 0x41, "Menu Pick";
+    MENU_UP = 0x42, "Menu Up";
+    MENU_DOWN = 0x43, "Menu Down";
+    MENU_LEFT = 0x44, "Menu Left";
+    MENU_RIGHT = 0x45, "Menu Right";
+    MENU_ESCAPE = 0x46, "Menu Escape";
+    MENU_VALUE_INCREASE = 0x47, "Menu Value Increase";
+    MENU_VALUE_DECREASE = 0x48, "Menu Value Decrease";
+    DATA_ON_SCREEN = 0x60, "Data On Screen";
+    CLOSED_CAPTION = 0x61, "Closed Caption";
+    SNAPSHOT = 0x65, "Snapshot";
+    DISPLAY_BRIGHTNESS_INCREMENT = 0x6F, "Display Brightness Increment";
+    DISPLAY_BRIGHTNESS_DECREMENT = 0x70, "Display Brightness Decrement";
+    PLAY = 0xB0, "Play";
+    PAUSE = 0xB1, "Pause";
+    RECORD = 0xB2, "Record";
+    FAST_FORWARD = 0xB3, "Fast Forward";
+    REWIND = 0xB4, "Rewind";
+    SCAN_NEXT_TRACK = 0xB5, "Scan Next Track";
+    SCAN_PREVIOUS_TRACK = 0xB6, "Scan Previous Track";
+    STOP = 0xB7, "Stop";
+    EJECT = 0xB8, "Eject";
+    RANDOM_PLAY = 0xB9, "Random Play";
+    PLAY_PAUSE = 0xCD, "Play/Pause";
+    VOICE_COMMAND = 0xCF, "Voice Command";
+    VOLUME = 0xE0, "Volume";
+    MUTE = 0xE2, "Mute";
+    BASS = 0xE3, "Bass";
+    TREBLE = 0xE4, "Treble";
+    BASS_BOOST = 0xE5, "Bass Boost";
+    VOLUME_INCREMENT = 0xE9, "Volume Increment";
+    VOLUME_DECREMENT = 0xEA, "Volume Decrement";
+    AL_CONSUMER_CONTROL_CONFIGURATION = 0x0183, "AL Consumer Control Configuration";
+    AL_EMAIL_READER = 0x018A, "AL Email Reader";
+    AL_CALCULATOR = 0x0192, "AL Calculator";
+    AL_LOCAL_MACHINE_BROWSER = 0x0194, "AL Local Machine Browser";
+    AL_INTERNET_BROWSER = 0x0196, "AL Internet Browser";
+    AC_NEW = 0x0201, "AC New";
+    AC_OPEN = 0x0202, "AC Open";
+    AC_CLOSE = 0x0203, "AC Close";
+    AC_SAVE = 0x0207, "AC Save";
+    AC_PRINT = 0x0208, "AC Print";
+    AC_UNDO = 0x021A, "AC Undo";
+    AC_COPY = 0x021B, "AC Copy";
+    AC_CUT = 0x021C, "AC Cut";
+    AC_PASTE = 0x021D, "AC Paste";
+    AC_FIND = 0x021F, "AC Find";
+    AC_SEARCH = 0x0221, "AC Search";
+    AC_HOME = 0x0223, "AC Home";
+    AC_BACK = 0x0224, "AC Back";
+    AC_FORWARD = 0x0225, "AC Forward";
+    AC_STOP = 0x0226, "AC Stop";
+    AC_REFRESH = 0x0227, "AC Refresh";
+    AC_BOOKMARKS = 0x022A, "AC Bookmarks";
+    AC_ZOOM_IN = 0x022D, "AC Zoom In";
+    AC_ZOOM_OUT = 0x022E, "AC Zoom Out";
+    AC_ZOOM = 0x022F, "AC Zoom";
+    AC_PAN = 0x0238, "AC Pan";
+}
+
+usage_table! {
+    /// Digitizers Page (0x0D)
+    Digitizer = 0x0D;
+    DIGITIZER = 0x01, "Digitizer";
+    PEN = 0x02, "Pen";
+    LIGHT_PEN = 0x03, "Light Pen";
+    TOUCH_SCREEN = 0x04, "Touch Screen";
+    TOUCH_PAD = 0x05, "Touch Pad";
+    WHITEBOARD = 0x06, "Whiteboard";
+    COORDINATE_MEASURING_MACHINE = 0x07, "Coordinate Measuring Machine";
+    DIGITIZER_3D = 0x08, "3D Digitizer";
+    STEREO_PLOTTER = 0x09, "Stereo Plotter";
+    ARTICULATED_ARM = 0x0A, "Articulated Arm";
+    ARMATURE = 0x0B, "Armature";
+    MULTIPLE_POINT_DIGITIZER = 0x0C, "Multiple Point Digitizer";
+    FREE_SPACE_WAND = 0x0D, "Free Space Wand";
+    DEVICE_CONFIGURATION = 0x0E, "Device Configuration";
+    CAPACITIVE_HEAT_MAP_DIGITIZER = 0x0F, "Capacitive Heat Map Digitizer";
+    STYLUS = 0x20, "Stylus";
+    PUCK = 0x21, "Puck";
+    FINGER = 0x22, "Finger";
+    DEVICE_SETTINGS = 0x23, "Device Settings";
+    CHARACTER_GESTURE = 0x24, "Character Gesture";
+    TIP_PRESSURE = 0x30, "Tip Pressure";
+    BARREL_PRESSURE = 0x31, "Barrel Pressure";
+    IN_RANGE = 0x32, "In Range";
+    TOUCH = 0x33, "Touch";
+    UNTOUCH = 0x34, "Untouch";
+    TAP = 0x35, "Tap";
+    QUALITY = 0x36, "Quality";
+    DATA_VALID = 0x37, "Data Valid";
+    TRANSDUCER_INDEX = 0x38, "Transducer Index";
+    TABLET_FUNCTION_KEYS = 0x39, "Tablet Function Keys";
+    PROGRAM_CHANGE_KEYS = 0x3A, "Program Change Keys";
+    BATTERY_STRENGTH = 0x3B, "Battery Strength";
+    INVERT = 0x3C, "Invert";
+    X_TILT = 0x3D, "X Tilt";
+    Y_TILT = 0x3E, "Y Tilt";
+    AZIMUTH = 0x3F, "Azimuth";
+    ALTITUDE = 0x40, "Altitude";
+    TWIST = 0x41, "Twist";
+    TIP_SWITCH = 0x42, "Tip Switch";
+    SECONDARY_TIP_SWITCH = 0x43, "Secondary Tip Switch";
+    BARREL_SWITCH = 0x44, "Barrel Switch";
+    ERASER = 0x45, "Eraser";
+    TABLET_PICK = 0x46, "Tablet Pick";
+    TOUCH_VALID = 0x47, "Touch Valid";
+    WIDTH = 0x48, "Width";
+    HEIGHT = 0x49, "Height";
+    CONTACT_IDENTIFIER = 0x51, "Contact Identifier";
+    DEVICE_MODE = 0x52, "Device Mode";
+    DEVICE_IDENTIFIER = 0x53, "Device Identifier";
+    CONTACT_COUNT = 0x54, "Contact Count";
+    CONTACT_COUNT_MAXIMUM = 0x55, "Contact Count Maximum";
+    SCAN_TIME = 0x56, "Scan Time";
+    SURFACE_SWITCH = 0x57, "Surface Switch";
+    BUTTON_SWITCH = 0x58, "Button Switch";
+    PAD_TYPE = 0x59, "Pad Type";
+    SECONDARY_BARREL_SWITCH = 0x5A, "Secondary Barrel Switch";
+    TRANSDUCER_SERIAL_NUMBER = 0x5B, "Transducer Serial Number";
+}
+
+usage_table! {
+    /// Haptics Page (0x0E)
+    Haptics = 0x0E;
+    SIMPLE_HAPTIC_CONTROLLER = 0x01, "Simple Haptic Controller";
+    WAVEFORM_LIST = 0x10, "Waveform List";
+    DURATION_LIST = 0x11, "Duration List";
+    AUTO_TRIGGER = 0x20, "Auto Trigger";
+    MANUAL_TRIGGER = 0x21, "Manual Trigger";
+    AUTO_TRIGGER_ASSOCIATED_CONTROL = 0x22, "Auto Trigger Associated Control";
+    INTENSITY = 0x23, "Intensity";
+    REPEAT_COUNT = 0x24, "Repeat Count";
+    RETRIGGER_PERIOD = 0x25, "Retrigger Period";
+    WAVEFORM_VENDOR_PAGE = 0x26, "Waveform Vendor Page";
+    WAVEFORM_VENDOR_ID = 0x27, "Waveform Vendor ID";
+    WAVEFORM_CUTOFF_TIME = 0x28, "Waveform Cutoff Time";
+    WAVEFORM_NONE = 0x1001, "Waveform None";
+    WAVEFORM_STOP = 0x1002, "Waveform Stop";
+    WAVEFORM_CLICK = 0x1003, "Waveform Click";
+    WAVEFORM_BUZZ_CONTINUOUS = 0x1004, "Waveform Buzz Continuous";
+    WAVEFORM_RUMBLE_CONTINUOUS = 0x1005, "Waveform Rumble Continuous";
+    WAVEFORM_PRESS = 0x1006, "Waveform Press";
+    WAVEFORM_RELEASE = 0x1007, "Waveform Release";
+}
+
+usage_table! {
+    /// Sensors Page (0x20)
+    Sensor = 0x20;
+    SENSOR = 0x01, "Sensor";
+    BIOMETRIC = 0x10, "Biometric";
+    BIOMETRIC_HUMAN_PRESENCE = 0x11, "Biometric: Human Presence";
+    BIOMETRIC_HUMAN_PROXIMITY = 0x12, "Biometric: Human Proximity";
+    BIOMETRIC_HUMAN_TOUCH = 0x13, "Biometric: Human Touch";
+    ELECTRICAL = 0x20, "Electrical";
+    ELECTRICAL_CAPACITANCE = 0x21, "Electrical: Capacitance";
+    ELECTRICAL_CURRENT = 0x22, "Electrical: Current";
+    ELECTRICAL_POWER = 0x23, "Electrical: Power";
+    ELECTRICAL_INDUCTANCE = 0x24, "Electrical: Inductance";
+    ELECTRICAL_RESISTANCE = 0x25, "Electrical: Resistance";
+    ELECTRICAL_VOLTAGE = 0x26, "Electrical: Voltage";
+    ELECTRICAL_POTENTIOMETER = 0x27, "Electrical: Potentiometer";
+    ELECTRICAL_FREQUENCY = 0x28, "Electrical: Frequency";
+    ELECTRICAL_PERIOD = 0x29, "Electrical: Period";
+    ENVIRONMENTAL = 0x30, "Environmental";
+    ENVIRONMENTAL_ATMOSPHERIC_PRESSURE = 0x31, "Environmental: Atmospheric Pressure";
+    ENVIRONMENTAL_HUMIDITY = 0x32, "Environmental: Humidity";
+    ENVIRONMENTAL_TEMPERATURE = 0x33, "Environmental: Temperature";
+    ENVIRONMENTAL_WIND_DIRECTION = 0x34, "Environmental: Wind Direction";
+    ENVIRONMENTAL_WIND_SPEED = 0x35, "Environmental: Wind Speed";
+    LIGHT = 0x40, "Light";
+    LIGHT_AMBIENT_LIGHT = 0x41, "Light: Ambient Light";
+    LIGHT_CONSUMER_INFRARED = 0x42, "Light: Consumer Infrared";
+    LOCATION = 0x50, "Location";
+    MECHANICAL = 0x60, "Mechanical";
+    MOTION = 0x70, "Motion";
+    MOTION_ACCELEROMETER_1D = 0x71, "Motion: Accelerometer 1D";
+    MOTION_ACCELEROMETER_2D = 0x72, "Motion: Accelerometer 2D";
+    MOTION_ACCELEROMETER_3D = 0x73, "Motion: Accelerometer 3D";
+    MOTION_GYROMETER_1D = 0x74, "Motion: Gyrometer 1D";
+    MOTION_GYROMETER_2D = 0x75, "Motion: Gyrometer 2D";
+    MOTION_GYROMETER_3D = 0x76, "Motion: Gyrometer 3D";
+    MOTION_MOTION_DETECTOR = 0x77, "Motion: Motion Detector";
+    MOTION_SPEEDOMETER = 0x78, "Motion: Speedometer";
+    MOTION_ACCELEROMETER = 0x79, "Motion: Accelerometer";
+    MOTION_GYROMETER = 0x7A, "Motion: Gyrometer";
+    ORIENTATION = 0x80, "Orientation";
+    ORIENTATION_COMPASS_1D = 0x81, "Orientation: Compass 1D";
+    ORIENTATION_COMPASS_2D = 0x82, "Orientation: Compass 2D";
+    ORIENTATION_COMPASS_3D = 0x83, "Orientation: Compass 3D";
+    ORIENTATION_INCLINOMETER_1D = 0x84, "Orientation: Inclinometer 1D";
+    ORIENTATION_INCLINOMETER_2D = 0x85, "Orientation: Inclinometer 2D";
+    ORIENTATION_INCLINOMETER_3D = 0x86, "Orientation: Inclinometer 3D";
+    ORIENTATION_DISTANCE_1D = 0x87, "Orientation: Distance 1D";
+    ORIENTATION_DISTANCE_2D = 0x88, "Orientation: Distance 2D";
+    ORIENTATION_DISTANCE_3D = 0x89, "Orientation: Distance 3D";
+    ORIENTATION_DEVICE_ORIENTATION = 0x8A, "Orientation: Device Orientation";
+    ORIENTATION_COMPASS = 0x8B, "Orientation: Compass";
+    ORIENTATION_INCLINOMETER = 0x8C, "Orientation: Inclinometer";
+    ORIENTATION_DISTANCE = 0x8D, "Orientation: Distance";
+    SENSOR_STATE = 0x0201, "Sensor State";
+    SENSOR_EVENT = 0x0202, "Sensor Event";
+    PROPERTY_FRIENDLY_NAME = 0x0301, "Property: Friendly Name";
+    PROPERTY_REPORT_INTERVAL = 0x030E, "Property: Report Interval";
+    DATA_FIELD_ATMOSPHERIC_PRESSURE = 0x0431, "Data Field: Atmospheric Pressure";
+    DATA_FIELD_RELATIVE_HUMIDITY = 0x0433, "Data Field: Relative Humidity";
+    DATA_FIELD_TEMPERATURE = 0x0434, "Data Field: Temperature";
+    DATA_FIELD_ACCELERATION = 0x0452, "Data Field: Acceleration";
+    DATA_FIELD_ACCELERATION_AXIS_X = 0x0453, "Data Field: Acceleration Axis X";
+    DATA_FIELD_ACCELERATION_AXIS_Y = 0x0454, "Data Field: Acceleration Axis Y";
+    DATA_FIELD_ACCELERATION_AXIS_Z = 0x0455, "Data Field: Acceleration Axis Z";
+    DATA_FIELD_ANGULAR_VELOCITY = 0x0456, "Data Field: Angular Velocity";
+    DATA_FIELD_ANGULAR_VELOCITY_X_AXIS = 0x0457, "Data Field: Angular Velocity about X Axis";
+    DATA_FIELD_ANGULAR_VELOCITY_Y_AXIS = 0x0458, "Data Field: Angular Velocity about Y Axis";
+    DATA_FIELD_ANGULAR_VELOCITY_Z_AXIS = 0x0459, "Data Field: Angular Velocity about Z Axis";
+    DATA_FIELD_ILLUMINANCE = 0x04D1, "Data Field: Illuminance";
+}
+
+usage_table! {
+    /// Power Device Page (0x84)
+    PowerDevice = 0x84;
+    I_NAME = 0x01, "iName";
+    PRESENT_STATUS = 0x02, "Present Status";
+    CHANGED_STATUS = 0x03, "Changed Status";
+    UPS = 0x04, "UPS";
+    POWER_SUPPLY = 0x05, "Power Supply";
+    BATTERY_SYSTEM = 0x10, "Battery System";
+    BATTERY_SYSTEM_ID = 0x11, "Battery System ID";
+    BATTERY = 0x12, "Battery";
+    BATTERY_ID = 0x13, "Battery ID";
+    CHARGER = 0x14, "Charger";
+    CHARGER_ID = 0x15, "Charger ID";
+    POWER_CONVERTER = 0x16, "Power Converter";
+    POWER_CONVERTER_ID = 0x17, "Power Converter ID";
+    OUTLET_SYSTEM = 0x18, "Outlet System";
+    OUTLET_SYSTEM_ID = 0x19, "Outlet System ID";
+    INPUT = 0x1A, "Input";
+    INPUT_ID = 0x1B, "Input ID";
+    OUTPUT = 0x1C, "Output";
+    OUTPUT_ID = 0x1D, "Output ID";
+    FLOW = 0x1E, "Flow";
+    FLOW_ID = 0x1F, "Flow ID";
+    OUTLET = 0x20, "Outlet";
+    OUTLET_ID = 0x21, "Outlet ID";
+    GANG = 0x22, "Gang";
+    GANG_ID = 0x23, "Gang ID";
+    POWER_SUMMARY = 0x24, "Power Summary";
+    POWER_SUMMARY_ID = 0x25, "Power Summary ID";
+    VOLTAGE = 0x30, "Voltage";
+    CURRENT = 0x31, "Current";
+    FREQUENCY = 0x32, "Frequency";
+    APPARENT_POWER = 0x33, "Apparent Power";
+    ACTIVE_POWER = 0x34, "Active Power";
+    PERCENT_LOAD = 0x35, "Percent Load";
+    TEMPERATURE = 0x36, "Temperature";
+    HUMIDITY = 0x37, "Humidity";
+    I_MANUFACTURER = 0xFD, "iManufacturer";
+    I_PRODUCT = 0xFE, "iProduct";
+    I_SERIAL_NUMBER = 0xFF, "iSerialNumber";
+}
+
+usage_table! {
+    /// Battery System Page (0x85)
+    BatterySystem = 0x85;
+    SMART_BATTERY_BATTERY_MODE = 0x01, "Smart Battery Battery Mode";
+    SMART_BATTERY_BATTERY_STATUS = 0x02, "Smart Battery Battery Status";
+    SMART_BATTERY_ALARM_WARNING = 0x03, "Smart Battery Alarm Warning";
+    SMART_BATTERY_CHARGER_MODE = 0x04, "Smart Battery Charger Mode";
+    SMART_BATTERY_CHARGER_STATUS = 0x05, "Smart Battery Charger Status";
+    SMART_BATTERY_CHARGER_SPEC_INFO = 0x06, "Smart Battery Charger Spec Info";
+    SMART_BATTERY_SELECTOR_STATE = 0x07, "Smart Battery Selector State";
+    SMART_BATTERY_SELECTOR_PRESETS = 0x08, "Smart Battery Selector Presets";
+    SMART_BATTERY_SELECTOR_INFO = 0x09, "Smart Battery Selector Info";
+    REMAINING_CAPACITY_LIMIT = 0x29, "Remaining Capacity Limit";
+    REMAINING_TIME_LIMIT = 0x2A, "Remaining Time Limit";
+    CAPACITY_MODE = 0x2C, "Capacity Mode";
+    TERMINATE_CHARGE = 0x40, "Terminate Charge";
+    TERMINATE_DISCHARGE = 0x41, "Terminate Discharge";
+    BELOW_REMAINING_CAPACITY_LIMIT = 0x42, "Below Remaining Capacity Limit";
+    REMAINING_TIME_LIMIT_EXPIRED = 0x43, "Remaining Time Limit Expired";
+    CHARGING = 0x44, "Charging";
+    DISCHARGING = 0x45, "Discharging";
+    FULLY_CHARGED = 0x46, "Fully Charged";
+    FULLY_DISCHARGED = 0x47, "Fully Discharged";
+    NEED_REPLACEMENT = 0x4B, "Need Replacement";
+    RELATIVE_STATE_OF_CHARGE = 0x64, "Relative State of Charge";
+    ABSOLUTE_STATE_OF_CHARGE = 0x65, "Absolute State of Charge";
+    REMAINING_CAPACITY = 0x66, "Remaining Capacity";
+    FULL_CHARGE_CAPACITY = 0x67, "Full Charge Capacity";
+    RUN_TIME_TO_EMPTY = 0x68, "Run Time to Empty";
+    AVERAGE_TIME_TO_EMPTY = 0x69, "Average Time to Empty";
+    AVERAGE_TIME_TO_FULL = 0x6A, "Average Time to Full";
+    CYCLE_COUNT = 0x6B, "Cycle Count";
+    DESIGN_CAPACITY = 0x83, "Design Capacity";
+    MANUFACTURE_DATE = 0x85, "Manufacture Date";
+    I_DEVICE_CHEMISTRY = 0x89, "iDeviceChemistry";
+    RECHARGEABLE = 0x8B, "Rechargeable";
+    WARNING_CAPACITY_LIMIT = 0x8C, "Warning Capacity Limit";
+    CAPACITY_GRANULARITY_1 = 0x8D, "Capacity Granularity 1";
+    CAPACITY_GRANULARITY_2 = 0x8E, "Capacity Granularity 2";
+    AC_PRESENT = 0xD0, "AC Present";
+    BATTERY_PRESENT = 0xD1, "Battery Present";
+}
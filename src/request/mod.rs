@@ -0,0 +1,3 @@
+pub mod recipient;
+pub mod request_type;
+pub mod setup_packet;
@@ -0,0 +1,38 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Recipient {
+    Device,
+    Interface,
+    Endpoint,
+    Other,
+}
+
+impl EncodeByte for Recipient {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            Recipient::Device => Ok(0x00),
+            Recipient::Interface => Ok(0x01),
+            Recipient::Endpoint => Ok(0x02),
+            Recipient::Other => Ok(0x03),
+        }
+    }
+}
+
+impl DecodeByte for Recipient {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(Recipient::Device),
+            0x01 => Ok(Recipient::Interface),
+            0x02 => Ok(Recipient::Endpoint),
+            0x03 => Ok(Recipient::Other),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmRequestType",
+                value: byte as u16,
+            }),
+        }
+    }
+}
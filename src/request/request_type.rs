@@ -0,0 +1,35 @@
+use crate::{
+    binary::{DecodeByte, EncodeByte},
+    error::{DecodeError, Error},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RequestType {
+    Standard,
+    Class,
+    Vendor,
+}
+
+impl EncodeByte for RequestType {
+    fn encode(&self) -> Result<u8, Error> {
+        match *self {
+            RequestType::Standard => Ok(0x00),
+            RequestType::Class => Ok(0x01),
+            RequestType::Vendor => Ok(0x02),
+        }
+    }
+}
+
+impl DecodeByte for RequestType {
+    fn decode(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0x00 => Ok(RequestType::Standard),
+            0x01 => Ok(RequestType::Class),
+            0x02 => Ok(RequestType::Vendor),
+            _ => Err(DecodeError::InvalidValue {
+                field: "bmRequestType",
+                value: byte as u16,
+            }),
+        }
+    }
+}
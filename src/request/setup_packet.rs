@@ -0,0 +1,161 @@
+use alloc::vec::Vec;
+
+use crate::{
+    binary::{DecodeByte, DecodeBytes, EncodeByte, EncodeBytes},
+    endpoint::direction::Direction,
+    error::{DecodeError, Error},
+};
+
+use super::{recipient::Recipient, request_type::RequestType};
+
+pub const SETUP_PACKET_LENGTH: usize = 8;
+
+/// `bRequest` of a standard GET_DESCRIPTOR request
+pub const GET_DESCRIPTOR: u8 = 0x06;
+
+/// The 8 byte SETUP packet that starts every control transfer
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SetupPacket {
+    /// Turns into `bmRequestType` bit 7
+    pub direction: Direction,
+    /// Turns into `bmRequestType` bits 6..5
+    pub request_type: RequestType,
+    /// Turns into `bmRequestType` bits 4..0
+    pub recipient: Recipient,
+    /// Turns into `bRequest`
+    pub request: u8,
+    /// Turns into `wValue`
+    pub value: u16,
+    /// Turns into `wIndex`
+    pub index: u16,
+    /// Turns into `wLength`
+    pub length: u16,
+}
+
+impl SetupPacket {
+    /// A standard GET_DESCRIPTOR request addressed to the device
+    pub fn get_descriptor(value: u16, index: u16, length: u16) -> SetupPacket {
+        SetupPacket {
+            direction: Direction::In,
+            request_type: RequestType::Standard,
+            recipient: Recipient::Device,
+            request: GET_DESCRIPTOR,
+            value,
+            index,
+            length,
+        }
+    }
+
+    pub fn is_get_descriptor(&self) -> bool {
+        self.direction == Direction::In
+            && self.request_type == RequestType::Standard
+            && self.request == GET_DESCRIPTOR
+    }
+}
+
+impl EncodeBytes for SetupPacket {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::<u8>::new();
+        bytes.push(
+            self.direction.encode()? << 7
+                | self.request_type.encode()? << 5
+                | self.recipient.encode()?,
+        );
+        bytes.push(self.request);
+        bytes.extend_from_slice(&self.value.to_le_bytes());
+        bytes.extend_from_slice(&self.index.to_le_bytes());
+        bytes.extend_from_slice(&self.length.to_le_bytes());
+        Ok(bytes)
+    }
+}
+
+impl DecodeBytes for SetupPacket {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < SETUP_PACKET_LENGTH {
+            return Err(DecodeError::Truncated {
+                expected: SETUP_PACKET_LENGTH,
+                actual: bytes.len(),
+            });
+        }
+
+        let request_type =
+            RequestType::decode((bytes[0] >> 5) & 0b11).map_err(|_| DecodeError::InvalidValue {
+                field: "bmRequestType",
+                value: bytes[0] as u16,
+            })?;
+        let recipient =
+            Recipient::decode(bytes[0] & 0b1_1111).map_err(|_| DecodeError::InvalidValue {
+                field: "bmRequestType",
+                value: bytes[0] as u16,
+            })?;
+
+        Ok(SetupPacket {
+            direction: Direction::decode(bytes[0] >> 7)?,
+            request_type,
+            recipient,
+            request: bytes[1],
+            value: u16::from_le_bytes([bytes[2], bytes[3]]),
+            index: u16::from_le_bytes([bytes[4], bytes[5]]),
+            length: u16::from_le_bytes([bytes[6], bytes[7]]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GET_DEVICE_DESCRIPTOR: [u8; 8] = [0x80, 0x06, 0x00, 0x01, 0x00, 0x00, 0x40, 0x00];
+
+    #[test]
+    fn test_decode() {
+        let setup = SetupPacket::decode(&GET_DEVICE_DESCRIPTOR).unwrap();
+        assert_eq!(setup, SetupPacket::get_descriptor(0x0100, 0x0000, 0x0040));
+        assert!(setup.is_get_descriptor());
+
+        // GET_DESCRIPTOR(Report) is addressed to the interface
+        let setup = SetupPacket::decode(&[0x81, 0x06, 0x00, 0x22, 0x01, 0x00, 0x40, 0x00]).unwrap();
+        assert_eq!(setup.recipient, Recipient::Interface);
+        assert_eq!(setup.index, 0x0001);
+        assert!(setup.is_get_descriptor());
+
+        // SET_ADDRESS
+        let setup = SetupPacket::decode(&[0x00, 0x05, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00]).unwrap();
+        assert!(!setup.is_get_descriptor());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            SetupPacket::decode(&GET_DEVICE_DESCRIPTOR[..7]),
+            Err(DecodeError::Truncated {
+                expected: 8,
+                actual: 7
+            })
+        );
+        assert_eq!(
+            SetupPacket::decode(&[0xe0, 0x06, 0x00, 0x01, 0x00, 0x00, 0x40, 0x00]),
+            Err(DecodeError::InvalidValue {
+                field: "bmRequestType",
+                value: 0xe0
+            })
+        );
+        assert_eq!(
+            SetupPacket::decode(&[0x84, 0x06, 0x00, 0x01, 0x00, 0x00, 0x40, 0x00]),
+            Err(DecodeError::InvalidValue {
+                field: "bmRequestType",
+                value: 0x84
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            SetupPacket::get_descriptor(0x0100, 0x0000, 0x0040)
+                .encode()
+                .unwrap(),
+            GET_DEVICE_DESCRIPTOR
+        );
+    }
+}
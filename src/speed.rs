@@ -0,0 +1,60 @@
+use alloc::{vec, vec::Vec};
+
+/// The bus speed a device operates at, reported by the device controller after reset
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Speed {
+    /// 1.5 Mbit/s
+    Low,
+    /// 12 Mbit/s
+    Full,
+    /// 480 Mbit/s
+    High,
+    /// 5 Gbit/s
+    Super,
+    /// 10 Gbit/s and above
+    SuperPlus,
+}
+
+impl Speed {
+    /// The speeds a device with this maximum speed operates at.
+    /// Low-speed devices only operate at low speed, faster devices also at full and high speed.
+    pub fn up_to(&self) -> Vec<Speed> {
+        match self {
+            Speed::Low => vec![Speed::Low],
+            Speed::Full => vec![Speed::Full],
+            Speed::High => vec![Speed::Full, Speed::High],
+            Speed::Super => vec![Speed::Full, Speed::High, Speed::Super],
+            Speed::SuperPlus => vec![Speed::Full, Speed::High, Speed::Super, Speed::SuperPlus],
+        }
+    }
+
+    /// The speed described by the device qualifier and the other speed configuration
+    pub fn other_speed(&self) -> Option<Speed> {
+        match self {
+            Speed::Full => Some(Speed::High),
+            Speed::High => Some(Speed::Full),
+            _ => None,
+        }
+    }
+
+    /// SuperSpeed and SuperSpeedPlus
+    pub fn is_super_speed(&self) -> bool {
+        *self >= Speed::Super
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_up_to() {
+        assert_eq!(Speed::Low.up_to(), vec![Speed::Low]);
+        assert_eq!(Speed::High.up_to(), vec![Speed::Full, Speed::High]);
+        assert_eq!(Speed::SuperPlus.up_to().len(), 4);
+        assert_eq!(Speed::High.other_speed(), Some(Speed::Full));
+        assert_eq!(Speed::Super.other_speed(), None);
+        assert!(Speed::SuperPlus.is_super_speed());
+        assert!(!Speed::High.is_super_speed());
+    }
+}
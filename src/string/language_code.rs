@@ -2,17 +2,32 @@ use core::fmt::{Display, Formatter};
 
 use alloc::vec::Vec;
 
-use crate::binary::EncodeBytes;
+use crate::{
+    binary::{DecodeBytes, EncodeBytes},
+    error::{DecodeError, Error},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct LanguageCode(u16);
+pub struct LanguageCode(pub u16);
 
 impl EncodeBytes for LanguageCode {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
         Ok(self.0.to_le_bytes().to_vec())
     }
 }
 
+impl DecodeBytes for LanguageCode {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        match bytes {
+            [low, high, ..] => Ok(LanguageCode(u16::from_le_bytes([*low, *high]))),
+            _ => Err(DecodeError::Truncated {
+                expected: 2,
+                actual: bytes.len(),
+            }),
+        }
+    }
+}
+
 impl Display for LanguageCode {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "0x{:04X}", self.0)
@@ -31,4 +46,16 @@ mod tests {
         assert_eq!(EN_US.encode().unwrap(), vec![0x09, 0x04]);
         assert_eq!(DE_DE.encode().unwrap(), vec![0x07, 0x04]);
     }
+
+    #[test]
+    fn test_language_code_decode() {
+        assert_eq!(LanguageCode::decode(&[0x09, 0x04]), Ok(EN_US));
+        assert_eq!(
+            LanguageCode::decode(&[0x07]),
+            Err(DecodeError::Truncated {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
 }
@@ -2,6 +2,8 @@ use core::fmt::{Display, Formatter};
 
 use alloc::{string::ToString, vec::Vec};
 
+use crate::error::DecodeError;
+
 use super::{
     language_code::LanguageCode, string_content::StringContent, string_descriptor::StringDescriptor,
 };
@@ -23,12 +25,48 @@ impl StringBuidler {
             string: StringContent::Languages(languages),
         }
     }
+    pub fn localized(texts: Vec<(LanguageCode, &str)>) -> StringBuidler {
+        StringBuidler {
+            string: StringContent::Localized(
+                texts
+                    .into_iter()
+                    .map(|(language, text)| (language, text.to_string()))
+                    .collect(),
+            ),
+        }
+    }
     pub fn build(&self, index: u8) -> StringDescriptor {
         StringDescriptor {
             index,
             string: self.string.clone(),
         }
     }
+    /// Builds the string descriptor served for the `wIndex` of `language`
+    pub fn build_for_language(&self, index: u8, language: LanguageCode) -> StringDescriptor {
+        StringDescriptor {
+            index,
+            string: self.string.for_language(Some(language)),
+        }
+    }
+    /// Looks up a string index of a decoded descriptor in `strings`.
+    /// Index 0 means the descriptor has no string and turns into `None`.
+    pub fn resolve(
+        index: u8,
+        strings: &[StringDescriptor],
+    ) -> Result<Option<StringBuidler>, DecodeError> {
+        if index == 0 {
+            return Ok(None);
+        }
+        strings
+            .iter()
+            .find(|descriptor| descriptor.index == index)
+            .map(|descriptor| {
+                Some(StringBuidler {
+                    string: descriptor.string.clone(),
+                })
+            })
+            .ok_or(DecodeError::MissingString(index))
+    }
 }
 
 impl Display for StringBuidler {
@@ -42,6 +80,13 @@ impl Display for StringBuidler {
                 write!(f, "]")
             }
             StringContent::Text(string) => write!(f, "Text: {}", string),
+            StringContent::Localized(texts) => {
+                write!(f, "Localized: [")?;
+                for (language, text) in texts {
+                    write!(f, "{}: {}, ", language, text)?;
+                }
+                write!(f, "]")
+            }
         }
     }
 }
@@ -56,7 +101,10 @@ mod tests {
     fn test_string_builder_text() {
         let string = StringBuidler::text("Hello, World!");
         assert_eq!(string.to_string(), "Text: Hello, World!");
-        assert_eq!(string.build(1).string, StringContent::Text("Hello, World!".to_string()));
+        assert_eq!(
+            string.build(1).string,
+            StringContent::Text("Hello, World!".to_string())
+        );
     }
 
     #[test]
@@ -66,11 +114,41 @@ mod tests {
         assert_eq!(string.to_string(), "Languages: [0x0409, 0x0407, ]");
     }
 
+    #[test]
+    fn test_string_builder_resolve() {
+        let strings = vec![StringBuidler::text("Hello").build(2)];
+        assert_eq!(
+            StringBuidler::resolve(2, &strings),
+            Ok(Some(StringBuidler::text("Hello")))
+        );
+        assert_eq!(StringBuidler::resolve(0, &strings), Ok(None));
+        assert_eq!(
+            StringBuidler::resolve(3, &strings),
+            Err(DecodeError::MissingString(3))
+        );
+    }
+
+    #[test]
+    fn test_string_builder_localized() {
+        let string = StringBuidler::localized(vec![(EN_US, "Mouse"), (DE_DE, "Maus")]);
+        assert_eq!(
+            string.to_string(),
+            "Localized: [0x0409: Mouse, 0x0407: Maus, ]"
+        );
+        assert_eq!(
+            string.build_for_language(2, DE_DE).string,
+            StringContent::Text("Maus".to_string())
+        );
+    }
+
     #[test]
     fn test_string_builder_build() {
         let string = StringBuidler::text("Hello, World!");
         let descriptor = string.build(1);
         assert_eq!(descriptor.index, 1);
-        assert_eq!(descriptor.string, StringContent::Text("Hello, World!".to_string()));
+        assert_eq!(
+            descriptor.string,
+            StringContent::Text("Hello, World!".to_string())
+        );
     }
 }
@@ -3,7 +3,10 @@ use alloc::{
     vec::Vec,
 };
 
-use crate::binary::EncodeBytes;
+use crate::{
+    binary::{DecodeBytes, EncodeBytes},
+    error::{DecodeError, Error},
+};
 
 use super::language_code::LanguageCode;
 
@@ -11,6 +14,8 @@ use super::language_code::LanguageCode;
 pub enum StringContent {
     Languages(Vec<LanguageCode>),
     Text(string::String),
+    /// One text per language. The first text is used for languages without a translation.
+    Localized(Vec<(LanguageCode, string::String)>),
 }
 
 impl StringContent {
@@ -18,12 +23,62 @@ impl StringContent {
         match self {
             StringContent::Languages(languages) => languages.len() as u8 * 2,
             StringContent::Text(string) => string.len() as u8 * 2,
+            StringContent::Localized(_) => self.for_language(None).len(),
+        }
+    }
+
+    /// Resolves localized texts to the text for `language`
+    pub fn for_language(&self, language: Option<LanguageCode>) -> StringContent {
+        match self {
+            StringContent::Localized(texts) => {
+                let text = texts
+                    .iter()
+                    .find(|(code, _)| Some(*code) == language)
+                    .or(texts.first())
+                    .map(|(_, text)| text.clone())
+                    .unwrap_or_default();
+                StringContent::Text(text)
+            }
+            _ => self.clone(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decodes the `wLANGID` array of string descriptor zero
+    pub fn decode_languages(bytes: &[u8]) -> Result<StringContent, DecodeError> {
+        let languages = bytes
+            .chunks(2)
+            .map(LanguageCode::decode)
+            .collect::<Result<Vec<LanguageCode>, DecodeError>>()?;
+        Ok(StringContent::Languages(languages))
+    }
+
+    /// Decodes the UTF-16LE `bString` of a text string descriptor
+    pub fn decode_text(bytes: &[u8]) -> Result<StringContent, DecodeError> {
+        if !bytes.len().is_multiple_of(2) {
+            return Err(DecodeError::Truncated {
+                expected: bytes.len() + 1,
+                actual: bytes.len(),
+            });
         }
+        let units = bytes
+            .chunks(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]));
+        let text = char::decode_utf16(units)
+            .collect::<Result<string::String, _>>()
+            .map_err(|error| DecodeError::InvalidValue {
+                field: "bString",
+                value: error.unpaired_surrogate(),
+            })?;
+        Ok(StringContent::Text(text))
     }
 }
 
 impl EncodeBytes for StringContent {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
         let mut bytes = Vec::<u8>::new();
         match self {
             StringContent::Languages(languages) => {
@@ -36,6 +91,9 @@ impl EncodeBytes for StringContent {
                     bytes.append(&mut c.to_le_bytes().to_vec());
                 }
             }
+            StringContent::Localized(_) => {
+                bytes.append(&mut self.for_language(None).encode()?);
+            }
         }
         Ok(bytes)
     }
@@ -70,4 +128,43 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_string_content_for_language() {
+        let content = StringContent::Localized(vec![
+            (EN_US, "Mouse".to_string()),
+            (DE_DE, "Maus".to_string()),
+        ]);
+        assert_eq!(
+            content.for_language(Some(DE_DE)),
+            StringContent::Text("Maus".to_string())
+        );
+        assert_eq!(
+            content.for_language(Some(LanguageCode(0x040c))),
+            StringContent::Text("Mouse".to_string())
+        );
+        assert_eq!(
+            content.encode().unwrap(),
+            StringContent::Text("Mouse".to_string()).encode().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_string_content_decode() {
+        assert_eq!(
+            StringContent::decode_text(&[0x48, 0x00, 0x69, 0x00]),
+            Ok(StringContent::Text("Hi".to_string()))
+        );
+        assert_eq!(
+            StringContent::decode_languages(&[0x09, 0x04, 0x07, 0x04]),
+            Ok(StringContent::Languages(vec![EN_US, DE_DE]))
+        );
+        assert_eq!(
+            StringContent::decode_text(&[0x00, 0xD8]),
+            Err(DecodeError::InvalidValue {
+                field: "bString",
+                value: 0xD800
+            })
+        );
+    }
 }
@@ -1,9 +1,10 @@
 use alloc::vec::Vec;
 
 use crate::{
-    binary::{EncodeByte, EncodeBytes},
+    binary::{expect_descriptor, EncodeByte, EncodeBytes},
     descriptor::Descriptor,
     descriptor_type::DescriptorType,
+    error::{DecodeError, Error},
 };
 
 use super::string_content::StringContent;
@@ -19,16 +20,29 @@ pub struct StringDescriptor {
 }
 
 impl Descriptor for StringDescriptor {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
         let mut bytes = Vec::<u8>::new();
         let string_encoded = self.string.encode()?;
-        let length = string_encoded.len() as u8 + 2;
+        let length: u8 = match (string_encoded.len() + 2).try_into() {
+            Ok(length) => length,
+            Err(_) => {
+                return Err(Error::LengthOverflow {
+                    descriptor: STRING_DESCRIPTOR_TYPE,
+                    field: "bLength",
+                    length: string_encoded.len() + 2,
+                })
+            }
+        };
         bytes.push(length);
         bytes.push(STRING_DESCRIPTOR_TYPE.encode()?);
         bytes.append(&mut self.string.encode()?);
 
         if bytes.len() != length as usize {
-            return Err("string bLength does not match the actual length");
+            return Err(Error::LengthMismatch {
+                descriptor: STRING_DESCRIPTOR_TYPE,
+                expected: length as usize,
+                actual: bytes.len(),
+            });
         }
 
         Ok(bytes)
@@ -39,10 +53,32 @@ impl Descriptor for StringDescriptor {
     }
 }
 
+impl StringDescriptor {
+    /// String descriptor zero holds the supported languages, all others hold text.
+    /// The index is not part of the descriptor and has to be supplied by the caller.
+    pub fn decode(index: u8, bytes: &[u8]) -> Result<Self, DecodeError> {
+        let bytes = expect_descriptor(bytes, STRING_DESCRIPTOR_TYPE, None)?;
+        if !bytes.len().is_multiple_of(2) {
+            return Err(DecodeError::InvalidLength {
+                descriptor_type: bytes[1],
+                length: bytes[0],
+            });
+        }
+
+        let string = match index {
+            0 => StringContent::decode_languages(&bytes[2..])?,
+            _ => StringContent::decode_text(&bytes[2..])?,
+        };
+        Ok(StringDescriptor { index, string })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
 
+    use crate::string::language_code::EN_US;
+
     use super::*;
 
     #[test]
@@ -70,5 +106,30 @@ mod tests {
                 0,   //
             ]
         );
+        assert_eq!(StringDescriptor::decode(1, &bytes), Ok(descriptor));
+    }
+
+    #[test]
+    fn test_decode_languages() {
+        let descriptor = StringDescriptor::decode(0, &[4, 3, 0x09, 0x04]).unwrap();
+        assert_eq!(descriptor.string, StringContent::Languages(vec![EN_US]));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            StringDescriptor::decode(1, &[5, 3, 72, 0, 101]),
+            Err(DecodeError::InvalidLength {
+                descriptor_type: 3,
+                length: 5
+            })
+        );
+        assert_eq!(
+            StringDescriptor::decode(1, &[6, 3, 72, 0]),
+            Err(DecodeError::Truncated {
+                expected: 6,
+                actual: 4
+            })
+        );
     }
 }
@@ -1,19 +1,42 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
-use crate::binary::EncodeBytes;
+use crate::{
+    binary::{DecodeBytes, EncodeBytes},
+    error::{DecodeError, Error},
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Ordered by major, then minor version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Version {
     pub major: u8,
     pub minor: u8,
 }
 
+impl Version {
+    /// The binary-coded decimal as in `bcdUSB`, e.g. 0x0200
+    pub fn bcd(&self) -> u16 {
+        (self.major as u16) << 8 | self.minor as u16
+    }
+}
+
 impl EncodeBytes for Version {
-    fn encode(&self) -> Result<Vec<u8>, &str> {
-        let mut bytes = Vec::<u8>::new();
-        bytes.push(self.minor);
-        bytes.push(self.major);
-        Ok(bytes)
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        Ok(vec![self.minor, self.major])
+    }
+}
+
+impl DecodeBytes for Version {
+    fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        match bytes {
+            [minor, major, ..] => Ok(Version {
+                major: *major,
+                minor: *minor,
+            }),
+            _ => Err(DecodeError::Truncated {
+                expected: 2,
+                actual: bytes.len(),
+            }),
+        }
     }
 }
 
@@ -23,7 +46,7 @@ pub const USB1_0: Version = Version {
 };
 pub const USB1_1: Version = Version {
     major: 0x01,
-    minor: 0x01,
+    minor: 0x10,
 };
 pub const USB2_0: Version = Version {
     major: 0x02,
@@ -31,7 +54,7 @@ pub const USB2_0: Version = Version {
 };
 pub const USB2_1: Version = Version {
     major: 0x02,
-    minor: 0x01,
+    minor: 0x10,
 };
 pub const USB3_0: Version = Version {
     major: 0x03,
@@ -39,9 +62,23 @@ pub const USB3_0: Version = Version {
 };
 pub const USB3_1: Version = Version {
     major: 0x03,
-    minor: 0x01,
+    minor: 0x10,
 };
 pub const USB3_2: Version = Version {
     major: 0x03,
-    minor: 0x02,
+    minor: 0x20,
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcd() {
+        assert_eq!(USB1_1.bcd(), 0x0110);
+        assert_eq!(USB2_1.encode().unwrap(), vec![0x10, 0x02]);
+        assert_eq!(USB3_2.bcd(), 0x0320);
+        assert_eq!(Version::decode(&[0x10, 0x03]), Ok(USB3_1));
+        assert!(USB2_1 < USB3_0);
+    }
+}